@@ -0,0 +1,77 @@
+//! Benchmarks of the load, filter and aggregate paths, run with `cargo bench`.
+//!
+//! Every benchmark works on synthetic sheets from `SheetGenerator`, so results are comparable
+//! across machines and runs.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use datatroll::{Cell, LoadOptions, Sheet, SheetGenerator};
+
+/// the number of rows of the benchmarked sheets
+const SIZES: [usize; 2] = [1_000, 100_000];
+
+fn movies(rows: usize) -> Sheet {
+    SheetGenerator::new()
+        .seed(42)
+        .col_int("id", 1..)
+        .col_choice("director", &["nolan", "martin", "quintin", "scorces"])
+        .col_int("release date", 1950..=2024)
+        .col_float_normal("review", 3.5, 1.0)
+        .col_bool("watched", 0.3)
+        .rows(rows)
+}
+
+fn load(c: &mut Criterion) {
+    let mut group = c.benchmark_group("load");
+    for rows in SIZES {
+        let csv = movies(rows).to_csv_string();
+        group.throughput(Throughput::Bytes(csv.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &csv, |b, csv| {
+            b.iter(|| Sheet::load_from_bytes(black_box(csv.as_bytes()), &LoadOptions::default()))
+        });
+    }
+    group.finish();
+}
+
+fn filter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("filter");
+    for rows in SIZES {
+        let sheet = movies(rows);
+        group.throughput(Throughput::Elements(rows as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &sheet, |b, sheet| {
+            b.iter(|| {
+                sheet
+                    .filter("review", |c| matches!(c, Cell::Float(r) if *r >= 4.0))
+                    .len()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn aggregate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("aggregate");
+    for rows in SIZES {
+        let sheet = movies(rows);
+        group.throughput(Throughput::Elements(rows as u64));
+        group.bench_with_input(BenchmarkId::new("mean", rows), &sheet, |b, sheet| {
+            b.iter(|| sheet.mean(black_box("review")).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("column_scan", rows), &sheet, |b, sheet| {
+            b.iter(|| {
+                let review = sheet.column(black_box("review")).unwrap();
+                review.floats().flatten().sum::<f64>()
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("group_summary", rows),
+            &sheet,
+            |b, sheet| b.iter(|| sheet.group_summary(black_box("director")).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, load, filter, aggregate);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs::File,
+    io::{BufReader, Read},
+};
+
+use zip::ZipArchive;
+
+use crate::Sheet;
+
+impl Sheet {
+    /// Loads every CSV file of a ZIP archive into its own Sheet.
+    ///
+    /// Entries with a ".csv" extension, at any depth, are parsed like [`Sheet::load_data`]
+    /// parses files on disk, without extracting the archive first. Other entries are ignored,
+    /// and so are the `__MACOSX/` metadata folders added by macOS when compressing a folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the archive, with a ".zip" extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".zip" extension, can't be read, isn't a
+    /// valid archive, or holds a CSV entry which isn't valid UTF-8.
+    ///
+    /// # Returns
+    ///
+    /// The sheets keyed by the path of their entry inside the archive, such as
+    /// `"2024/january.csv"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheets = Sheet::load_zip("monthly_bundle.zip").unwrap();
+    /// for (name, sheet) in &sheets {
+    ///     println!("{name}: {} rows", sheet.row_count());
+    /// }
+    /// ```
+    pub fn load_zip(file_path: &str) -> Result<HashMap<String, Sheet>, Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("zip") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let f = File::open(file_path)?;
+        let mut archive = ZipArchive::new(BufReader::new(f))?;
+        let mut sheets = HashMap::new();
+
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i)?;
+            let name = entry.name().to_string();
+            if entry.is_dir()
+                || name.starts_with("__MACOSX/")
+                || name.rsplit('.').next() != Some("csv")
+            {
+                continue;
+            }
+
+            let mut data = String::new();
+            entry
+                .read_to_string(&mut data)
+                .map_err(|err| format!("could not read entry '{name}': {err}"))?;
+            sheets.insert(name, Self::load_data_from_str(&data));
+        }
+
+        Ok(sheets)
+    }
+}
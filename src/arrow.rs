@@ -0,0 +1,210 @@
+use std::{error::Error, sync::Arc};
+
+use arrow_array::{
+    cast::AsArray,
+    types::{
+        Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
+        UInt32Type, UInt64Type, UInt8Type,
+    },
+    Array, ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, RecordBatchOptions,
+    StringArray, UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::{uint_cell, Cell, Row, Sheet};
+
+impl Sheet {
+    /// Converts the Sheet into an Arrow record batch.
+    ///
+    /// The header row provides the field names, and every column gets a single nullable Arrow
+    /// type: `Int64` when it only holds ints, `UInt64` when it only holds `Cell::UInt` values,
+    /// `Float64` when it mixes numeric variants, `Boolean` when it only holds bools, and `Utf8`
+    /// otherwise. Null cells map to Arrow nulls.
+    ///
+    /// This method is only available with the `arrow` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sheet has no header row, or if Arrow rejects the resulting batch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,her,4");
+    /// let batch = sheet.to_record_batch().unwrap();
+    ///
+    /// assert_eq!(batch.num_rows(), 2);
+    /// assert_eq!(batch.schema().field(2).data_type().to_string(), "Float64");
+    /// ```
+    #[allow(deprecated)]
+    pub fn to_record_batch(&self) -> Result<RecordBatch, Box<dyn Error>> {
+        let header = self
+            .header()
+            .ok_or("cannot build a record batch from a sheet without a header row")?;
+        let mut fields = Vec::with_capacity(header.len());
+        let mut columns: Vec<ArrayRef> = Vec::with_capacity(header.len());
+
+        for (j, name) in header.iter().enumerate() {
+            let cells = self.data.iter().skip(1).map(|row| &row[j]);
+            let data_type = arrow_type(cells.clone());
+            let column: ArrayRef = match data_type {
+                DataType::Int64 => Arc::new(Int64Array::from_iter(cells.map(|c| match c {
+                    Cell::Int(i) => Some(*i),
+                    _ => None,
+                }))),
+                DataType::UInt64 => Arc::new(UInt64Array::from_iter(cells.map(|c| match c {
+                    Cell::UInt(u) => Some(*u),
+                    _ => None,
+                }))),
+                DataType::Float64 => Arc::new(Float64Array::from_iter(cells.map(|c| match c {
+                    Cell::Int(i) => Some(*i as f64),
+                    Cell::UInt(u) => Some(*u as f64),
+                    Cell::Float(f) => Some(*f),
+                    _ => None,
+                }))),
+                DataType::Boolean => Arc::new(BooleanArray::from_iter(cells.map(|c| match c {
+                    Cell::Bool(b) => Some(*b),
+                    _ => None,
+                }))),
+                _ => Arc::new(StringArray::from_iter(cells.map(|c| match c {
+                    Cell::Null => None,
+                    c => Some(c.to_string()),
+                }))),
+            };
+
+            fields.push(Field::new(name.to_string(), data_type, true));
+            columns.push(column);
+        }
+
+        let options = RecordBatchOptions::new().with_row_count(Some(self.row_count()));
+        let batch =
+            RecordBatch::try_new_with_options(Arc::new(Schema::new(fields)), columns, &options)?;
+
+        Ok(batch)
+    }
+
+    /// Builds a Sheet out of an Arrow record batch.
+    ///
+    /// The field names make up the header row. Integer arrays become `Cell::Int`, floating point
+    /// arrays `Cell::Float`, boolean arrays `Cell::Bool` and string arrays `Cell::String`, while
+    /// Arrow nulls map to `Cell::Null`.
+    ///
+    /// This method is only available with the `arrow` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a column has an Arrow type that can't be mapped to a `Cell`, such as
+    /// nested or temporal types.
+    pub fn from_record_batch(batch: &RecordBatch) -> Result<Self, Box<dyn Error>> {
+        Self::from_record_batches(&batch.schema(), std::slice::from_ref(batch))
+    }
+
+    /// from_record_batches builds a Sheet out of Arrow record batches sharing the same schema
+    ///
+    /// Returns an error if a column has an Arrow type that can't be mapped to a `Cell`.
+    #[allow(deprecated)]
+    pub(crate) fn from_record_batches(
+        schema: &Schema,
+        batches: &[RecordBatch],
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut sheet = Self::new_sheet();
+        sheet.data.push(
+            schema
+                .fields()
+                .iter()
+                .map(|f| Cell::String(f.name().clone()))
+                .collect(),
+        );
+
+        for batch in batches {
+            let offset = sheet.data.len();
+            sheet.data.extend(
+                (0..batch.num_rows()).map(|_| Row(Vec::with_capacity(batch.num_columns()))),
+            );
+            for column in batch.columns() {
+                for (i, cell) in array_to_cells(column)?.into_iter().enumerate() {
+                    sheet.data[offset + i].push(cell);
+                }
+            }
+        }
+
+        Ok(sheet)
+    }
+}
+
+/// Picks the Arrow type able to hold every cell of a column.
+fn arrow_type<'a>(cells: impl Iterator<Item = &'a Cell>) -> DataType {
+    let mut data_type: Option<DataType> = None;
+
+    for cell in cells {
+        let cell_type = match cell {
+            Cell::Null => continue,
+            Cell::Int(_) => DataType::Int64,
+            Cell::UInt(_) => DataType::UInt64,
+            Cell::Float(_) => DataType::Float64,
+            Cell::Bool(_) => DataType::Boolean,
+            Cell::String(_) => return DataType::Utf8,
+        };
+
+        data_type = match (data_type, cell_type) {
+            (None, t) => Some(t),
+            (Some(a), b) if a == b => Some(a),
+            (
+                Some(DataType::Int64 | DataType::UInt64 | DataType::Float64),
+                DataType::Int64 | DataType::UInt64 | DataType::Float64,
+            ) => Some(DataType::Float64),
+            _ => return DataType::Utf8,
+        };
+    }
+
+    data_type.unwrap_or(DataType::Utf8)
+}
+
+/// Converts an Arrow array into cells, mapping Arrow nulls to `Cell::Null`.
+fn array_to_cells(array: &ArrayRef) -> Result<Vec<Cell>, Box<dyn Error>> {
+    macro_rules! convert {
+        ($values:expr, $to_cell:expr) => {{
+            let values = $values;
+            (0..array.len())
+                .map(|i| {
+                    if array.is_null(i) {
+                        Cell::Null
+                    } else {
+                        $to_cell(values.value(i))
+                    }
+                })
+                .collect()
+        }};
+    }
+
+    let cells = match array.data_type() {
+        DataType::Null => vec![Cell::Null; array.len()],
+        DataType::Boolean => convert!(array.as_boolean(), Cell::Bool),
+        DataType::Int8 => convert!(array.as_primitive::<Int8Type>(), |v| Cell::Int(v as i64)),
+        DataType::Int16 => convert!(array.as_primitive::<Int16Type>(), |v| Cell::Int(v as i64)),
+        DataType::Int32 => convert!(array.as_primitive::<Int32Type>(), |v| Cell::Int(v as i64)),
+        DataType::Int64 => convert!(array.as_primitive::<Int64Type>(), Cell::Int),
+        DataType::UInt8 => convert!(array.as_primitive::<UInt8Type>(), |v| Cell::Int(v as i64)),
+        DataType::UInt16 => convert!(array.as_primitive::<UInt16Type>(), |v| Cell::Int(v as i64)),
+        DataType::UInt32 => convert!(array.as_primitive::<UInt32Type>(), |v| Cell::Int(v as i64)),
+        DataType::UInt64 => convert!(array.as_primitive::<UInt64Type>(), uint_cell),
+        DataType::Float32 => convert!(array.as_primitive::<Float32Type>(), |v| Cell::Float(
+            v as f64
+        )),
+        DataType::Float64 => convert!(array.as_primitive::<Float64Type>(), Cell::Float),
+        DataType::Utf8 => convert!(array.as_string::<i32>(), |v: &str| Cell::String(
+            v.to_string()
+        )),
+        DataType::LargeUtf8 => convert!(array.as_string::<i64>(), |v: &str| Cell::String(
+            v.to_string()
+        )),
+        DataType::Utf8View => convert!(array.as_string_view(), |v: &str| Cell::String(
+            v.to_string()
+        )),
+        t => return Err(Box::from(format!("unsupported arrow data type '{t}'"))),
+    };
+
+    Ok(cells)
+}
@@ -0,0 +1,261 @@
+use std::{
+    error::Error,
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter},
+};
+
+use apache_avro::{types::Value, Reader, Schema, Writer};
+
+use crate::{json::write_json_string, Cell, Row, Sheet};
+
+/// field attribute holding the original column name when it isn't a valid Avro name
+const HEADER_ATTRIBUTE: &str = "datatroll_header";
+
+/// Avro types a column can hold besides null, in the order they appear in its union.
+const AVRO_TYPES: [&str; 4] = ["boolean", "long", "double", "string"];
+
+impl Sheet {
+    /// Loads the records of an Avro object container file into a Sheet.
+    ///
+    /// The file must hold records of primitive fields, as written by [`Sheet::export_avro`] or
+    /// by Kafka pipelines standardized on Avro. Every field becomes a column: `boolean` values
+    /// become `Cell::Bool`, `int` and `long` values `Cell::Int`, `float` and `double` values
+    /// `Cell::Float`, `string` and `enum` values `Cell::String`, while nulls map to
+    /// `Cell::Null`. Unions are resolved to the value they hold.
+    ///
+    /// This method is only available with the `avro` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the Avro file to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".avro" extension, can't be read, doesn't
+    /// hold records, or holds a value that can't be mapped to a `Cell`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_avro("movies.avro").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    #[allow(deprecated)]
+    pub fn load_avro(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("avro") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let reader = Reader::new(BufReader::new(File::open(file_path)?))?;
+        let header: Row = match reader.writer_schema() {
+            Schema::Record(record) => record
+                .fields
+                .iter()
+                .map(|field| {
+                    let name = field
+                        .custom_attributes
+                        .get(HEADER_ATTRIBUTE)
+                        .and_then(|name| name.as_str())
+                        .unwrap_or(&field.name);
+                    Cell::String(name.to_string())
+                })
+                .collect(),
+            _ => return Err(Box::from("expected the Avro schema to be a record")),
+        };
+
+        let mut sheet = Self::new_sheet();
+        sheet.data.push(header);
+        for value in reader {
+            let fields = match value? {
+                Value::Record(fields) => fields,
+                _ => return Err(Box::from("expected every Avro value to be a record")),
+            };
+            let row: Row = fields
+                .into_iter()
+                .map(|(name, value)| {
+                    avro_to_cell(value).ok_or_else(|| {
+                        format!("field '{name}' holds a value that can't be mapped to a cell")
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            sheet.data.push(row);
+        }
+
+        Ok(sheet)
+    }
+
+    /// Exports the content of a Sheet to an Avro object container file.
+    ///
+    /// Every row is written as a record, and every column as a field whose type is a union of
+    /// `null` and the types its cells hold: `boolean` for `Cell::Bool`, `long` for `Cell::Int`,
+    /// `double` for `Cell::Float` and `string` for `Cell::String`. `Cell::UInt` values are
+    /// written as strings, since Avro has no unsigned type. Column names that aren't valid Avro
+    /// names, such as `release date`, are sanitized into `release_date`, and the original name
+    /// is kept in the schema so [`Sheet::load_avro`] restores it. If the file already exists,
+    /// it truncates the file and overwrites its content.
+    ///
+    /// This method is only available with the `avro` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the Avro file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".avro" extension, if two column names are
+    /// sanitized into the same field name, or if the file can't be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.export_avro("movies.avro").unwrap();
+    /// ```
+    #[allow(deprecated)]
+    pub fn export_avro(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("avro") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let names: Vec<String> = self.data[0].iter().map(|c| c.to_string()).collect();
+        let fields: Vec<String> = names.iter().map(|name| avro_name(name)).collect();
+        for (i, field) in fields.iter().enumerate() {
+            if let Some(j) = fields[..i].iter().position(|f| f == field) {
+                return Err(Box::from(format!(
+                    "columns '{}' and '{}' both map to the Avro field '{field}'",
+                    names[j], names[i]
+                )));
+            }
+        }
+
+        // the non-null types of every column, in union order
+        let types: Vec<Vec<&str>> = (0..names.len())
+            .map(|j| {
+                AVRO_TYPES
+                    .into_iter()
+                    .filter(|t| {
+                        self.data[1..]
+                            .iter()
+                            .any(|row| avro_type(&row[j]) == Some(t))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut schema = String::from(
+            "{\"type\":\"record\",\"name\":\"Row\",\"namespace\":\"datatroll\",\"fields\":[",
+        );
+        for (j, (name, field)) in names.iter().zip(&fields).enumerate() {
+            if j > 0 {
+                schema.push(',');
+            }
+            schema.push_str("{\"name\":");
+            write_json_string(&mut schema, field);
+            schema.push_str(",\"type\":[\"null\"");
+            for t in &types[j] {
+                schema.push_str(",\"");
+                schema.push_str(t);
+                schema.push('"');
+            }
+            schema.push_str("],\"default\":null");
+            if name != field {
+                schema.push_str(",\"");
+                schema.push_str(HEADER_ATTRIBUTE);
+                schema.push_str("\":");
+                write_json_string(&mut schema, name);
+            }
+            schema.push('}');
+        }
+        schema.push_str("]}");
+        let schema = Schema::parse_str(&schema)?;
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+        let mut writer = Writer::new(&schema, BufWriter::new(file))?;
+        for row in self.data.iter().skip(1) {
+            let record = row
+                .iter()
+                .enumerate()
+                .map(|(j, cell)| {
+                    let value = match avro_type(cell) {
+                        None => Value::Union(0, Box::new(Value::Null)),
+                        Some(t) => {
+                            let index = types[j].iter().position(|u| *u == t).unwrap_or(0) + 1;
+                            Value::Union(index as u32, Box::new(cell_to_avro(cell)))
+                        }
+                    };
+                    (fields[j].clone(), value)
+                })
+                .collect();
+            writer.append_value(Value::Record(record))?;
+        }
+        writer.into_inner()?;
+
+        Ok(())
+    }
+}
+
+/// Returns the Avro type a cell is written as, or None for nulls.
+fn avro_type(cell: &Cell) -> Option<&'static str> {
+    match cell {
+        Cell::Null => None,
+        Cell::Bool(_) => Some("boolean"),
+        Cell::Int(_) => Some("long"),
+        Cell::Float(_) => Some("double"),
+        Cell::String(_) | Cell::UInt(_) => Some("string"),
+    }
+}
+
+/// Converts a non-null cell into the Avro value of its type.
+fn cell_to_avro(cell: &Cell) -> Value {
+    match cell {
+        Cell::Null => Value::Null,
+        Cell::Bool(b) => Value::Boolean(*b),
+        Cell::Int(i) => Value::Long(*i),
+        Cell::Float(f) => Value::Double(*f),
+        Cell::String(s) => Value::String(s.clone()),
+        Cell::UInt(u) => Value::String(u.to_string()),
+    }
+}
+
+/// Converts a primitive Avro value into a cell, returning None for complex values.
+fn avro_to_cell(value: Value) -> Option<Cell> {
+    match value {
+        Value::Null => Some(Cell::Null),
+        Value::Boolean(b) => Some(Cell::Bool(b)),
+        Value::Int(i) => Some(Cell::Int(i as i64)),
+        Value::Long(i) => Some(Cell::Int(i)),
+        Value::Float(f) => Some(Cell::Float(f as f64)),
+        Value::Double(f) => Some(Cell::Float(f)),
+        Value::String(s) | Value::Enum(_, s) => Some(Cell::String(s)),
+        Value::Union(_, value) => avro_to_cell(*value),
+        _ => None,
+    }
+}
+
+/// Turns a column name into a valid Avro name, made of letters, digits and underscores and not
+/// starting with a digit.
+fn avro_name(name: &str) -> String {
+    let mut out: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !out.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        out.insert(0, '_');
+    }
+
+    out
+}
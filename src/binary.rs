@@ -0,0 +1,185 @@
+use std::{
+    error::Error,
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter, Read, Write},
+};
+
+use crate::{Cell, Row, Sheet};
+
+/// magic bytes written at the start of every binary snapshot
+const MAGIC: &[u8; 4] = b"DTRL";
+/// version of the binary layout, bumped whenever the encoding changes
+const VERSION: u8 = 1;
+
+const TAG_NULL: u8 = 0;
+const TAG_STRING: u8 = 1;
+const TAG_BOOL: u8 = 2;
+const TAG_INT: u8 = 3;
+const TAG_FLOAT: u8 = 4;
+const TAG_UINT: u8 = 5;
+
+impl Sheet {
+    /// Saves a snapshot of the Sheet to a binary file.
+    ///
+    /// The snapshot keeps the exact type of every cell, so loading it back with
+    /// [`Sheet::load_binary`] skips type inference entirely and is much faster than
+    /// re-parsing a CSV file. This makes it suitable for caching intermediate pipeline results.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path of the snapshot file, truncated if it already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("input.csv").unwrap();
+    /// sheet.save_binary("input.dtrl").unwrap();
+    ///
+    /// let restored = Sheet::load_binary("input.dtrl").unwrap();
+    /// assert_eq!(sheet.row_count(), restored.row_count());
+    /// ```
+    pub fn save_binary(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+
+        let mut buf_writer = BufWriter::new(file);
+        self.write_binary(&mut buf_writer)?;
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Loads a Sheet from a binary snapshot produced by [`Sheet::save_binary`].
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path of the snapshot file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not a datatroll snapshot, was written by
+    /// an unsupported version, or is truncated.
+    pub fn load_binary(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        let f = File::open(file_path)?;
+        let mut reader = BufReader::new(f);
+
+        Self::read_binary(&mut reader)
+    }
+
+    /// write_binary encodes the Sheet into the given writer
+    #[allow(deprecated)]
+    pub(crate) fn write_binary<W: Write>(&self, w: &mut W) -> Result<(), Box<dyn Error>> {
+        w.write_all(MAGIC)?;
+        w.write_all(&[VERSION])?;
+        w.write_all(&(self.data.len() as u64).to_le_bytes())?;
+
+        for row in &self.data {
+            w.write_all(&(row.len() as u64).to_le_bytes())?;
+            for cell in row {
+                match cell {
+                    Cell::Null => w.write_all(&[TAG_NULL])?,
+                    Cell::String(s) => {
+                        w.write_all(&[TAG_STRING])?;
+                        w.write_all(&(s.len() as u64).to_le_bytes())?;
+                        w.write_all(s.as_bytes())?;
+                    }
+                    Cell::Bool(b) => w.write_all(&[TAG_BOOL, *b as u8])?,
+                    Cell::Int(i) => {
+                        w.write_all(&[TAG_INT])?;
+                        w.write_all(&i.to_le_bytes())?;
+                    }
+                    Cell::UInt(u) => {
+                        w.write_all(&[TAG_UINT])?;
+                        w.write_all(&u.to_le_bytes())?;
+                    }
+                    Cell::Float(f) => {
+                        w.write_all(&[TAG_FLOAT])?;
+                        w.write_all(&f.to_le_bytes())?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// read_binary decodes a Sheet from the given reader
+    #[allow(deprecated)]
+    pub(crate) fn read_binary<R: Read>(r: &mut R) -> Result<Self, Box<dyn Error>> {
+        let mut magic = [0_u8; 4];
+        r.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(Box::from(
+                "the provided file is not a datatroll binary snapshot",
+            ));
+        }
+
+        let version = read_u8(r)?;
+        if version != VERSION {
+            return Err(Box::from(format!(
+                "unsupported binary snapshot version '{version}'"
+            )));
+        }
+
+        let row_count = read_u64(r)?;
+        let mut sheet = Self::new_sheet();
+        for _ in 0..row_count {
+            let cell_count = read_u64(r)?;
+            let mut row: Row = Row(Vec::new());
+            for _ in 0..cell_count {
+                let cell = match read_u8(r)? {
+                    TAG_NULL => Cell::Null,
+                    TAG_STRING => {
+                        // the length isn't trusted for allocation, a corrupt file could claim
+                        // any size
+                        let len = read_u64(r)?;
+                        let mut buf = Vec::new();
+                        r.by_ref().take(len).read_to_end(&mut buf)?;
+                        if (buf.len() as u64) < len {
+                            return Err(Box::from("the binary snapshot is truncated"));
+                        }
+                        Cell::String(String::from_utf8(buf)?)
+                    }
+                    TAG_BOOL => Cell::Bool(read_u8(r)? != 0),
+                    TAG_INT => {
+                        let mut buf = [0_u8; 8];
+                        r.read_exact(&mut buf)?;
+                        Cell::Int(i64::from_le_bytes(buf))
+                    }
+                    TAG_UINT => Cell::UInt(read_u64(r)?),
+                    TAG_FLOAT => {
+                        let mut buf = [0_u8; 8];
+                        r.read_exact(&mut buf)?;
+                        Cell::Float(f64::from_le_bytes(buf))
+                    }
+                    tag => return Err(Box::from(format!("invalid cell tag '{tag}'"))),
+                };
+                row.push(cell);
+            }
+            sheet.data.push(row);
+        }
+
+        Ok(sheet)
+    }
+}
+
+pub(crate) fn read_u8<R: Read>(r: &mut R) -> Result<u8, Box<dyn Error>> {
+    let mut buf = [0_u8; 1];
+    r.read_exact(&mut buf)?;
+    Ok(buf[0])
+}
+
+pub(crate) fn read_u64<R: Read>(r: &mut R) -> Result<u64, Box<dyn Error>> {
+    let mut buf = [0_u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
@@ -0,0 +1,193 @@
+use std::{
+    error::Error,
+    fs::{self, File, OpenOptions},
+    io::{BufReader, BufWriter, Read, Write},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use crate::{
+    binary::{read_u64, read_u8},
+    Sheet,
+};
+
+/// marks a cache file, followed by the fingerprint of its source and a binary snapshot
+const MAGIC: &[u8; 4] = b"DTRC";
+
+/// The size and modification time of the file a cache was built from, telling whether the file
+/// changed since.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Fingerprint {
+    size: u64,
+    modified: Duration,
+}
+
+impl Fingerprint {
+    fn of(path: &str) -> Result<Self, Box<dyn Error>> {
+        let metadata = fs::metadata(path)?;
+
+        Ok(Fingerprint {
+            size: metadata.len(),
+            modified: metadata
+                .modified()?
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl Sheet {
+    /// Saves the Sheet to a cache file, so that later runs can skip parsing its source.
+    ///
+    /// The cache holds a versioned binary snapshot of the already typed cells, as written by
+    /// [`Sheet::save_binary`]. It's written to a temporary file first and then moved in place,
+    /// so an interrupted run never leaves a truncated cache behind. A cache saved this way
+    /// isn't tied to a source file, so [`Sheet::load_cache_or_csv`] always considers it stale.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_path` - The path of the cache file, replaced if it already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("large.csv").unwrap();
+    /// sheet.save_cache("large.csv.cache").unwrap();
+    /// ```
+    pub fn save_cache(&self, cache_path: &str) -> Result<(), Box<dyn Error>> {
+        self.write_cache(cache_path, None)
+    }
+
+    /// write_cache saves the Sheet to a cache file, along with the fingerprint of its source
+    fn write_cache(
+        &self,
+        cache_path: &str,
+        source: Option<Fingerprint>,
+    ) -> Result<(), Box<dyn Error>> {
+        let tmp_path = format!("{cache_path}.tmp");
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(&tmp_path)?;
+
+        let mut buf_writer = BufWriter::new(file);
+        buf_writer.write_all(MAGIC)?;
+        match source {
+            Some(source) => {
+                buf_writer.write_all(&[1])?;
+                buf_writer.write_all(&source.size.to_le_bytes())?;
+                buf_writer.write_all(&source.modified.as_secs().to_le_bytes())?;
+                buf_writer.write_all(&u64::from(source.modified.subsec_nanos()).to_le_bytes())?;
+            }
+            None => buf_writer.write_all(&[0])?,
+        }
+        self.write_binary(&mut buf_writer)?;
+        buf_writer.flush()?;
+        drop(buf_writer);
+
+        fs::rename(&tmp_path, cache_path)?;
+
+        Ok(())
+    }
+
+    /// Loads a Sheet from a cache file written by [`Sheet::save_cache`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, isn't a cache, or was written by an
+    /// incompatible version of the cache format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_cache("large.csv.cache").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_cache(cache_path: &str) -> Result<Self, Box<dyn Error>> {
+        let mut reader = BufReader::new(File::open(cache_path)?);
+        read_source(&mut reader)?;
+
+        Self::read_binary(&mut reader)
+    }
+
+    /// Loads a CSV file through a cache, parsing it only when the cache is missing or stale.
+    ///
+    /// The cache records the size and modification time the CSV file had when it was built, and
+    /// is used while the CSV file still has both. Otherwise, including when the cache is
+    /// unreadable or was written by an incompatible version, the CSV file is parsed with
+    /// [`Sheet::load_data`] and the cache is rewritten for the next run.
+    ///
+    /// # Arguments
+    ///
+    /// * `csv_path` - The path of the CSV file.
+    /// * `cache_path` - The path of the cache file, created if it doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache can't be used and the CSV file can't be loaded, or if the
+    /// refreshed cache can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// // parses the CSV file on the first run only
+    /// let sheet = Sheet::load_cache_or_csv("large.csv", "large.csv.cache").unwrap();
+    /// ```
+    pub fn load_cache_or_csv(csv_path: &str, cache_path: &str) -> Result<Self, Box<dyn Error>> {
+        let source = Fingerprint::of(csv_path)?;
+        if let Some(sheet) = Self::fresh_cache(cache_path, source) {
+            return Ok(sheet);
+        }
+
+        let sheet = Self::load_data(csv_path)?;
+        sheet.write_cache(cache_path, Some(source))?;
+
+        Ok(sheet)
+    }
+
+    /// fresh_cache loads a cache if it was built from a file matching `source`, None otherwise
+    fn fresh_cache(cache_path: &str, source: Fingerprint) -> Option<Self> {
+        let mut reader = BufReader::new(File::open(cache_path).ok()?);
+        if read_source(&mut reader).ok()? != Some(source) {
+            return None;
+        }
+
+        Self::read_binary(&mut reader).ok()
+    }
+}
+
+/// Reads the header of a cache file, returning the fingerprint of its source if it has one.
+fn read_source<R: Read>(r: &mut R) -> Result<Option<Fingerprint>, Box<dyn Error>> {
+    let mut magic = [0_u8; 4];
+    r.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(Box::from("the provided file is not a datatroll cache"));
+    }
+
+    match read_u8(r)? {
+        0 => Ok(None),
+        1 => {
+            let (size, secs, nanos) = (read_u64(r)?, read_u64(r)?, read_u64(r)?);
+            let nanos = u32::try_from(nanos)
+                .ok()
+                .filter(|n| *n < 1_000_000_000)
+                .ok_or("invalid cache source modification time")?;
+
+            Ok(Some(Fingerprint {
+                size,
+                modified: Duration::new(secs, nanos),
+            }))
+        }
+        flag => Err(Box::from(format!("invalid cache source flag '{flag}'"))),
+    }
+}
@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use crate::{ExportOptions, LoadOptions, Sheet};
+
+impl Sheet {
+    /// Loads the tab separated text held by the system clipboard into a Sheet.
+    ///
+    /// Spreadsheet software such as Excel, LibreOffice Calc or Google Sheets puts copied cell
+    /// ranges on the clipboard as tab separated lines, so figures can be wrangled without saving
+    /// them to a temporary file first. The first copied line is used as the header row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clipboard can't be accessed or doesn't hold any text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::from_clipboard().unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn from_clipboard() -> Result<Self, Box<dyn Error>> {
+        let text = arboard::Clipboard::new()?.get_text()?;
+
+        Self::from_clipboard_text(&text)
+    }
+
+    /// Puts the content of the Sheet on the system clipboard as tab separated text, header
+    /// included, ready to be pasted into a spreadsheet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clipboard can't be accessed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.to_clipboard().unwrap();
+    /// ```
+    pub fn to_clipboard(&self) -> Result<(), Box<dyn Error>> {
+        arboard::Clipboard::new()?.set_text(self.to_clipboard_text()?)?;
+
+        Ok(())
+    }
+
+    /// from_clipboard_text parses tab separated text as copied from a spreadsheet
+    pub(crate) fn from_clipboard_text(text: &str) -> Result<Self, Box<dyn Error>> {
+        let options = LoadOptions {
+            delimiter: '\t',
+            ..Default::default()
+        };
+
+        Self::load_data_from_str_with(text, &options)
+    }
+
+    /// to_clipboard_text renders the sheet as tab separated text
+    pub(crate) fn to_clipboard_text(&self) -> Result<String, Box<dyn Error>> {
+        let mut buf = Vec::new();
+        self.export_to_writer_with(&mut buf, &ExportOptions::profile("tsv")?)?;
+
+        Ok(String::from_utf8(buf)?)
+    }
+}
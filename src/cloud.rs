@@ -0,0 +1,86 @@
+use std::error::Error;
+
+use object_store::{path::Path, ObjectStore, PutPayload};
+use url::Url;
+
+use crate::Sheet;
+
+impl Sheet {
+    /// Loads a CSV object from a cloud object store into a Sheet.
+    ///
+    /// Supported URIs are `s3://bucket/key` (Amazon S3), `gs://bucket/key` (Google Cloud
+    /// Storage), `az://container/key` (Azure Blob Storage) and `file:///path` for the local
+    /// filesystem. Credentials, regions and endpoints are read from the environment variables
+    /// used by each provider, such as `AWS_ACCESS_KEY_ID`, `AWS_REGION`,
+    /// `GOOGLE_SERVICE_ACCOUNT` or `AZURE_STORAGE_ACCOUNT_NAME`.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The location of the object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URI is invalid or of an unsupported scheme, if the object can't
+    /// be fetched, or if it isn't valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_object_store("s3://datalake/movies/2024.csv").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_object_store(uri: &str) -> Result<Self, Box<dyn Error>> {
+        let (store, path) = object_store(uri)?;
+        let bytes = runtime()?.block_on(async { store.get(&path).await?.bytes().await })?;
+
+        Self::load_from_reader(&bytes[..])
+    }
+
+    /// Exports the content of a Sheet as a CSV object to a cloud object store.
+    ///
+    /// The object is written the same way as [`Sheet::export`] writes files, and replaced if it
+    /// already exists. See [`Sheet::load_object_store`] for the supported URIs and credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The location of the object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URI is invalid or of an unsupported scheme, or if the object
+    /// can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.export_object_store("gs://datalake/movies/2024.csv").unwrap();
+    /// ```
+    pub fn export_object_store(&self, uri: &str) -> Result<(), Box<dyn Error>> {
+        let (store, path) = object_store(uri)?;
+        let payload = PutPayload::from(self.to_csv_string().into_bytes());
+        runtime()?.block_on(store.put(&path, payload))?;
+
+        Ok(())
+    }
+}
+
+/// Builds the store holding the object of a URI, configured from the environment.
+fn object_store(uri: &str) -> Result<(Box<dyn ObjectStore>, Path), Box<dyn Error>> {
+    let url = Url::parse(uri).map_err(|err| format!("invalid object store URI '{uri}': {err}"))?;
+    // the builders' configuration keys are the lowercase names of the environment variables
+    let options = std::env::vars().map(|(key, value)| (key.to_ascii_lowercase(), value));
+
+    Ok(object_store::parse_url_opts(&url, options)?)
+}
+
+/// Builds the runtime driving the asynchronous object store client on the current thread.
+fn runtime() -> std::io::Result<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+}
@@ -0,0 +1,238 @@
+use std::{error::Error, fmt};
+
+use crate::{parse_token, Cell, LoadOptions, Sheet};
+
+/// number of failed cells listed per column by the `Display` implementation of
+/// [`CoercionReport`]
+const LISTED_FAILURES: usize = 5;
+
+/// The type a mixed column is coerced to by [`Sheet::coerce_types`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoercionTarget {
+    /// ints and floats, which are kept as they are
+    Number,
+    /// bools
+    Bool,
+    /// strings
+    String,
+}
+
+impl fmt::Display for CoercionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoercionTarget::Number => write!(f, "number"),
+            CoercionTarget::Bool => write!(f, "bool"),
+            CoercionTarget::String => write!(f, "string"),
+        }
+    }
+}
+
+/// The cells of a column changed by [`Sheet::coerce_types`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnCoercion {
+    /// the name of the column
+    pub column: String,
+    /// the dominant type of the column, which every cell was coerced to
+    pub target: CoercionTarget,
+    /// the cells converted to the target type, as their row index and original value
+    pub coerced: Vec<(usize, Cell)>,
+    /// the cells that couldn't be converted and were set to `Cell::Null`, as their row index
+    /// and original value
+    pub failed: Vec<(usize, Cell)>,
+}
+
+/// What [`Sheet::coerce_types`] changed, one entry per mixed column.
+///
+/// Its `Display` implementation renders one line per column, meant for load logs.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CoercionReport {
+    /// the mixed columns, in header order
+    pub columns: Vec<ColumnCoercion>,
+}
+
+impl CoercionReport {
+    /// is_empty tells whether every column already had a single type
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+}
+
+impl fmt::Display for CoercionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no mixed columns");
+        }
+
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{}: {} coerced to {}, {} failed",
+                column.column,
+                column.coerced.len(),
+                column.target,
+                column.failed.len()
+            )?;
+            if column.failed.is_empty() {
+                continue;
+            }
+
+            let listed: Vec<String> = column
+                .failed
+                .iter()
+                .take(LISTED_FAILURES)
+                .map(|(row, cell)| format!("'{cell}' at row {row}"))
+                .collect();
+            write!(f, " ({}", listed.join(", "))?;
+            if column.failed.len() > LISTED_FAILURES {
+                write!(f, ", ...")?;
+            }
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Sheet {
+    /// Loads a delimited file like [`Sheet::load_data_with`], then coerces its mixed columns to
+    /// their dominant type, see [`Sheet::coerce_types`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{LoadOptions, Sheet};
+    ///
+    /// let (sheet, report) = Sheet::load_data_coerced("sales.csv", &LoadOptions::default()).unwrap();
+    /// if !report.is_empty() {
+    ///     eprintln!("{report}");
+    /// }
+    /// ```
+    pub fn load_data_coerced(
+        file_path: &str,
+        options: &LoadOptions,
+    ) -> Result<(Self, CoercionReport), Box<dyn Error>> {
+        let mut sheet = Self::load_data_with(file_path, options)?;
+        let report = sheet.coerce_types();
+
+        Ok((sheet, report))
+    }
+
+    /// coerce_types converts the cells of every mixed column to the column's dominant type
+    ///
+    /// Type inference works value by value, so a column of numbers holding a few `n/a` strings
+    /// ends up mixed, which breaks aggregations later on. A column is mixed when its non-null
+    /// cells are of more than one type among numbers (ints and floats alike), bools and
+    /// strings. Its dominant type is the most frequent one, and the other cells are converted:
+    ///
+    /// * to a number, strings holding a number once trimmed are parsed;
+    /// * to a bool, the ints 0 and 1 and the strings `yes`, `no`, `y`, `n`, `true` and `false`,
+    ///   in any case, are converted;
+    /// * to a string, every value is converted to its text representation.
+    ///
+    /// Cells that can't be converted are set to `Cell::Null`. Columns of a single type and the
+    /// header row are left untouched.
+    ///
+    /// # Returns
+    ///
+    /// A report of the coerced and failed cells of every mixed column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,review\n1,3.5\n2,n/a\n3,4\n4,5");
+    /// let report = sheet.coerce_types();
+    ///
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::Null);
+    /// assert_eq!(report.to_string(), "review: 0 coerced to number, 1 failed ('n/a' at row 2)");
+    /// ```
+    #[allow(deprecated)]
+    pub fn coerce_types(&mut self) -> CoercionReport {
+        let mut report = CoercionReport::default();
+        let Some(header) = self.data.first() else {
+            return report;
+        };
+
+        for j in 0..header.len() {
+            let Some(target) = self.dominant_type(j) else {
+                continue;
+            };
+
+            let mut column = ColumnCoercion {
+                column: self.data[0][j].to_string(),
+                target,
+                coerced: Vec::new(),
+                failed: Vec::new(),
+            };
+            for (i, row) in self.data.iter_mut().enumerate().skip(1) {
+                let cell = &mut row[j];
+                let converted = match (target, &*cell) {
+                    (_, Cell::Null) => continue,
+                    (CoercionTarget::Number, Cell::Int(_) | Cell::UInt(_) | Cell::Float(_))
+                    | (CoercionTarget::Bool, Cell::Bool(_))
+                    | (CoercionTarget::String, Cell::String(_)) => continue,
+                    (CoercionTarget::Number, Cell::String(s)) => match parse_token(s.trim()) {
+                        number @ (Cell::Int(_) | Cell::UInt(_) | Cell::Float(_)) => Some(number),
+                        _ => None,
+                    },
+                    (CoercionTarget::Bool, Cell::Int(0)) => Some(Cell::Bool(false)),
+                    (CoercionTarget::Bool, Cell::Int(1)) => Some(Cell::Bool(true)),
+                    (CoercionTarget::Bool, Cell::String(s)) => {
+                        match s.trim().to_ascii_lowercase().as_str() {
+                            "yes" | "y" | "true" => Some(Cell::Bool(true)),
+                            "no" | "n" | "false" => Some(Cell::Bool(false)),
+                            _ => None,
+                        }
+                    }
+                    (CoercionTarget::String, other) => Some(Cell::String(other.to_string())),
+                    _ => None,
+                };
+
+                let original = std::mem::replace(cell, converted.clone().unwrap_or(Cell::Null));
+                match converted {
+                    Some(_) => column.coerced.push((i, original)),
+                    None => column.failed.push((i, original)),
+                }
+            }
+            report.columns.push(column);
+        }
+
+        report
+    }
+
+    /// dominant_type returns the most frequent type among the non-null cells of the column at
+    /// index `j`, or None when the column isn't mixed
+    #[allow(deprecated)]
+    pub(crate) fn dominant_type(&self, j: usize) -> Option<CoercionTarget> {
+        // number, bool and string cells of the column
+        let mut counts = [0; 3];
+        for row in self.data.iter().skip(1) {
+            match &row[j] {
+                Cell::Null => {}
+                Cell::Int(_) | Cell::UInt(_) | Cell::Float(_) => counts[0] += 1,
+                Cell::Bool(_) => counts[1] += 1,
+                Cell::String(_) => counts[2] += 1,
+            }
+        }
+        if counts.iter().filter(|count| **count > 0).count() < 2 {
+            return None;
+        }
+
+        // ties are broken in favor of numbers, then bools
+        Some(if counts[0] >= counts[1] && counts[0] >= counts[2] {
+            CoercionTarget::Number
+        } else if counts[1] >= counts[2] {
+            CoercionTarget::Bool
+        } else {
+            CoercionTarget::String
+        })
+    }
+}
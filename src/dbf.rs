@@ -0,0 +1,191 @@
+use std::{error::Error, fs};
+
+use crate::{Cell, Row, Sheet};
+
+/// size of the file header, and of every field descriptor following it
+const BLOCK_SIZE: usize = 32;
+/// byte ending the list of field descriptors
+const HEADER_END: u8 = 0x0d;
+/// flag marking a record as deleted
+const DELETED: u8 = b'*';
+
+/// A field of a DBF table, as described in the file header.
+struct Field {
+    name: String,
+    kind: u8,
+    length: usize,
+    decimals: u8,
+}
+
+impl Sheet {
+    /// Loads a dBase table (.dbf) into a Sheet.
+    ///
+    /// The field names make up the header row. Field types are mapped onto cells as follows:
+    /// character fields become `Cell::String`, numeric and float fields `Cell::Int` when they
+    /// have no decimals and `Cell::Float` otherwise, logical fields `Cell::Bool`, and date fields
+    /// `YYYY-MM-DD` strings. Blank values, as well as `?` logicals, become `Cell::Null`. Records
+    /// flagged as deleted are skipped. Text is decoded as UTF-8 when valid, and as Latin-1
+    /// otherwise, which is what most legacy exports use.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the DBF file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".dbf" extension, can't be read, is truncated,
+    /// declares records too short for its fields, or holds a field of another type, such as memo
+    /// fields stored in a separate file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_dbf("parcels.dbf").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_dbf(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if !file_path
+            .rsplit('.')
+            .next()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("dbf"))
+        {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        Self::read_dbf(&fs::read(file_path)?)
+    }
+
+    /// read_dbf decodes the content of a DBF file
+    #[allow(deprecated)]
+    pub(crate) fn read_dbf(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+        if bytes.len() < BLOCK_SIZE {
+            return Err(Box::from("the DBF file is truncated"));
+        }
+        let record_count = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+        let header_length = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        let record_length = u16::from_le_bytes([bytes[10], bytes[11]]) as usize;
+
+        let mut fields = Vec::new();
+        let mut offset = BLOCK_SIZE;
+        while bytes.get(offset).is_some_and(|b| *b != HEADER_END) {
+            let descriptor = bytes
+                .get(offset..offset + BLOCK_SIZE)
+                .ok_or("the DBF file is truncated")?;
+            let name = &descriptor[..11];
+            let name = &name[..name.iter().position(|b| *b == 0).unwrap_or(name.len())];
+            let field = Field {
+                name: decode(name).trim().to_string(),
+                kind: descriptor[11].to_ascii_uppercase(),
+                length: descriptor[16] as usize,
+                decimals: descriptor[17],
+            };
+            if !matches!(field.kind, b'C' | b'N' | b'F' | b'L' | b'D') {
+                return Err(Box::from(format!(
+                    "field '{}' has unsupported type '{}'",
+                    field.name, field.kind as char
+                )));
+            }
+            fields.push(field);
+            offset += BLOCK_SIZE;
+        }
+        // every record starts with its deletion flag, followed by the fields
+        let fields_length: usize = fields.iter().map(|f| f.length).sum();
+        if record_length < 1 + fields_length {
+            return Err(Box::from(format!(
+                "records of {record_length} bytes can't hold fields of {fields_length} bytes"
+            )));
+        }
+
+        let mut sheet = Self::new_sheet();
+        sheet.data.push(
+            fields
+                .iter()
+                .map(|f| Cell::String(f.name.clone()))
+                .collect(),
+        );
+
+        for i in 0..record_count {
+            let start = header_length + i * record_length;
+            let record = bytes
+                .get(start..start + record_length)
+                .ok_or("the DBF file is truncated")?;
+            if record[0] == DELETED {
+                continue;
+            }
+
+            let mut row = Row(Vec::with_capacity(fields.len()));
+            // the first byte of every record holds its deletion flag
+            let mut position = 1;
+            for field in &fields {
+                let value = record
+                    .get(position..position + field.length)
+                    .ok_or("the DBF file is truncated")?;
+                row.push(field_cell(field, value)?);
+                position += field.length;
+            }
+            sheet.data.push(row);
+        }
+
+        Ok(sheet)
+    }
+}
+
+/// Converts the raw value of a field into a cell.
+fn field_cell(field: &Field, value: &[u8]) -> Result<Cell, Box<dyn Error>> {
+    let text = decode(value);
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(Cell::Null);
+    }
+
+    let cell = match field.kind {
+        b'N' | b'F' => {
+            if field.decimals == 0 {
+                if let Ok(i) = text.parse::<i64>() {
+                    return Ok(Cell::Int(i));
+                }
+            }
+            match text.parse::<f64>() {
+                Ok(f) => Cell::Float(f),
+                // overflowing values are stored as asterisks
+                Err(_) if text.bytes().all(|b| b == b'*') => Cell::Null,
+                Err(_) => {
+                    return Err(Box::from(format!(
+                        "invalid numeric value '{text}' in field '{}'",
+                        field.name
+                    )))
+                }
+            }
+        }
+        b'L' => match text.as_bytes()[0].to_ascii_uppercase() {
+            b'T' | b'Y' => Cell::Bool(true),
+            b'F' | b'N' => Cell::Bool(false),
+            _ => Cell::Null,
+        },
+        b'D' => {
+            if text.len() != 8 || !text.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(Box::from(format!(
+                    "invalid date '{text}' in field '{}'",
+                    field.name
+                )));
+            }
+            Cell::String(format!("{}-{}-{}", &text[..4], &text[4..6], &text[6..]))
+        }
+        _ => Cell::String(text.to_string()),
+    };
+
+    Ok(cell)
+}
+
+/// Decodes text as UTF-8, falling back to Latin-1.
+fn decode(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s.to_string(),
+        Err(_) => bytes.iter().map(|b| *b as char).collect(),
+    }
+}
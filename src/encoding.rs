@@ -0,0 +1,101 @@
+use std::{
+    borrow::Cow,
+    io::{Error, ErrorKind},
+};
+
+/// The character encoding of delimited text, see [`LoadOptions::encoding`] and
+/// [`ExportOptions::encoding`].
+///
+/// Every encoding but UTF-8 requires the `encoding` feature.
+///
+/// [`LoadOptions::encoding`]: crate::LoadOptions::encoding
+/// [`ExportOptions::encoding`]: crate::ExportOptions::encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextEncoding {
+    /// UTF-8, the default
+    #[default]
+    Utf8,
+    /// ISO-8859-1, mapping every byte to the code point of the same value
+    Latin1,
+    /// Windows-1252, the default code page of Western European Windows systems
+    Windows1252,
+    /// UTF-16 little-endian, as written by Windows tools and Excel's "Unicode Text"
+    Utf16Le,
+    /// UTF-16 big-endian
+    Utf16Be,
+}
+
+#[cfg(not(feature = "encoding"))]
+const ENCODING_DISABLED: &str = "reading and writing text encoded in anything but UTF-8 \
+                                 requires the `encoding` feature";
+
+impl TextEncoding {
+    /// decode turns bytes of this encoding into a string
+    ///
+    /// Invalid UTF-8 is an error, while malformed UTF-16 sequences are replaced with U+FFFD.
+    pub(crate) fn decode(self, bytes: &[u8]) -> Result<Cow<'_, str>, Error> {
+        match self {
+            Self::Utf8 => std::str::from_utf8(bytes)
+                .map(Cow::Borrowed)
+                .map_err(|err| Error::new(ErrorKind::InvalidData, err)),
+            #[cfg(feature = "encoding")]
+            Self::Latin1 => Ok(encoding_rs::mem::decode_latin1(bytes)),
+            #[cfg(feature = "encoding")]
+            Self::Windows1252 => Ok(encoding_rs::WINDOWS_1252
+                .decode_without_bom_handling(bytes)
+                .0),
+            #[cfg(feature = "encoding")]
+            Self::Utf16Le => Ok(encoding_rs::UTF_16LE.decode(bytes).0),
+            #[cfg(feature = "encoding")]
+            Self::Utf16Be => Ok(encoding_rs::UTF_16BE.decode(bytes).0),
+            #[cfg(not(feature = "encoding"))]
+            _ => Err(Error::new(ErrorKind::Unsupported, ENCODING_DISABLED)),
+        }
+    }
+
+    /// encode turns a string into bytes of this encoding
+    ///
+    /// Characters which can't be represented in a single-byte encoding are an error.
+    pub(crate) fn encode(self, text: &str) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::Utf8 => Ok(text.as_bytes().to_vec()),
+            #[cfg(feature = "encoding")]
+            Self::Latin1 => match text.chars().find(|&c| u32::from(c) > 0xff) {
+                Some(c) => Err(unmappable(c, "Latin-1")),
+                None => Ok(encoding_rs::mem::encode_latin1_lossy(text).into_owned()),
+            },
+            #[cfg(feature = "encoding")]
+            Self::Windows1252 => {
+                let (bytes, _, unmappable_found) = encoding_rs::WINDOWS_1252.encode(text);
+                if unmappable_found {
+                    let c = text
+                        .chars()
+                        .find(|c| {
+                            encoding_rs::WINDOWS_1252
+                                .encode(c.encode_utf8(&mut [0; 4]))
+                                .2
+                        })
+                        .unwrap_or(char::REPLACEMENT_CHARACTER);
+                    return Err(unmappable(c, "Windows-1252"));
+                }
+                Ok(bytes.into_owned())
+            }
+            // encoding_rs only decodes UTF-16, which is simple enough to write by hand
+            #[cfg(feature = "encoding")]
+            Self::Utf16Le => Ok(text.encode_utf16().flat_map(u16::to_le_bytes).collect()),
+            #[cfg(feature = "encoding")]
+            Self::Utf16Be => Ok(text.encode_utf16().flat_map(u16::to_be_bytes).collect()),
+            #[cfg(not(feature = "encoding"))]
+            _ => Err(Error::new(ErrorKind::Unsupported, ENCODING_DISABLED)),
+        }
+    }
+}
+
+/// unmappable builds the error returned for a character absent from the target encoding
+#[cfg(feature = "encoding")]
+fn unmappable(c: char, encoding: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("character '{c}' can't be encoded in {encoding}"),
+    )
+}
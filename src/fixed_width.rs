@@ -0,0 +1,112 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufReader, Read},
+};
+
+use crate::{parse_token, strip_bom, Cell, Row, Sheet};
+
+impl Sheet {
+    /// Loads data from a fixed-width text file into a Sheet.
+    ///
+    /// Fixed-width files, as exported by mainframes and legacy reporting tools, align their
+    /// columns by byte offsets rather than separating them with a delimiter. Every field is cut
+    /// out of its line, trimmed and typed like the values of a CSV file. The header row is made
+    /// of the given column names, so the file itself is expected to hold data lines only. Empty
+    /// lines are skipped, and fields lying past the end of a short line are loaded as
+    /// `Cell::Null`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file to load.
+    /// * `columns` - The `(name, start, width)` of every column, with byte offsets starting at 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or if a field boundary falls inside a
+    /// multi-byte character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_fixed_width(
+    ///     "movies.dat",
+    ///     &[("id", 0, 4), ("title", 4, 10), ("review", 14, 5)],
+    /// )
+    /// .unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_fixed_width(
+        file_path: &str,
+        columns: &[(&str, usize, usize)],
+    ) -> Result<Self, Box<dyn Error>> {
+        let f = File::open(file_path)?;
+        let mut reader = BufReader::new(f);
+        let mut data = String::new();
+
+        reader.read_to_string(&mut data)?;
+
+        Self::load_fixed_width_from_str(&data, columns)
+    }
+
+    /// Loads data from a fixed-width string into a Sheet.
+    ///
+    /// See [`Sheet::load_fixed_width`] for the expected layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a field boundary falls inside a multi-byte character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "0001old       3.5\n0002her       4.2";
+    /// let sheet =
+    ///     Sheet::load_fixed_width_from_str(data, &[("id", 0, 4), ("title", 4, 10), ("review", 14, 3)])
+    ///         .unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[0], Cell::Int(1));
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::String("her".to_string()));
+    /// assert_eq!(sheet.row(1).unwrap()[2], Cell::Float(4.2));
+    /// ```
+    #[allow(deprecated)]
+    pub fn load_fixed_width_from_str(
+        data: &str,
+        columns: &[(&str, usize, usize)],
+    ) -> Result<Self, Box<dyn Error>> {
+        let data = strip_bom(data);
+        let mut sheet = Self::new_sheet();
+        sheet.data.push(
+            columns
+                .iter()
+                .map(|(name, _, _)| Cell::String(name.to_string()))
+                .collect(),
+        );
+
+        for (i, line) in data.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut row = Row(Vec::with_capacity(columns.len()));
+            for (name, start, width) in columns {
+                let start = (*start).min(line.len());
+                let end = (start + width).min(line.len());
+                let field = line.get(start..end).ok_or_else(|| {
+                    format!(
+                        "field '{name}' of line '{}' doesn't fall on character boundaries",
+                        i + 1
+                    )
+                })?;
+                row.push(parse_token(field.trim()));
+            }
+            sheet.data.push(row);
+        }
+
+        Ok(sheet)
+    }
+}
@@ -0,0 +1,207 @@
+use std::{error::Error, time::Duration};
+
+use crate::{Cell, ColumnRef, Sheet};
+
+/// seconds in a day
+const DAY: i64 = 86_400;
+
+/// A run of missing periods in a time series, as returned by [`Sheet::detect_gaps`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gap {
+    /// the first missing period
+    pub start: String,
+    /// the last missing period
+    pub end: String,
+    /// the number of missing periods
+    pub missing: u64,
+}
+
+impl Sheet {
+    /// detect_gaps reports the periods missing from a time series, e.g. the days absent from a
+    /// daily log export
+    ///
+    /// The values of the column are sorted, so the rows don't need to be in time order, and
+    /// duplicates are ignored. Whenever two consecutive values are further apart than
+    /// `expected_interval`, the periods expected between them are reported as one gap.
+    ///
+    /// Values are dates such as `2024-01-31`, date-times such as `2024-01-31T08:00:00` or
+    /// `2024-01-31 08:00` with an optional trailing `Z`, or ints counting seconds since the Unix
+    /// epoch. Null cells are skipped. The periods of a gap are written as dates when the interval
+    /// is a whole number of days and every value falls on midnight, as date-times otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `date_col` - the column holding the time of every row
+    /// * `expected_interval` - the time between two consecutive rows, at least one second
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, if the interval is shorter than a second,
+    /// or if a value can't be read as a date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str(
+    ///     "day,requests\n2024-02-27,120\n2024-03-02,90\n2024-02-28,80\n2024-03-03,95",
+    /// );
+    /// let gaps = sheet
+    ///     .detect_gaps("day", Duration::from_secs(24 * 60 * 60))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(gaps.len(), 1);
+    /// assert_eq!(gaps[0].start, "2024-02-29");
+    /// assert_eq!(gaps[0].end, "2024-03-01");
+    /// assert_eq!(gaps[0].missing, 2);
+    /// ```
+    #[allow(deprecated)]
+    pub fn detect_gaps<'a>(
+        &self,
+        date_col: impl Into<ColumnRef<'a>>,
+        expected_interval: Duration,
+    ) -> Result<Vec<Gap>, Box<dyn Error>> {
+        let col_index = self.col_index(date_col)?;
+        let interval = expected_interval.as_secs();
+        if interval == 0 {
+            return Err(Box::from(
+                "the expected interval should be at least one second",
+            ));
+        }
+
+        let mut times = Vec::with_capacity(self.row_count());
+        for (i, row) in self.data.iter().enumerate().skip(1) {
+            let time = match &row[col_index] {
+                Cell::Null => continue,
+                Cell::Int(seconds) => *seconds,
+                Cell::String(s) => parse_time(s.trim())
+                    .ok_or_else(|| format!("row '{i}': could not parse '{s}' as a date"))?,
+                other => return Err(Box::from(format!("row '{i}': '{other}' is not a date"))),
+            };
+            times.push(time);
+        }
+        times.sort_unstable();
+        times.dedup();
+
+        // periods are written as dates when no value has a time of day
+        let dates_only = interval.is_multiple_of(DAY.unsigned_abs())
+            && times.iter().all(|time| time.rem_euclid(DAY) == 0);
+        let format = |time: i64| {
+            if dates_only {
+                format_date(time)
+            } else {
+                format_date_time(time)
+            }
+        };
+
+        let gaps = times
+            .windows(2)
+            .filter(|pair| pair[1].abs_diff(pair[0]) > interval)
+            .map(|pair| {
+                let missing = (pair[1].abs_diff(pair[0]) - 1) / interval;
+                // both periods fall between the values around the gap, so they don't overflow
+                Gap {
+                    start: format(pair[0].wrapping_add_unsigned(interval)),
+                    end: format(pair[0].wrapping_add_unsigned(missing * interval)),
+                    missing,
+                }
+            })
+            .collect();
+
+        Ok(gaps)
+    }
+}
+
+/// Parses a date or a date-time, returning the seconds since the Unix epoch.
+fn parse_time(text: &str) -> Option<i64> {
+    let text = text.strip_suffix('Z').unwrap_or(text);
+    let (date, time) = match text.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (text, None),
+    };
+
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next().filter(|year| year.len() == 4)?;
+    let year: i64 = year.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let mut seconds = 0;
+    if let Some(time) = time {
+        let mut parts = time.split(':');
+        let hours: i64 = parts.next()?.parse().ok()?;
+        let minutes: i64 = parts.next()?.parse().ok()?;
+        let secs: i64 = parts.next().map_or(Some(0), |s| s.parse().ok())?;
+        if parts.next().is_some() || hours > 23 || minutes > 59 || secs > 59 {
+            return None;
+        }
+        seconds = hours * 3600 + minutes * 60 + secs;
+    }
+
+    Some(days_from_civil(year, month, day) * DAY + seconds)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Counts the days from 1970-01-01 to a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // shifts the year to start in March, so the leap day ends it
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * i64::from(shifted_month) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the year, month and day of a number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+fn format_date(time: i64) -> String {
+    let (year, month, day) = civil_from_days(time.div_euclid(DAY));
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn format_date_time(time: i64) -> String {
+    let seconds = time.rem_euclid(DAY);
+    format!(
+        "{}T{:02}:{:02}:{:02}",
+        format_date(time),
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
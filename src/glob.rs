@@ -0,0 +1,194 @@
+use std::{error::Error, fs, path::Path};
+
+use crate::{Cell, Row, Sheet};
+
+/// name of the column added by [`GlobOptions::source_column`]
+const SOURCE_COLUMN: &str = "__source_file";
+
+/// Describes how the files matched by [`Sheet::load_glob_with`] are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GlobOptions {
+    /// when false, every file must have the same header row; when true, the headers are unioned
+    /// and the columns missing from a file are filled with `Cell::Null`
+    pub union_headers: bool,
+    /// whether a `__source_file` column holding the path of the file every row comes from is
+    /// appended
+    pub source_column: bool,
+}
+
+impl Sheet {
+    /// Loads every CSV file matching a glob pattern, and concatenates their rows into a Sheet.
+    ///
+    /// The pattern can hold `*` (any sequence of characters) and `?` (any single character)
+    /// wildcards in its file name part, such as `"data/2024-*.csv"`. Files are loaded with
+    /// [`Sheet::load_data`] in alphabetical order, and must all have the same header row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern has wildcards in its directory part, if no file matches
+    /// it, if a file can't be loaded or is empty, or if the headers of two files differ.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let year = Sheet::load_glob("data/2024-*.csv").unwrap();
+    /// ```
+    pub fn load_glob(pattern: &str) -> Result<Self, Box<dyn Error>> {
+        Self::load_glob_with(pattern, &GlobOptions::default())
+    }
+
+    /// Loads every CSV file matching a glob pattern, combined according to the given options.
+    ///
+    /// See [`Sheet::load_glob`] for the supported patterns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern has wildcards in its directory part, if no file matches
+    /// it, if a file can't be loaded or is empty, or if the headers of two files differ while
+    /// `union_headers` is false.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{GlobOptions, Sheet};
+    ///
+    /// let options = GlobOptions {
+    ///     union_headers: true,
+    ///     source_column: true,
+    /// };
+    /// let year = Sheet::load_glob_with("data/2024-*.csv", &options).unwrap();
+    /// ```
+    #[allow(deprecated)]
+    pub fn load_glob_with(pattern: &str, options: &GlobOptions) -> Result<Self, Box<dyn Error>> {
+        let path = Path::new(pattern);
+        let dir = match path.parent() {
+            Some(dir) if dir.as_os_str().is_empty() => Path::new("."),
+            Some(dir) => dir,
+            None => Path::new("."),
+        };
+        let file_pattern = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| format!("invalid glob pattern '{pattern}'"))?;
+        if dir.to_string_lossy().contains(['*', '?']) {
+            return Err(Box::from(format!(
+                "wildcards are only supported in the file name of the glob pattern '{pattern}'"
+            )));
+        }
+
+        let mut paths: Vec<String> = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            if entry.file_type()?.is_file()
+                && name
+                    .to_str()
+                    .is_some_and(|name| wildcard_match(file_pattern, name))
+            {
+                paths.push(entry.path().to_string_lossy().into_owned());
+            }
+        }
+        if paths.is_empty() {
+            return Err(Box::from(format!(
+                "no file matches the pattern '{pattern}'"
+            )));
+        }
+        paths.sort();
+
+        let mut sheet = Self::new_sheet();
+        let mut header: Vec<String> = Vec::new();
+        for (i, path) in paths.iter().enumerate() {
+            let part = Self::load_data(path)?;
+            let names: Vec<String> = part
+                .header()
+                .ok_or_else(|| format!("'{path}' has no header row"))?
+                .iter()
+                .map(|c| c.to_string())
+                .collect();
+
+            if i == 0 {
+                header = names.clone();
+            } else if names != header && !options.union_headers {
+                return Err(Box::from(format!(
+                    "the header of '{path}' differs from the header of '{}'",
+                    paths[0]
+                )));
+            }
+
+            // position of every column of the file in the combined header
+            let positions: Vec<usize> = names
+                .iter()
+                .map(|name| match header.iter().position(|h| h == name) {
+                    Some(j) => j,
+                    None => {
+                        header.push(name.clone());
+                        header.len() - 1
+                    }
+                })
+                .collect();
+
+            for row in part.data.into_iter().skip(1) {
+                let mut combined = Row(vec![Cell::Null; header.len()]);
+                for (cell, &j) in row.into_iter().zip(&positions) {
+                    combined[j] = cell;
+                }
+                if options.source_column {
+                    combined.push(Cell::String(path.clone()));
+                }
+                sheet.data.push(combined);
+            }
+        }
+
+        // rows loaded before the header grew are missing the later columns
+        let width = header.len();
+        for row in sheet.data.iter_mut() {
+            if row.len() < width + options.source_column as usize {
+                let source = options.source_column.then(|| row.pop()).flatten();
+                row.resize(width, Cell::Null);
+                row.extend(source);
+            }
+        }
+
+        let mut header: Row = header.into_iter().map(Cell::String).collect();
+        if options.source_column {
+            header.push(Cell::String(SOURCE_COLUMN.to_string()));
+        }
+        sheet.data.insert(0, header);
+
+        Ok(sheet)
+    }
+}
+
+/// Matches a file name against a pattern holding `*` and `?` wildcards.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // position of the last `*` in the pattern, and of the name character it was matched at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
@@ -0,0 +1,265 @@
+use std::{error::Error, fmt::Write};
+
+use crate::{
+    json::{write_json_string, write_json_value, JsonValue},
+    Cell, Row, Sheet,
+};
+
+/// base URL of the Google Sheets API
+const SHEETS_API: &str = "https://sheets.googleapis.com/v4/spreadsheets";
+
+/// Credentials used to reach the Google Sheets API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoogleCredentials {
+    /// an API key, which can only read spreadsheets shared publicly
+    ApiKey(String),
+    /// an OAuth 2.0 access token with a Sheets scope, such as the output of
+    /// `gcloud auth print-access-token` or a token minted for a service account
+    AccessToken(String),
+}
+
+impl Sheet {
+    /// Loads a range of a Google Sheets spreadsheet into a Sheet.
+    ///
+    /// The first row of the range is used as the header row. Values are read unformatted, so
+    /// numbers and booleans keep their type, and the rows Google shortens by dropping their
+    /// trailing empty cells are padded with `Cell::Null`.
+    ///
+    /// # Arguments
+    ///
+    /// * `spreadsheet_id` - The id of the spreadsheet, as found in its URL.
+    /// * `range` - The range to load in A1 notation, such as `"Movies!A1:E"` or `"Movies"` for
+    ///   a whole tab.
+    /// * `credentials` - How the request is authorized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, if the API answers with an error status, or if
+    /// the response can't be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{GoogleCredentials, Sheet};
+    ///
+    /// let token = std::env::var("GOOGLE_ACCESS_TOKEN").unwrap();
+    /// let sheet = Sheet::load_google_sheet(
+    ///     "1BxiMVs0XRA5nFMdKvBdBZjgmUUqptlbs74OgvE2upms",
+    ///     "Movies!A1:E",
+    ///     &GoogleCredentials::AccessToken(token),
+    /// )
+    /// .unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_google_sheet(
+        spreadsheet_id: &str,
+        range: &str,
+        credentials: &GoogleCredentials,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::load_google_sheet_from(SHEETS_API, spreadsheet_id, range, credentials)
+    }
+
+    /// Writes the content of a Sheet, header included, to a range of a Google Sheets
+    /// spreadsheet.
+    ///
+    /// Values are written as they are, without being interpreted as formulas or dates, starting
+    /// at the top-left cell of the range. Cells outside of the sheet's content are left
+    /// untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `spreadsheet_id` - The id of the spreadsheet, as found in its URL.
+    /// * `range` - The range to write in A1 notation, such as `"Movies!A1"`.
+    /// * `credentials` - How the request is authorized, which must be an access token since API
+    ///   keys can't write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, or if the API answers with an error status.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{GoogleCredentials, Sheet};
+    ///
+    /// let token = std::env::var("GOOGLE_ACCESS_TOKEN").unwrap();
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet
+    ///     .export_google_sheet(
+    ///         "1BxiMVs0XRA5nFMdKvBdBZjgmUUqptlbs74OgvE2upms",
+    ///         "Movies!A1",
+    ///         &GoogleCredentials::AccessToken(token),
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn export_google_sheet(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        credentials: &GoogleCredentials,
+    ) -> Result<(), Box<dyn Error>> {
+        self.export_google_sheet_to(SHEETS_API, spreadsheet_id, range, credentials)
+    }
+
+    /// load_google_sheet_from loads a range through the Sheets API served at `base`
+    #[allow(deprecated)]
+    pub(crate) fn load_google_sheet_from(
+        base: &str,
+        spreadsheet_id: &str,
+        range: &str,
+        credentials: &GoogleCredentials,
+    ) -> Result<Self, Box<dyn Error>> {
+        let url = values_url(
+            base,
+            spreadsheet_id,
+            range,
+            credentials,
+            "valueRenderOption=UNFORMATTED_VALUE",
+        );
+        let mut response = authorize(ureq::get(&url), credentials).call()?;
+        let body = response.body_mut().read_to_string()?;
+
+        let rows = match JsonValue::parse(&body)? {
+            JsonValue::Object(fields) => fields
+                .into_iter()
+                .find(|(key, _)| key == "values")
+                .map(|(_, values)| values),
+            _ => return Err(Box::from("expected the response to be a JSON object")),
+        };
+
+        let mut sheet = Self::new_sheet();
+        let rows = match rows {
+            Some(JsonValue::Array(rows)) => rows,
+            // an empty range has no "values" key
+            None => return Ok(sheet),
+            Some(_) => return Err(Box::from("expected \"values\" to be an array of rows")),
+        };
+
+        for (i, row) in rows.into_iter().enumerate() {
+            let values = match row {
+                JsonValue::Array(values) => values,
+                _ => return Err(Box::from("expected every row to be an array")),
+            };
+            let row: Row = values
+                .into_iter()
+                .map(|value| match value.into_cell() {
+                    Some(Cell::String(s)) if s.is_empty() => Ok(Cell::Null),
+                    Some(Cell::String(s)) => Ok(Cell::String(s)),
+                    Some(cell) if i == 0 => Ok(Cell::String(cell.to_string())),
+                    Some(cell) => Ok(cell),
+                    None => Err(format!("row {i} holds a nested value")),
+                })
+                .collect::<Result<_, _>>()?;
+            sheet.data.push(row);
+        }
+
+        let width = sheet.data.first().map_or(0, |header| header.len());
+        for row in sheet.data.iter_mut().skip(1) {
+            if row.len() < width {
+                row.resize(width, Cell::Null);
+            }
+        }
+
+        Ok(sheet)
+    }
+
+    /// export_google_sheet_to writes the sheet through the Sheets API served at `base`
+    #[allow(deprecated)]
+    pub(crate) fn export_google_sheet_to(
+        &self,
+        base: &str,
+        spreadsheet_id: &str,
+        range: &str,
+        credentials: &GoogleCredentials,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut body = String::from("{\"range\":");
+        write_json_string(&mut body, range);
+        body.push_str(",\"majorDimension\":\"ROWS\",\"values\":[");
+        for (i, row) in self.data.iter().enumerate() {
+            if i > 0 {
+                body.push(',');
+            }
+            body.push('[');
+            for (j, cell) in row.iter().enumerate() {
+                if j > 0 {
+                    body.push(',');
+                }
+                match cell {
+                    // null would leave the existing value of the cell in place
+                    Cell::Null => body.push_str("\"\""),
+                    cell => write_json_value(&mut body, cell),
+                }
+            }
+            body.push(']');
+        }
+        body.push_str("]}");
+
+        let url = values_url(
+            base,
+            spreadsheet_id,
+            range,
+            credentials,
+            "valueInputOption=RAW",
+        );
+        authorize(ureq::put(&url), credentials)
+            .header("Content-Type", "application/json")
+            .send(body)?;
+
+        Ok(())
+    }
+}
+
+/// Builds the URL of the values of a range, with the given query parameters.
+fn values_url(
+    base: &str,
+    spreadsheet_id: &str,
+    range: &str,
+    credentials: &GoogleCredentials,
+    query: &str,
+) -> String {
+    let mut url = format!(
+        "{base}/{}/values/{}?{query}",
+        encode_path_segment(spreadsheet_id),
+        encode_path_segment(range)
+    );
+    if let GoogleCredentials::ApiKey(key) = credentials {
+        write!(url, "&key={}", encode_path_segment(key)).unwrap();
+    }
+
+    url
+}
+
+/// Adds the authorization header of an access token to a request.
+fn authorize<B>(
+    request: ureq::RequestBuilder<B>,
+    credentials: &GoogleCredentials,
+) -> ureq::RequestBuilder<B> {
+    match credentials {
+        GoogleCredentials::AccessToken(token) => {
+            request.header("Authorization", format!("Bearer {token}"))
+        }
+        GoogleCredentials::ApiKey(_) => request,
+    }
+}
+
+/// Percent-encodes a URL path segment, keeping the characters of A1 notation readable.
+fn encode_path_segment(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~'
+            | b'!'
+            | b':'
+            | b'$' => out.push(b as char),
+            b => write!(out, "%{b:02X}").unwrap(),
+        }
+    }
+
+    out
+}
@@ -0,0 +1,126 @@
+use std::{collections::HashMap, error::Error};
+
+use crate::{Cell, CellKey, ColumnRef, Row, Sheet};
+
+/// the statistics computed for every group and numeric column by [`Sheet::group_summary`]
+const STATISTICS: [&str; 6] = ["count", "sum", "mean", "min", "max", "variance"];
+
+impl Sheet {
+    /// group_summary computes statistics of every numeric column per group, as a long-format Sheet
+    ///
+    /// The returned Sheet has the columns `group`, `column`, `statistic` and `value`, with one
+    /// row for every group, numeric column and statistic, ready to be fed to BI or plotting
+    /// tools. The statistics are `count`, `sum`, `mean`, `min`, `max` and `variance`, the
+    /// population variance like [`Sheet::variance`], computed over the non-null values. The
+    /// `count` is an int, and the other statistics of a group holding no value are null. Groups
+    /// appear in order of first appearance, and the grouping column isn't summarized.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_col` - the column whose values define the groups
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("director,review\nnolan,4.0\nnolan,5.0\nmartin,3.0");
+    /// let summary = sheet.group_summary("director").unwrap();
+    ///
+    /// // 2 groups, 1 numeric column and 6 statistics, after the header
+    /// assert_eq!(summary.row_count(), 12);
+    /// assert_eq!(summary.row(2).unwrap()[2], Cell::String("mean".to_string()));
+    /// assert_eq!(summary.row(2).unwrap()[3], Cell::Float(4.5));
+    /// ```
+    #[allow(deprecated)]
+    pub fn group_summary<'a>(
+        &self,
+        group_col: impl Into<ColumnRef<'a>>,
+    ) -> Result<Sheet, Box<dyn Error>> {
+        let group_index = self.col_index(group_col)?;
+        let numeric_cols: Vec<usize> = (0..self.data[0].len())
+            .filter(|&j| j != group_index && self.is_numeric_col(j))
+            .collect();
+
+        let mut groups: Vec<(&Cell, Vec<usize>)> = Vec::new();
+        let mut positions: HashMap<CellKey, usize> = HashMap::new();
+        for (i, row) in self.data.iter().enumerate().skip(1) {
+            let position = *positions
+                .entry(CellKey(&row[group_index]))
+                .or_insert_with(|| {
+                    groups.push((&row[group_index], Vec::new()));
+                    groups.len() - 1
+                });
+            groups[position].1.push(i);
+        }
+
+        let mut summary = Self::new_sheet();
+        summary.data.push(
+            ["group", "column", "statistic", "value"]
+                .into_iter()
+                .map(|name| Cell::String(name.to_string()))
+                .collect(),
+        );
+        for (group, rows) in &groups {
+            for &j in &numeric_cols {
+                let values: Vec<f64> = rows
+                    .iter()
+                    .filter_map(|&i| match self.data[i][j] {
+                        Cell::Int(x) => Some(x as f64),
+                        Cell::UInt(u) => Some(u as f64),
+                        Cell::Float(f) => Some(f),
+                        _ => None,
+                    })
+                    .collect();
+
+                for (statistic, value) in STATISTICS.iter().zip(statistics(&values)) {
+                    let row: Row = [
+                        (*group).clone(),
+                        self.data[0][j].clone(),
+                        Cell::String(statistic.to_string()),
+                        value,
+                    ]
+                    .into_iter()
+                    .collect();
+                    summary.data.push(row);
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// statistics computes the values of [`STATISTICS`], in order, over a group's values
+fn statistics(values: &[f64]) -> [Cell; 6] {
+    if values.is_empty() {
+        return [
+            Cell::Int(0),
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+        ];
+    }
+
+    let n = values.len() as f64;
+    let sum: f64 = values.iter().sum();
+    let mean = sum / n;
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+    [
+        Cell::Int(values.len() as i64),
+        Cell::Float(sum),
+        Cell::Float(mean),
+        Cell::Float(min),
+        Cell::Float(max),
+        Cell::Float(variance),
+    ]
+}
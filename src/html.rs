@@ -0,0 +1,122 @@
+use std::{
+    error::Error,
+    fs::OpenOptions,
+    io::{BufWriter, Write},
+};
+
+use crate::{style::format_number, Cell, Sheet};
+
+impl Sheet {
+    /// Renders the Sheet as an HTML table.
+    ///
+    /// The header row is written in `<thead>` and the other rows in `<tbody>`. Values are
+    /// HTML-escaped and nulls are left empty. The styles attached with [`Sheet::style_col`] and
+    /// [`Sheet::style_where`] are honored: highlights become the background color of the cell,
+    /// and number formats are applied to ints and floats.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("title,review\nold & new,3.5");
+    ///
+    /// assert_eq!(
+    ///     sheet.to_html(),
+    ///     "<table>\n<thead>\n<tr><th>title</th><th>review</th></tr>\n</thead>\n<tbody>\n\
+    ///      <tr><td>old &amp; new</td><td>3.5</td></tr>\n</tbody>\n</table>\n"
+    /// );
+    /// ```
+    #[allow(deprecated)]
+    pub fn to_html(&self) -> String {
+        let mut out = String::from("<table>\n");
+        let Some(header) = self.data.first() else {
+            out.push_str("</table>\n");
+            return out;
+        };
+
+        out.push_str("<thead>\n<tr>");
+        for cell in header.iter() {
+            out.push_str(&format!("<th>{}</th>", html_escape(&cell.to_string())));
+        }
+        out.push_str("</tr>\n</thead>\n<tbody>\n");
+
+        for (i, row) in self.data.iter().enumerate().skip(1) {
+            out.push_str("<tr>");
+            for (j, cell) in row.iter().enumerate() {
+                let style = self.cell_style(i, j).unwrap_or_default();
+                let value = match (cell, &style.number_format) {
+                    (Cell::Null, _) => String::new(),
+                    (Cell::Int(x), Some(format)) => format_number(*x as f64, format),
+                    (Cell::UInt(u), Some(format)) => format_number(*u as f64, format),
+                    (Cell::Float(f), Some(format)) => format_number(*f, format),
+                    (cell, _) => cell.to_string(),
+                };
+                match style.highlight {
+                    Some(color) => out.push_str(&format!(
+                        "<td style=\"background-color: {}\">",
+                        html_escape(&color)
+                    )),
+                    None => out.push_str("<td>"),
+                }
+                out.push_str(&html_escape(&value));
+                out.push_str("</td>");
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</tbody>\n</table>\n");
+
+        out
+    }
+
+    /// Exports the content of a Sheet as an HTML table, see [`Sheet::to_html`].
+    ///
+    /// The file holds the table only, ready to be embedded in a page or an email. If the file
+    /// already exists, it is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the HTML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".html" extension or can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("input.csv").unwrap();
+    /// sheet.export_html("report.html").unwrap();
+    /// ```
+    pub fn export_html(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("html") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+
+        let mut buf_writer = BufWriter::new(file);
+        buf_writer.write_all(self.to_html().as_bytes())?;
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Escapes the characters with a special meaning in HTML text and attributes.
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,72 @@
+use std::error::Error;
+
+use crate::Sheet;
+
+impl Sheet {
+    /// Downloads a CSV file over HTTP(S) and loads it into a Sheet.
+    ///
+    /// The response body is streamed into the CSV parser, so published datasets can be read by
+    /// scheduled jobs without a manual download step. Redirects are followed.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the CSV file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, if the server answers with an error status, or if
+    /// the body isn't valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_url("https://example.com/datasets/movies.csv").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_url(url: &str) -> Result<Self, Box<dyn Error>> {
+        Self::load_url_with_headers(url, &[])
+    }
+
+    /// Downloads a CSV file over HTTP(S), sending the given request headers, and loads it into
+    /// a Sheet.
+    ///
+    /// This is how authenticated endpoints are reached, e.g. with an `Authorization` header
+    /// holding a bearer token. See [`Sheet::load_url`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the CSV file.
+    /// * `headers` - The `(name, value)` pairs sent along with the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, if the server answers with an error status, or if
+    /// the body isn't valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let token = std::env::var("API_TOKEN").unwrap();
+    /// let sheet = Sheet::load_url_with_headers(
+    ///     "https://example.com/private/movies.csv",
+    ///     &[("Authorization", &format!("Bearer {token}"))],
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn load_url_with_headers(
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut request = ureq::get(url);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+
+        let response = request.call()?;
+        Self::load_from_reader(response.into_body().into_reader())
+    }
+}
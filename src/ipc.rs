@@ -0,0 +1,108 @@
+use std::{
+    error::Error,
+    fs::{File, OpenOptions},
+    io::BufWriter,
+};
+
+use arrow_ipc::{reader::FileReader, writer::FileWriter};
+
+use crate::Sheet;
+
+impl Sheet {
+    /// Loads data from an Arrow IPC file, also known as Feather v2, into a Sheet.
+    ///
+    /// This is the format written by pandas' `DataFrame.to_feather` and polars'
+    /// `DataFrame.write_ipc`, including their LZ4 compressed files. Integer columns become
+    /// `Cell::Int` (`Cell::UInt` for `UInt64`), floating point columns `Cell::Float`, boolean
+    /// columns `Cell::Bool` and string columns `Cell::String`, while nulls map to `Cell::Null`.
+    ///
+    /// This method is only available with the `ipc` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the IPC file to load, with a ".arrow", ".feather" or ".ipc"
+    ///   extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file has an unsupported extension, can't be read, or holds a
+    /// column type that can't be mapped to a `Cell`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_ipc("movies.feather").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_ipc(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if !is_ipc_path(file_path) {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let reader = FileReader::try_new(File::open(file_path)?, None)?;
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_record_batches(&schema, &batches)
+    }
+
+    /// Exports the content of a Sheet to an Arrow IPC file, also known as Feather v2.
+    ///
+    /// Columns are typed as in [`Sheet::to_record_batch`], so the file can be read back by
+    /// pandas' `read_feather` or polars' `read_ipc` without losing cell types. The file is
+    /// written uncompressed. If the file already exists, it truncates the file and overwrites its
+    /// content.
+    ///
+    /// This method is only available with the `ipc` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the IPC file, with a ".arrow", ".feather" or ".ipc" extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file has an unsupported extension, or can't be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.export_ipc("movies.feather").unwrap();
+    /// ```
+    pub fn export_ipc(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if !is_ipc_path(file_path) {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let batch = self.to_record_batch()?;
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+
+        let mut writer = FileWriter::try_new(BufWriter::new(file), &batch.schema())?;
+        writer.write(&batch)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+}
+
+/// Checks that a path has one of the extensions used for Arrow IPC files.
+fn is_ipc_path(file_path: &str) -> bool {
+    matches!(
+        file_path.rsplit('.').next(),
+        Some("arrow" | "feather" | "ipc")
+    )
+}
@@ -0,0 +1,623 @@
+use std::{
+    error::Error,
+    fmt::Write as _,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Read, Write},
+};
+
+use crate::{strip_bom, Cell, Row, Sheet};
+
+impl Sheet {
+    /// Loads data from a JSON file into a Sheet.
+    ///
+    /// The file must hold an array of flat objects. The keys of every object are unioned, in the
+    /// order they first appear, to build the header row, and keys missing from an object are
+    /// filled with `Cell::Null`. JSON numbers become `Cell::Int` when they are integers and
+    /// `Cell::Float` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the JSON file to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".json" extension, can't be read, isn't valid
+    /// JSON, or isn't an array of flat objects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_json("movies.json").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_json(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("json") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let f = File::open(file_path)?;
+        let mut reader = BufReader::new(f);
+        let mut data = String::new();
+
+        reader.read_to_string(&mut data)?;
+
+        Self::load_json_from_str(&data)
+    }
+
+    /// Loads data from a JSON string into a Sheet.
+    ///
+    /// See [`Sheet::load_json`] for the expected layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't valid JSON, or isn't an array of flat objects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_json_from_str(r#"[{"id": 1, "title": "old"}, {"id": 2, "review": 4.2}]"#).unwrap();
+    ///
+    /// assert_eq!(sheet.col_count(), 3);
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::Null);
+    /// assert_eq!(sheet.row(1).unwrap()[2], Cell::Float(4.2));
+    /// ```
+    #[allow(deprecated)]
+    pub fn load_json_from_str(data: &str) -> Result<Self, Box<dyn Error>> {
+        let items = match JsonValue::parse(strip_bom(data))? {
+            JsonValue::Array(items) => items,
+            _ => return Err(Box::from("expected a JSON array of objects")),
+        };
+
+        let mut header: Vec<String> = Vec::new();
+        let mut records: Vec<Vec<(usize, Cell)>> = Vec::with_capacity(items.len());
+        for item in items {
+            let record = json_object_to_record(item, &mut header)?;
+            records.push(record);
+        }
+
+        let mut sheet = Self::new_sheet();
+        sheet
+            .data
+            .push(header.iter().cloned().map(Cell::String).collect());
+        for record in records {
+            let mut row: Row = Row(vec![Cell::Null; header.len()]);
+            for (i, cell) in record {
+                row[i] = cell;
+            }
+            sheet.data.push(row);
+        }
+
+        Ok(sheet)
+    }
+
+    /// Loads data from a newline-delimited JSON (JSON Lines) file into a Sheet.
+    ///
+    /// Every non-empty line must hold a flat JSON object. The file is streamed line by line, so
+    /// only the resulting sheet is kept in memory. Keys are unioned into the header row like in
+    /// [`Sheet::load_json`], and keys missing from a record are filled with `Cell::Null`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file, with a ".ndjson" or ".jsonl" extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file has an unsupported extension, can't be read, or if a line
+    /// isn't a valid flat JSON object.
+    #[allow(deprecated)]
+    pub fn load_ndjson(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if !matches!(file_path.rsplit('.').next(), Some("ndjson" | "jsonl")) {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let f = File::open(file_path)?;
+        let reader = BufReader::new(f);
+
+        let mut header: Vec<String> = Vec::new();
+        let mut sheet = Self::new_sheet();
+        sheet.data.push(Row(Vec::new()));
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let value = JsonValue::parse(&line).map_err(|err| format!("line {}: {err}", i + 1))?;
+            let record = json_object_to_record(value, &mut header)
+                .map_err(|err| format!("line {}: {err}", i + 1))?;
+
+            let mut row: Row = Row(vec![Cell::Null; header.len()]);
+            for (i, cell) in record {
+                row[i] = cell;
+            }
+            sheet.data.push(row);
+        }
+
+        sheet.data[0] = header.into_iter().map(Cell::String).collect();
+        // records read before a key first appeared are shorter than the final header
+        sheet.normalize_cols();
+
+        Ok(sheet)
+    }
+
+    /// Exports the content of a Sheet to a newline-delimited JSON (JSON Lines) file.
+    ///
+    /// Every data row is written as a JSON object keyed by the header row, one per line. Rows
+    /// are streamed to the file one at a time. If the file already exists, it truncates the file
+    /// and overwrites its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file, with a ".ndjson" or ".jsonl" extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file has an unsupported extension, or can't be written to.
+    #[allow(deprecated)]
+    pub fn export_ndjson(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if !matches!(file_path.rsplit('.').next(), Some("ndjson" | "jsonl")) {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+
+        let mut buf_writer = BufWriter::new(file);
+        let mut line = String::new();
+        for row in self.data.iter().skip(1) {
+            line.clear();
+            write_json_object(&mut line, &self.data[0], row);
+            writeln!(buf_writer, "{line}")?;
+        }
+
+        buf_writer.flush()?;
+        Ok(())
+    }
+
+    /// Serializes the Sheet into a JSON string.
+    ///
+    /// The sheet is represented as an array of objects, one per data row, keyed by the header
+    /// row. `Cell::Null` maps to JSON `null`, ints and floats to numbers and bools to booleans.
+    /// Floats that JSON can't represent (NaN and infinities) are written as `null`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,,4.0");
+    ///
+    /// assert_eq!(
+    ///     sheet.to_json_string(),
+    ///     r#"[{"id":1,"title":"old","review":3.5},{"id":2,"title":null,"review":4.0}]"#
+    /// );
+    /// ```
+    #[allow(deprecated)]
+    pub fn to_json_string(&self) -> String {
+        let mut out = String::from("[");
+        for (i, row) in self.data.iter().skip(1).enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write_json_object(&mut out, &self.data[0], row);
+        }
+        out.push(']');
+
+        out
+    }
+
+    /// Serializes a single row as a JSON object keyed by the header row.
+    ///
+    /// Values are mapped the same way as in [`Sheet::to_json_string`], which makes it possible to
+    /// stream individual records to a message queue or an HTTP consumer without exporting the
+    /// whole table.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the data row, header excluded, like in [`Sheet::row`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `i` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,her,");
+    ///
+    /// assert_eq!(sheet.row_to_json(1).unwrap(), r#"{"id":2,"title":"her","review":null}"#);
+    /// ```
+    pub fn row_to_json(&self, i: usize) -> Result<String, Box<dyn Error>> {
+        let (Some(header), Some(row)) = (self.header(), self.row(i)) else {
+            return Err(Box::from(format!("row index '{i}' is out of bounds")));
+        };
+
+        let mut out = String::new();
+        write_json_object(&mut out, header, row);
+
+        Ok(out)
+    }
+
+    /// Exports the content of a Sheet to a JSON file.
+    ///
+    /// The file holds the same array of objects produced by [`Sheet::to_json_string`]. If the file
+    /// already exists, it truncates the file and overwrites its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".json" extension, or if it can't be written to.
+    pub fn export_json(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("json") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+
+        let mut buf_writer = BufWriter::new(file);
+        buf_writer.write_all(self.to_json_string().as_bytes())?;
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Writes a row as a JSON object keyed by the given header row.
+pub(crate) fn write_json_object(out: &mut String, header: &[Cell], row: &[Cell]) {
+    out.push('{');
+    for (i, (key, cell)) in header.iter().zip(row.iter()).enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_json_string(out, &key.to_string());
+        out.push(':');
+        write_json_value(out, cell);
+    }
+    out.push('}');
+}
+
+/// Writes a cell as a JSON value.
+pub(crate) fn write_json_value(out: &mut String, cell: &Cell) {
+    match cell {
+        Cell::Null => out.push_str("null"),
+        Cell::String(s) => write_json_string(out, s),
+        Cell::Bool(b) => write!(out, "{b}").unwrap(),
+        Cell::Int(i) => write!(out, "{i}").unwrap(),
+        Cell::UInt(u) => write!(out, "{u}").unwrap(),
+        Cell::Float(f) if f.is_finite() => write!(out, "{f:?}").unwrap(),
+        Cell::Float(_) => out.push_str("null"),
+    }
+}
+
+/// Writes a string as a quoted and escaped JSON string.
+pub(crate) fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Converts a flat JSON object into `(column index, cell)` pairs, registering unseen keys in the
+/// header.
+pub(crate) fn json_object_to_record(
+    value: JsonValue,
+    header: &mut Vec<String>,
+) -> Result<Vec<(usize, Cell)>, Box<dyn Error>> {
+    let fields = match value {
+        JsonValue::Object(fields) => fields,
+        _ => return Err(Box::from("expected every array item to be a JSON object")),
+    };
+
+    let mut record = Vec::with_capacity(fields.len());
+    for (key, value) in fields {
+        let cell = value
+            .into_cell()
+            .ok_or_else(|| format!("value of key '{key}' should be a scalar"))?;
+        let index = match header.iter().position(|k| *k == key) {
+            Some(i) => i,
+            None => {
+                header.push(key);
+                header.len() - 1
+            }
+        };
+        record.push((index, cell));
+    }
+
+    Ok(record)
+}
+
+/// A parsed JSON document.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(Cell),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Parses a complete JSON document.
+    pub(crate) fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let mut parser = JsonParser {
+            bytes: input.as_bytes(),
+            pos: 0,
+            depth: 0,
+        };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.bytes.len() {
+            return Err(parser.error("unexpected trailing characters"));
+        }
+
+        Ok(value)
+    }
+
+    /// Converts a scalar JSON value into a cell, returning None for arrays and objects.
+    pub(crate) fn into_cell(self) -> Option<Cell> {
+        match self {
+            JsonValue::Null => Some(Cell::Null),
+            JsonValue::Bool(b) => Some(Cell::Bool(b)),
+            JsonValue::Number(n) => Some(n),
+            JsonValue::String(s) => Some(Cell::String(s)),
+            JsonValue::Array(_) | JsonValue::Object(_) => None,
+        }
+    }
+}
+
+/// A minimal recursive descent JSON parser.
+struct JsonParser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// the number of arrays and objects the parser is inside
+    depth: usize,
+}
+
+/// the deepest nesting of arrays and objects accepted, so hostile input can't overflow the stack
+const MAX_DEPTH: usize = 128;
+
+impl JsonParser<'_> {
+    fn error(&self, msg: &str) -> Box<dyn Error> {
+        Box::from(format!("invalid JSON at byte {}: {msg}", self.pos))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), Box<dyn Error>> {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) != Some(&b) {
+            return Err(self.error(&format!("expected '{}'", b as char)));
+        }
+        self.pos += 1;
+
+        Ok(())
+    }
+
+    fn parse_literal(
+        &mut self,
+        literal: &str,
+        value: JsonValue,
+    ) -> Result<JsonValue, Box<dyn Error>> {
+        if !self.bytes[self.pos..].starts_with(literal.as_bytes()) {
+            return Err(self.error(&format!("expected '{literal}'")));
+        }
+        self.pos += literal.len();
+
+        Ok(value)
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, Box<dyn Error>> {
+        self.skip_whitespace();
+        match self.bytes.get(self.pos) {
+            Some(b'n') => self.parse_literal("null", JsonValue::Null),
+            Some(b't') => self.parse_literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.parse_literal("false", JsonValue::Bool(false)),
+            Some(b'"') => Ok(JsonValue::String(self.parse_string()?)),
+            Some(&open @ (b'[' | b'{')) => {
+                if self.depth == MAX_DEPTH {
+                    return Err(self.error(&format!("nested deeper than {MAX_DEPTH} levels")));
+                }
+                self.depth += 1;
+                let value = if open == b'[' {
+                    self.parse_array()
+                } else {
+                    self.parse_object()
+                };
+                self.depth -= 1;
+                value
+            }
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, Box<dyn Error>> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(items));
+        }
+
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Array(items));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, Box<dyn Error>> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            fields.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Object(fields));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, Box<dyn Error>> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+
+        loop {
+            let start = self.pos;
+            while let Some(&b) = self.bytes.get(self.pos) {
+                if b == b'"' || b == b'\\' {
+                    break;
+                }
+                self.pos += 1;
+            }
+            out.push_str(std::str::from_utf8(&self.bytes[start..self.pos])?);
+
+            match self.bytes.get(self.pos) {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = match self.bytes.get(self.pos) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.pos += 1;
+                            let c = self.parse_unicode_escape()?;
+                            out.push(c);
+                            continue;
+                        }
+                        _ => return Err(self.error("invalid escape sequence")),
+                    };
+                    self.pos += 1;
+                    out.push(escaped);
+                }
+                _ => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    /// parses the 4 hex digits following `\u`, combining surrogate pairs when needed
+    fn parse_unicode_escape(&mut self) -> Result<char, Box<dyn Error>> {
+        let high = self.parse_hex4()?;
+        if (0xD800..0xDC00).contains(&high) {
+            if !self.bytes[self.pos..].starts_with(b"\\u") {
+                return Err(self.error("unpaired surrogate in escape sequence"));
+            }
+            self.pos += 2;
+            let low = self.parse_hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error("invalid low surrogate in escape sequence"));
+            }
+            let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+            return char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"));
+        }
+
+        char::from_u32(high).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, Box<dyn Error>> {
+        let hex = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .ok_or_else(|| self.error("truncated unicode escape"))?;
+        let code = u32::from_str_radix(std::str::from_utf8(hex)?, 16)
+            .map_err(|_| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+
+        Ok(code)
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, Box<dyn Error>> {
+        let start = self.pos;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+        let token = std::str::from_utf8(&self.bytes[start..self.pos])?;
+
+        if let Ok(i) = token.parse::<i64>() {
+            return Ok(JsonValue::Number(Cell::Int(i)));
+        }
+        if let Ok(u) = token.parse::<u64>() {
+            return Ok(JsonValue::Number(Cell::UInt(u)));
+        }
+        match token.parse::<f64>() {
+            Ok(f) => Ok(JsonValue::Number(Cell::Float(f))),
+            Err(_) => Err(self.error(&format!("invalid number '{token}'"))),
+        }
+    }
+}
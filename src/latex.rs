@@ -0,0 +1,114 @@
+use std::{
+    error::Error,
+    fs::OpenOptions,
+    io::{BufWriter, Write},
+};
+
+use crate::{Cell, Sheet};
+
+/// The rules drawn around a table exported by [`Sheet::export_latex`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TableStyle {
+    /// `\hline` rules, which need no extra package
+    #[default]
+    Plain,
+    /// `\toprule`, `\midrule` and `\bottomrule` rules, which need the `booktabs` package
+    Booktabs,
+}
+
+impl Sheet {
+    /// Exports the content of a Sheet as a LaTeX `tabular` environment.
+    ///
+    /// The header row is separated from the data rows by a rule, numeric columns are
+    /// right-aligned and every other column is left-aligned. LaTeX special characters such as
+    /// `&`, `%` or `_` are escaped, so the file can be `\input` straight into a manuscript. If the
+    /// file already exists, it truncates the file and overwrites its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the LaTeX file.
+    /// * `style` - The rules drawn around the table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".tex" extension, or can't be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{Sheet, TableStyle};
+    ///
+    /// let sheet = Sheet::load_data("summary.csv").unwrap();
+    /// sheet.export_latex("summary.tex", TableStyle::Booktabs).unwrap();
+    /// ```
+    pub fn export_latex(&self, file_path: &str, style: TableStyle) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("tex") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+        let mut buf_writer = BufWriter::new(file);
+        buf_writer.write_all(self.to_latex(style).as_bytes())?;
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+
+    /// to_latex renders the table written by [`Sheet::export_latex`]
+    #[allow(deprecated)]
+    pub(crate) fn to_latex(&self, style: TableStyle) -> String {
+        let (top, mid, bottom) = match style {
+            TableStyle::Plain => ("\\hline", "\\hline", "\\hline"),
+            TableStyle::Booktabs => ("\\toprule", "\\midrule", "\\bottomrule"),
+        };
+        let Some(header) = self.data.first() else {
+            return String::new();
+        };
+
+        let columns: String = (0..header.len())
+            .map(|j| if self.is_numeric_col(j) { 'r' } else { 'l' })
+            .collect();
+
+        let mut out = format!("\\begin{{tabular}}{{{columns}}}\n{top}\n");
+        for (i, row) in self.data.iter().enumerate() {
+            let values: Vec<String> = row.iter().map(latex_value).collect();
+            out.push_str(&values.join(" & "));
+            out.push_str(" \\\\\n");
+            if i == 0 {
+                out.push_str(mid);
+                out.push('\n');
+            }
+        }
+        out.push_str(bottom);
+        out.push_str("\n\\end{tabular}\n");
+
+        out
+    }
+}
+
+/// Formats a cell with LaTeX special characters escaped.
+fn latex_value(cell: &Cell) -> String {
+    let mut out = String::new();
+    for c in cell.to_string().chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\\' => out.push_str("\\textbackslash{}"),
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
@@ -4,9 +4,11 @@
 //!
 //! ## Features:
 //! - **Versatile Data Loading:**
-//!   - Read data from CSV files with configurable separators and headers.
+//!   - Read data from CSV, TSV or semicolon separated files with a configurable delimiter.
 //!   - Specify data types for each column, ensuring type safety and efficient processing.
 //!   - Handle missing values with graceful error handling.
+//!   - Load JSON arrays of objects, unioning their keys into a header row.
+//!   - Load fixed-width text files, cutting columns at byte offsets.
 //! - **Intuitive Data Manipulation:**
 //!     - Insert new rows with custom values into your data.
 //!     - Drop unwanted rows or columns to focus on relevant data.
@@ -16,51 +18,112 @@
 //!         - Variance of numeric columns.
 //!     - Apply custom transformations to specific columns using lambda functions.
 //!     - Supports Pagination
+//!     - Reshape "one column per month" layouts into long tables with a date column.
 //! - **Seamless Data Export:**
 //!     - Write manipulated data back to a new CSV file, retaining original format or specifying your own.
 //!     - Customize output with options like separator selection and header inclusion.
+//!     - Snapshot sheets into a compact binary format that preserves cell types.
+//!     - Export sheets as JSON arrays of objects.
+//!     - Render sheets as GitHub-flavored Markdown tables.
+//!     - Export sheets as LaTeX `tabular` environments, optionally with booktabs rules.
+//!     - Load and export XML documents, mapping attributes and child elements to columns.
+//!     - Mask personal data columns by hashing, redacting or replacing values with fake names.
+//!     - Export rows as YAML mappings, e.g. to generate config fixtures.
+//!     - Serialize sheets to and from MessagePack, keeping cell types.
+//!     - Cache parsed CSV files in a binary snapshot, refreshed when the CSV file changes.
+//!     - Summarize text columns: lengths, empty values, distinct count and top values.
+//!     - Export with named profiles such as `excel-eu` or `postgres-copy`.
+//!     - Load and concatenate every CSV file matching a glob pattern.
+//!     - Estimate column statistics from a random sample, with confidence margins.
+//!     - Rename columns from an `old,new` mapping CSV file.
+//!     - Copy sheets to and paste them from the system clipboard as tab separated text.
+//!     - Keep or drop rows whose value is in a given set.
+//!     - Load and export ranges of Google Sheets spreadsheets.
+//!     - Select rows whose numeric or date value lies within a range.
+//!     - Load and export Avro object container files.
+//!     - Group related sheets in a named Workbook, stored as a directory of CSV files or one
+//!       xlsx file, and join them by name.
+//!     - Follow a growing CSV file and process its new rows in batches as they are appended.
+//!     - Load sheets of OpenDocument spreadsheets (.ods) saved by LibreOffice.
+//!     - Upsert rows into SQL tables keyed on a column, to keep a live table up to date.
+//!     - Load and export Arrow IPC (Feather v2) files, as read and written by pandas.
+//!     - Load dBase tables (.dbf), as exported by legacy GIS and ERP software.
+//!     - Track the rows and columns a pipeline added, removed or modified, summarized for logs.
+//!     - Stream CSV files larger than memory in chunks of rows.
+//!     - Add row number or random UUID columns to datasets lacking a key.
+//!     - Draw random rows with probabilities proportional to a weight column.
+//!     - Sniff the delimiter, quote character, header and line endings of unknown files.
+//!     - Draw up to n random rows per group, for balanced review subsets.
+//!     - Coerce mixed columns to their dominant type, reporting the coerced and failed cells.
+//!     - Apply named value transformers, such as `upper` or `to_date:%d/%m/%Y`, from config.
+//!     - Run pipelines of load, filter, rename, aggregate and export steps described in YAML or JSON.
+//!     - Load and export quoted cells spanning several lines, with `""` escaped quotes.
+//!     - Find columns identical or nearly perfectly correlated to another one.
+//!     - Configure the quote and escape characters of non-standard files, on load and export.
+//!     - Skip `#` comment lines on load, keeping their text as sheet metadata.
+//!     - Summarize numeric columns per group as a long-format (group, column, statistic, value) sheet.
+//!     - Render sheets as HTML tables.
+//!     - Highlight cells or set their number format per column or predicate, in HTML and xlsx exports.
+//!     - Skip the UTF-8 byte order mark of files saved by Excel, and optionally write one on export.
+//!     - Load and export Latin-1, Windows-1252 or UTF-16 encoded files.
+//!     - Read rows and columns through views, and edit them through checked mutators.
+//!     - Generate reproducible synthetic sheets from column descriptions, for benchmarks and tests.
+//!     - Parse byte buffers in bulk and scan columns as floats or strings.
+//!     - Fail on malformed values with their line, column and token, or load them as nulls with warnings.
+//!     - Standardize column values against a mapping or a reference sheet, reporting unmapped values.
+//!     - Load headerless files, naming their columns `col_0`, `col_1`... and keeping every row as data.
+//!     - Build null-safe filter predicates such as `gt`, `eq` or `is_null`, combined with `and`, `or` and `not`.
+//!     - Skip preamble lines and footer rows, such as banners and totals, while loading.
+//!     - Reject, truncate or pad rows holding more or fewer values than the header.
+//!     - Detect the periods missing from a time series, such as days absent from a log export.
+//!     - Check whether a column is sorted or monotonic, and skip sorting data that is already ordered.
+//!     - Load numbers grouped by a thousands separator, such as `1,234,567` or `1_000`, as ints and floats.
+//!     - Split sheets into one CSV file per group, described by a JSON manifest.
+//!     - Dump sheets as SQL `CREATE TABLE` and `INSERT` statements for SQLite, PostgreSQL or MySQL.
 //!
 //! # Example:
-//! ```rust
+//! ```rust,no_run
 //! use datatroll::{Cell, Sheet};
 //!
-//! fn main() {
-//!     // Read data from a CSV file
-//!     let data = "id ,title , director, release date, review
+//! // Read data from a CSV file
+//! let data = "id ,title , director, release date, review
 //!1, old, quintin, 2011, 3.5
 //!2, her, quintin, 2013, 4.2
 //!3, easy, scorces, 2005, 1.0
 //!4, hey, nolan, 1997, 4.7
 //!5, who, martin, 2017, 5.0";
-//!     let mut sheet = Sheet::load_data_from_str(data);
+//! let mut sheet = Sheet::load_data_from_str(data);
 //!
-//!     // drop all the rows in which the review is less than 4.0
-//!     sheet.drop_rows("review", |c| {
-//!         if let Cell::Float(r) = c {
-//!             return *r < 4.0;
-//!         }
-//!         false
-//!     });
-//!
-//!     // calculate the variance of the review column
-//!     let variance = sheet.variance("review").unwrap();
-//!     println!("variance for review is: {variance}");
-//!     
-//!     // Write the transformed data to a new CSV file
-//!     if let Err(err) = sheet.export("output.csv") {
-//!         eprintln!("Error exporting data: {}", err);
-//!     } else {
-//!         println!("Data exported successfully to output.csv");
+//! // drop all the rows in which the review is less than 4.0
+//! sheet.drop_rows("review", |c| {
+//!     if let Cell::Float(r) = c {
+//!         return *r < 4.0;
 //!     }
+//!     false
+//! });
+//!
+//! // calculate the variance of the review column
+//! let variance = sheet.variance("review").unwrap();
+//! println!("variance for review is: {variance}");
+//!
+//! // Write the transformed data to a new CSV file
+//! if let Err(err) = sheet.export("output.csv") {
+//!     eprintln!("Error exporting data: {}", err);
+//! } else {
+//!     println!("Data exported successfully to output.csv");
 //! }
 //! ```
 
 use std::{
-    iter,
+    borrow::Cow,
+    collections::{HashMap, HashSet},
     error::Error,
     fmt::Display,
     fs::{File, OpenOptions},
-    io::{BufReader, BufWriter, Read, Write}, ops,
+    hash::{BuildHasher, Hash, Hasher},
+    io::{BufReader, BufWriter, Read, Write},
+    iter, ops,
+    sync::Arc,
 };
 
 /// Represents different types of data that can be stored in a cell.
@@ -70,6 +133,11 @@ pub enum Cell {
     String(String),
     Bool(bool),
     Int(i64),
+    /// unsigned integers that don't fit an `i64`, such as IDs using the full `u64` range
+    ///
+    /// Parsing only produces this variant for values above `i64::MAX`, every other integer is
+    /// stored as [`Cell::Int`], which keeps comparisons between the two variants consistent.
+    UInt(u64),
     Float(f64),
 }
 
@@ -80,11 +148,80 @@ impl Display for Cell {
             Cell::String(s) => write!(f, "{}", s),
             Cell::Bool(b) => write!(f, "{}", b),
             Cell::Int(i) => write!(f, "{}", i),
+            Cell::UInt(u) => write!(f, "{}", u),
             Cell::Float(x) => write!(f, "{}", x),
         }
     }
 }
 
+/// compare_values orders two numbers by value, or two strings lexicographically, returning None
+/// for values of different kinds, nulls, bools and NaN
+fn compare_values(a: &Cell, b: &Cell) -> Option<std::cmp::Ordering> {
+    match (a, b) {
+        (Cell::String(a), Cell::String(b)) => Some(a.cmp(b)),
+        (Cell::Int(a), Cell::Int(b)) => Some(a.cmp(b)),
+        (Cell::Int(a), Cell::UInt(b)) => Some((*a as i128).cmp(&(*b as i128))),
+        (Cell::UInt(a), Cell::Int(b)) => Some((*a as i128).cmp(&(*b as i128))),
+        (Cell::UInt(a), Cell::UInt(b)) => Some(a.cmp(b)),
+        (Cell::Int(a), Cell::Float(b)) => (*a as f64).partial_cmp(b),
+        (Cell::UInt(a), Cell::Float(b)) => (*a as f64).partial_cmp(b),
+        (Cell::Float(a), Cell::Int(b)) => a.partial_cmp(&(*b as f64)),
+        (Cell::Float(a), Cell::UInt(b)) => a.partial_cmp(&(*b as f64)),
+        (Cell::Float(a), Cell::Float(b)) => a.partial_cmp(b),
+        _ => None,
+    }
+}
+
+/// sort_order orders two cells the way [`Sheet::sorted`] does: numbers by value whatever their
+/// type and strings lexicographically, in ascending or descending order, with nulls last either
+/// way. Values [`compare_values`] can't order, such as bools or values of different kinds, fall
+/// back to the order of their variants.
+fn sort_order(a: &Cell, b: &Cell, ascending: bool) -> std::cmp::Ordering {
+    match (a, b) {
+        (Cell::Null, Cell::Null) => std::cmp::Ordering::Equal,
+        (Cell::Null, _) => std::cmp::Ordering::Greater,
+        (_, Cell::Null) => std::cmp::Ordering::Less,
+        (a, b) => {
+            let (a, b) = if ascending { (a, b) } else { (b, a) };
+            compare_values(a, b)
+                .or_else(|| a.partial_cmp(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        }
+    }
+}
+
+/// CellKey wraps a cell reference so it can be used as a key in hash based collections.
+///
+/// Floats are compared and hashed by their bit pattern, which makes every value (NaN included)
+/// equal to itself.
+#[derive(Debug, Clone, Copy)]
+struct CellKey<'a>(&'a Cell);
+
+impl PartialEq for CellKey<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.0, other.0) {
+            (Cell::Float(a), Cell::Float(b)) => a.to_bits() == b.to_bits(),
+            (a, b) => a == b,
+        }
+    }
+}
+
+impl Eq for CellKey<'_> {}
+
+impl Hash for CellKey<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self.0).hash(state);
+        match self.0 {
+            Cell::Null => {}
+            Cell::String(s) => s.hash(state),
+            Cell::Bool(b) => b.hash(state),
+            Cell::Int(i) => i.hash(state),
+            Cell::UInt(u) => u.hash(state),
+            Cell::Float(f) => f.to_bits().hash(state),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Row(Vec<Cell>);
 
@@ -97,11 +234,11 @@ impl Display for Row {
                 Cell::String(s) => s.clone(),
                 Cell::Bool(b) => b.to_string(),
                 Cell::Int(i) => i.to_string(),
+                Cell::UInt(u) => u.to_string(),
                 Cell::Float(x) => x.to_string(),
             })
             .collect();
 
-
         write!(f, "[{}]", items.join(","))
     }
 }
@@ -146,18 +283,573 @@ impl<'a> IntoIterator for &'a Row {
     }
 }
 
+/// Numeric transformations that can be applied to a column with [`Sheet::transform`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericTransform {
+    /// absolute value, integers stay integers
+    Abs,
+    /// natural logarithm, defined for values greater than 0
+    Ln,
+    /// base 10 logarithm, defined for values greater than 0
+    Log10,
+    /// square root, defined for values greater than or equal to 0
+    Sqrt,
+    /// raises every value to the given power
+    Pow(f64),
+}
+
+/// Decides what happens to values a [`NumericTransform`] is not defined for, such as negative
+/// values for [`NumericTransform::Sqrt`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DomainPolicy {
+    /// abort the transformation and return an error
+    #[default]
+    Error,
+    /// replace the value with `Cell::Null`
+    Null,
+    /// leave the original value untouched
+    Keep,
+}
+
+/// Common unit conversions that can be applied to a column with [`Sheet::convert`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnitConversion {
+    MilesToKm,
+    KmToMiles,
+    FeetToMeters,
+    MetersToFeet,
+    InchesToCm,
+    CmToInches,
+    PoundsToKg,
+    KgToPounds,
+    FahrenheitToCelsius,
+    CelsiusToFahrenheit,
+}
+
+impl UnitConversion {
+    /// returns the `(factor, offset)` pair of the conversion, such that
+    /// `converted = value * factor + offset`
+    pub fn factor_offset(self) -> (f64, f64) {
+        match self {
+            UnitConversion::MilesToKm => (1.609344, 0.0),
+            UnitConversion::KmToMiles => (1.0 / 1.609344, 0.0),
+            UnitConversion::FeetToMeters => (0.3048, 0.0),
+            UnitConversion::MetersToFeet => (1.0 / 0.3048, 0.0),
+            UnitConversion::InchesToCm => (2.54, 0.0),
+            UnitConversion::CmToInches => (1.0 / 2.54, 0.0),
+            UnitConversion::PoundsToKg => (0.45359237, 0.0),
+            UnitConversion::KgToPounds => (1.0 / 0.45359237, 0.0),
+            UnitConversion::FahrenheitToCelsius => (5.0 / 9.0, -160.0 / 9.0),
+            UnitConversion::CelsiusToFahrenheit => (9.0 / 5.0, 32.0),
+        }
+    }
+}
+
+/// Decides how column names passed to the Sheet's methods are matched against its header row.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColumnMatching {
+    /// column names must match the header exactly
+    #[default]
+    Exact,
+    /// column names are matched ignoring surrounding whitespace and letter case
+    Relaxed,
+}
+
+impl ColumnMatching {
+    /// matches checks whether a header name matches the requested column name
+    fn matches(self, header: &str, column: &str) -> bool {
+        match self {
+            ColumnMatching::Exact => header == column,
+            ColumnMatching::Relaxed => self.normalize(header) == self.normalize(column),
+        }
+    }
+
+    /// normalize returns the form of a column name used for comparisons
+    fn normalize(self, name: &str) -> String {
+        match self {
+            ColumnMatching::Exact => name.to_string(),
+            ColumnMatching::Relaxed => name.trim().to_lowercase(),
+        }
+    }
+}
+
+/// References a column either by its name in the header row, or by its position.
+///
+/// Every method addressing a column accepts anything convertible into a `ColumnRef`, so both
+/// `sheet.mean("review")` and `sheet.mean(4)` work. Positions are handy for headerless data or
+/// for headers holding duplicate names.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnRef<'a> {
+    /// the zero-based position of the column
+    Index(usize),
+    /// the name of the column in the header row
+    Name(&'a str),
+}
+
+impl<'a> From<&'a str> for ColumnRef<'a> {
+    fn from(name: &'a str) -> Self {
+        ColumnRef::Name(name)
+    }
+}
+
+impl<'a> From<&'a String> for ColumnRef<'a> {
+    fn from(name: &'a String) -> Self {
+        ColumnRef::Name(name)
+    }
+}
+
+impl From<usize> for ColumnRef<'_> {
+    fn from(index: usize) -> Self {
+        ColumnRef::Index(index)
+    }
+}
+
+impl Display for ColumnRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnRef::Index(i) => write!(f, "#{}", i),
+            ColumnRef::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Decides how [`Sheet::merge_updates`] resolves rows whose key exists in both sheets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeStrategy {
+    /// overwrite the existing row with the values of the other sheet
+    PreferOther,
+    /// keep the existing row, ignoring the values of the other sheet
+    PreferSelf,
+    /// abort the merge if the values of the two rows differ
+    Error,
+}
+
+/// A rule checked against the values of a column whenever rows are inserted into a Sheet.
+///
+/// Validators are registered with [`Sheet::add_validator`].
+#[derive(Clone)]
+pub struct Validator {
+    column: String,
+    rule: Arc<dyn Fn(&Cell) -> bool + Send + Sync>,
+}
+
+impl Validator {
+    /// new creates a validator accepting the values of `column` for which `rule` returns true
+    pub fn new<F>(column: &str, rule: F) -> Self
+    where
+        F: Fn(&Cell) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            column: column.to_string(),
+            rule: Arc::new(rule),
+        }
+    }
+
+    /// column returns the name of the column checked by the validator
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+}
+
+impl std::fmt::Debug for Validator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Validator")
+            .field("column", &self.column)
+            .finish_non_exhaustive()
+    }
+}
+
+/// error returned by aggregations over a column holding NaN values
+const NAN_ERROR: &str = "column value should not be NaN";
+
+/// Decides what happens to NaN and infinite float values, see [`Sheet::handle_non_finite`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NonFinitePolicy {
+    /// return an error on the first non-finite value
+    Reject,
+    /// replace non-finite values with `Cell::Null`
+    Null,
+    /// leave non-finite values untouched
+    #[default]
+    Keep,
+}
+
+/// Decides how tokens written in scientific notation, such as `1e5`, are loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ScientificNotation {
+    /// parse them as floats
+    #[default]
+    Parse,
+    /// keep them as strings, for identifier-like values such as product codes
+    KeepAsString,
+}
+
+/// Decides which values have their leading and trailing whitespace removed when loading.
+///
+/// Whatever the policy, values wrapped in quotes are loaded as strings, with the quotes removed
+/// and their `""` escapes unescaped.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TrimPolicy {
+    /// keep every value as written, values padded with spaces are then loaded as strings
+    None,
+    /// trim unquoted values, while quoted values keep the whitespace inside their quotes
+    UnquotedOnly,
+    /// trim every value, inside the quotes of quoted values too
+    #[default]
+    All,
+}
+
+/// Decides what happens to the rows of delimited text holding more or fewer values than the
+/// header.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RaggedRows {
+    /// fail to load, naming the line of the first ragged row
+    Error,
+    /// drop the values past the width of the header, and pad shorter rows with `Cell::Null`
+    Truncate,
+    /// pad shorter rows with `Cell::Null`, while longer rows widen the header with columns
+    /// named after their position, such as `col_5` or `col_5_2` when taken, so no value is lost
+    #[default]
+    PadNull,
+}
+
+/// file extensions accepted for delimited text files
+const DELIMITED_EXTENSIONS: [&str; 3] = ["csv", "tsv", "txt"];
+
+const GZIP_DISABLED: &str = "reading and writing \".gz\" files requires the `gzip` feature";
+
+/// Options controlling how delimited text is parsed by [`Sheet::load_data_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// use datatroll::{LoadOptions, NonFinitePolicy};
+///
+/// let options = LoadOptions {
+///     delimiter: ';',
+///     non_finite: NonFinitePolicy::Reject,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadOptions {
+    /// the character separating the values of a row, `,` by default
+    pub delimiter: char,
+    /// what to do with NaN and infinite values, see [`Sheet::handle_non_finite`]
+    pub non_finite: NonFinitePolicy,
+    /// how tokens in scientific notation are loaded
+    pub scientific_notation: ScientificNotation,
+    /// per-column overrides of `scientific_notation`, keyed by header name
+    pub scientific_notation_columns: Vec<(String, ScientificNotation)>,
+    /// which values get their surrounding whitespace removed
+    pub trim: TrimPolicy,
+    /// the character wrapping values which contain delimiters or line breaks, `"` by default
+    pub quote_char: char,
+    /// the character escaping the next character inside quoted values, such as `\\`; when
+    /// None, the default, a quote inside a quoted value is escaped by doubling it
+    pub escape_char: Option<char>,
+    /// the character starting comment lines, such as `#`; comment lines are skipped and their
+    /// text, without the comment character, is kept in [`Sheet::comments`]. None by default
+    pub comment_char: Option<char>,
+    /// the character encoding of the input, UTF-8 by default; other encodings require the
+    /// `encoding` feature and are only decoded by the loaders reading the whole input
+    pub encoding: TextEncoding,
+    /// how values that don't match the type of their column are handled, kept as they were
+    /// parsed by default
+    pub parse_mode: ParseMode,
+    /// whether the first row holds column names, true by default; without a header, columns
+    /// are named `col_0`, `col_1`... and every row is loaded as data
+    pub header: bool,
+    /// the number of lines skipped at the start of the input, before the header, such as a
+    /// preamble banner; 0 by default
+    pub skip_rows: usize,
+    /// the number of lines skipped at the end of the input, such as a totals row; 0 by default.
+    /// A final line break doesn't count as an empty line
+    pub skip_footer: usize,
+    /// what happens to rows holding more or fewer values than the header, padded with nulls by
+    /// default
+    pub ragged_rows: RaggedRows,
+    /// the character grouping the digits of numbers by thousands, such as `,` in `1,234,567`
+    /// or `_` in `1_000`; None by default. Grouped numbers are loaded as ints or floats, even
+    /// when quoted, apart from the columns keeping scientific notation as strings, which hold
+    /// identifiers. The decimal mark stays `.`, so it can't be the separator
+    pub thousands_separator: Option<char>,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            non_finite: NonFinitePolicy::default(),
+            scientific_notation: ScientificNotation::default(),
+            scientific_notation_columns: Vec::new(),
+            trim: TrimPolicy::default(),
+            quote_char: '"',
+            escape_char: None,
+            comment_char: None,
+            encoding: TextEncoding::default(),
+            parse_mode: ParseMode::default(),
+            header: true,
+            skip_rows: 0,
+            skip_footer: 0,
+            ragged_rows: RaggedRows::default(),
+            thousands_separator: None,
+        }
+    }
+}
+
+impl LoadOptions {
+    /// has_header sets whether the first row holds column names, see [`LoadOptions::header`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, LoadOptions, Sheet};
+    ///
+    /// let options = LoadOptions::default().has_header(false);
+    /// let sheet = Sheet::load_data_from_str_with("1,3.5\n2,4.5", &options).unwrap();
+    ///
+    /// assert_eq!(sheet.header().unwrap()[1], Cell::String("col_1".to_string()));
+    /// assert_eq!(sheet.mean("col_1").unwrap(), 4.0);
+    /// ```
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.header = has_header;
+        self
+    }
+
+    /// skip_rows sets the number of lines skipped before the header, see
+    /// [`LoadOptions::skip_rows`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, LoadOptions, Sheet};
+    ///
+    /// let data = "Monthly report\nexported 2024-01-31\ntitle,sales\nold,10\nher,5\ntotal,15\n";
+    /// let options = LoadOptions::default().skip_rows(2).skip_footer(1);
+    /// let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    ///
+    /// assert_eq!(sheet.header().unwrap()[0], Cell::String("title".to_string()));
+    /// assert_eq!(sheet.row_count(), 2);
+    /// assert_eq!(sheet.mean("sales").unwrap(), 7.5);
+    /// ```
+    pub fn skip_rows(mut self, n: usize) -> Self {
+        self.skip_rows = n;
+        self
+    }
+
+    /// skip_footer sets the number of lines skipped at the end of the input, see
+    /// [`LoadOptions::skip_footer`]
+    pub fn skip_footer(mut self, n: usize) -> Self {
+        self.skip_footer = n;
+        self
+    }
+
+    /// thousands_separator sets the character grouping the digits of numbers, see
+    /// [`LoadOptions::thousands_separator`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, LoadOptions, Sheet};
+    ///
+    /// let data = "title,budget,revenue\nold,\"1,500,000\",2.5e6\nher,\"800,000.50\",1e6";
+    /// let options = LoadOptions::default().thousands_separator(',');
+    /// let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::Int(1_500_000));
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::Float(800_000.5));
+    /// assert_eq!(sheet.row(0).unwrap()[2], Cell::Float(2_500_000.0));
+    /// ```
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+}
+
+/// Describes how a Sheet is written by [`Sheet::export_with`].
+///
+/// Every field can be set by hand, or taken from a named profile with [`ExportOptions::profile`]
+/// and then adjusted.
+///
+/// # Examples
+///
+/// ```rust
+/// use datatroll::ExportOptions;
+///
+/// let options = ExportOptions {
+///     header: false,
+///     ..ExportOptions::profile("excel-eu").unwrap()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportOptions {
+    /// the character separating the values of a row, `,` by default
+    pub delimiter: char,
+    /// the character written in place of the decimal point of floats, `.` by default
+    pub decimal_separator: char,
+    /// the text written for `Cell::Null`, empty by default
+    pub null: String,
+    /// whether the header row is written, true by default
+    pub header: bool,
+    /// whether the file starts with a UTF-8 byte order mark, false by default
+    pub bom: bool,
+    /// the text ending every row, `\n` by default
+    pub line_terminator: String,
+    /// whether backslashes, delimiters and line breaks inside values are escaped with a
+    /// backslash, as expected by PostgreSQL's `COPY` text format, false by default
+    pub backslash_escapes: bool,
+    /// the character wrapping strings which contain the delimiter, a quote or a line break,
+    /// `"` by default
+    pub quote_char: char,
+    /// the character written before quotes and escape characters inside quoted strings; when
+    /// None, the default, quotes are doubled instead
+    pub escape_char: Option<char>,
+    /// the character encoding of the output, UTF-8 by default; other encodings require the
+    /// `encoding` feature
+    pub encoding: TextEncoding,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            decimal_separator: '.',
+            null: String::new(),
+            header: true,
+            bom: false,
+            line_terminator: "\n".to_string(),
+            backslash_escapes: false,
+            quote_char: '"',
+            escape_char: None,
+            encoding: TextEncoding::default(),
+        }
+    }
+}
+
+impl ExportOptions {
+    /// profile returns the options of a named export profile
+    ///
+    /// The available profiles are:
+    ///
+    /// - `"excel"`: `,` delimiter, byte order mark and `\r\n` line endings, so that Excel
+    ///   detects the encoding.
+    /// - `"excel-eu"`: the same with a `;` delimiter and `,` as decimal separator, as expected by
+    ///   Excel in most European locales.
+    /// - `"postgres-copy"`: the text format of PostgreSQL's `COPY ... FROM`, tab separated,
+    ///   without header, with `\N` for nulls and backslash escapes.
+    /// - `"tsv"`: tab separated values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no profile is named `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::ExportOptions;
+    ///
+    /// let options = ExportOptions::profile("excel-eu").unwrap();
+    ///
+    /// assert_eq!(options.delimiter, ';');
+    /// assert_eq!(options.decimal_separator, ',');
+    /// assert!(ExportOptions::profile("excel-us").is_err());
+    /// ```
+    pub fn profile(name: &str) -> Result<Self, Box<dyn Error>> {
+        let excel = Self {
+            bom: true,
+            line_terminator: "\r\n".to_string(),
+            ..Default::default()
+        };
+
+        match name {
+            "excel" => Ok(excel),
+            "excel-eu" => Ok(Self {
+                delimiter: ';',
+                decimal_separator: ',',
+                ..excel
+            }),
+            "postgres-copy" => Ok(Self {
+                delimiter: '\t',
+                null: "\\N".to_string(),
+                header: false,
+                backslash_escapes: true,
+                ..Default::default()
+            }),
+            "tsv" => Ok(Self {
+                delimiter: '\t',
+                ..Default::default()
+            }),
+            _ => Err(Box::from(format!(
+                "unknown export profile '{name}', expected one of 'excel', 'excel-eu', \
+                 'postgres-copy' or 'tsv'"
+            ))),
+        }
+    }
+}
+
 /// Represents a 2D vector of cells, forming a sheet of data.
-#[derive(Debug, Default)]
+///
+/// The rows, and the snapshot of them taken for change tracking, are only reachable through
+/// methods. The other fields are settings and metadata that sit beside the rows: no value
+/// written to them can leave the rows inconsistent, so they stay public.
+#[derive(Debug)]
 pub struct Sheet {
     /// 2D vector of cells
+    ///
+    /// Direct access to the rows is deprecated: it ties the API to the row-oriented layout and
+    /// lets callers break invariants, such as every row holding one cell per column. Read
+    /// through [`Sheet::header`], [`Sheet::rows`], [`Sheet::column`] or [`Sheet::as_slices`],
+    /// and write through [`Sheet::set_cell`], [`Sheet::insert_row_cells`],
+    /// [`Sheet::remove_row`], [`Sheet::retain_rows`] or [`Sheet::rename_col`] instead.
+    #[deprecated(
+        since = "0.1.4",
+        note = "use the accessors, such as `rows()`, `column()` or `as_slices()`, and mutators, \
+                such as `set_cell()`, instead"
+    )]
     pub data: Vec<Row>,
+    /// how column names are matched against the header row
+    pub column_matching: ColumnMatching,
+    /// rules every inserted row has to satisfy
+    pub validators: Vec<Validator>,
+    /// the character separating values when exporting, `,` by default
+    pub delimiter: char,
+    /// snapshot of the rows taken by [`Sheet::begin_tracking`], None when changes aren't tracked
+    #[deprecated(
+        since = "0.1.4",
+        note = "use `begin_tracking()` and `changes()` instead"
+    )]
+    pub tracking: Option<Vec<Row>>,
+    /// the transformers available to [`Sheet::apply_named`]
+    pub transformers: TransformerRegistry,
+    /// the comment lines skipped while loading, see [`LoadOptions::comment_char`]
+    pub comments: Vec<String>,
+    /// presentation hints used by the HTML and xlsx exporters, see [`Sheet::style_col`]
+    pub styles: Vec<StyleRule>,
+    /// the malformed values set to null while loading, see [`ParseMode::Lenient`]
+    pub parse_warnings: Vec<ParseIssue>,
+}
+
+impl Default for Sheet {
+    fn default() -> Self {
+        Self::new_sheet()
+    }
 }
 
 impl Sheet {
     /// new_sheet initialize a Sheet
+    #[allow(deprecated)]
     fn new_sheet() -> Self {
         Self {
             data: Vec::<Row>::new(),
+            column_matching: ColumnMatching::default(),
+            validators: Vec::new(),
+            delimiter: ',',
+            tracking: None,
+            transformers: TransformerRegistry::default(),
+            comments: Vec::new(),
+            styles: Vec::new(),
+            parse_warnings: Vec::new(),
         }
     }
 
@@ -165,7 +857,9 @@ impl Sheet {
     ///
     /// This function reads the content of a CSV file specified by `file_path` and populates
     /// the Sheet's data structure accordingly. The file must have a ".csv" extension, and
-    /// its content should be in CSV (Comma-Separated Values) format.
+    /// its content should be in CSV (Comma-Separated Values) format. With the `gzip` feature,
+    /// ".csv.gz" files are decompressed on the fly. A UTF-8 byte order mark at the start of the
+    /// file, as written by Excel, is skipped.
     ///
     /// # Arguments
     ///
@@ -188,448 +882,2838 @@ impl Sheet {
     /// }
     /// ```
     pub fn load_data(file_path: &str) -> Result<Self, Box<dyn Error>> {
-        let mut sheet = Self::new_sheet();
         // check for ext
-        if file_path.split('.').last() != Some("csv") {
+        if strip_gzip(file_path).0.rsplit('.').next() != Some("csv") {
             return Err(Box::from(
                 "the provided file path is invalid, or of unsupported format",
             ));
         }
 
-        let f = File::open(file_path)?;
-        let mut reader = BufReader::new(f);
-        let mut data = String::new();
-
-        reader.read_to_string(&mut data)?;
-
-        data.lines().for_each(|line| {
-            let row: Row = line.split(',').map(|s| s.trim()).map(parse_token).collect();
-            sheet.data.push(row);
-        });
-
-        // if some column values are absent from a row, then fill it with a default Cell::Null
-        sheet.normalize_cols();
-
-        Ok(sheet)
-    }
-
-    pub fn load_data_from_str(data: &str) -> Self {
-        let mut sheet = Self::new_sheet();
-
-        data.lines().for_each(|line| {
-            let row: Row = line.split(',').map(|s| s.trim()).map(parse_token).collect();
-            sheet.data.push(row);
-        });
-
-        // if some column values are absent from a row, then fill it with a default Cell::Null
-        sheet.normalize_cols();
-
-        sheet
-    }
-
-    fn normalize_cols(&mut self) {
-        let col_len = self.data[0].len();
-        for i in 1..self.data.len() {
-            let row_len = self.data[i].len();
-            if row_len < col_len {
-                for _ in 0..col_len - row_len {
-                    self.data[i].push(Cell::Null);
-                }
-            }
-        }
+        Self::load_data_with(file_path, &LoadOptions::default())
     }
 
-    /// Exports the content of a Sheet to a CSV file.
+    /// Loads data from a delimited text file, parsed according to the given options.
     ///
-    /// The function writes the content of the Sheet into a CSV file specified by `file_path`.
-    /// If the file already exists, it truncates the file and overwrites its content.
+    /// This makes it possible to load TSV files or semicolon separated files, as produced by
+    /// spreadsheet software in many European locales. The delimiter is remembered by the Sheet
+    /// and reused by [`Sheet::export`].
     ///
     /// # Arguments
     ///
-    /// * `file_path` - The path to the CSV file.
+    /// * `file_path` - The path to the file to load, with a ".csv", ".tsv" or ".txt" extension,
+    ///   optionally followed by ".gz" for gzip compressed files (requires the `gzip` feature).
+    /// * `options` - How the content of the file is parsed.
     ///
-    /// # Examples
+    /// # Errors
     ///
-    /// ```rust
-    /// let cell_string = Cell::String(String::from("Hello, Rust!"));
-    /// let cell_int = Cell::Int(42);
+    /// Returns an error if the file has an unsupported extension or cannot be read, or if it
+    /// holds a non-finite value under [`NonFinitePolicy::Reject`].
     ///
-    /// let row1 = vec![cell_string, Cell::Bool(true), cell_int];
-    /// let row2 = vec![Cell::Null, Cell::Float(3.14), Cell::String(String::from("World"))];
+    /// # Examples
     ///
-    /// let sheet = Sheet { data: vec![row1, row2] };
+    /// ```rust,no_run
+    /// use datatroll::{LoadOptions, Sheet};
     ///
-    /// if let Err(err) = sheet.export("output.csv") {
-    ///     eprintln!("Error exporting data: {}", err);
-    /// } else {
-    ///     println!("Data exported successfully to output.csv");
-    /// }
+    /// let options = LoadOptions {
+    ///     delimiter: '\t',
+    ///     ..Default::default()
+    /// };
+    /// let sheet = Sheet::load_data_with("movies.tsv", &options).unwrap();
+    /// sheet.export("movies_clean.tsv").unwrap();
     /// ```
-    ///
-    /// # Errors
-    ///
-    /// Returns an `Result` indicating success or failure.
-    ///
-    pub fn export(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+    pub fn load_data_with(file_path: &str, options: &LoadOptions) -> Result<Self, Box<dyn Error>> {
+        let (stem, gzip) = strip_gzip(file_path);
         // check for ext
-        if file_path.split('.').last() != Some("csv") {
+        if !stem
+            .rsplit('.')
+            .next()
+            .is_some_and(|ext| DELIMITED_EXTENSIONS.contains(&ext))
+        {
             return Err(Box::from(
                 "the provided file path is invalid, or of unsupported format",
             ));
         }
 
-        let file = OpenOptions::new()
-            .write(true)
-            .truncate(true)
-            .create(true)
-            .open(file_path)?;
-
-        let mut buf_writer = BufWriter::new(file);
+        let f = File::open(file_path)?;
 
-        for row in &self.data {
-            for cell in row {
-                match cell {
-                    Cell::Null => write!(buf_writer, ",")?,
-                    Cell::String(s) => write!(buf_writer, "{},", s)?,
-                    Cell::Bool(b) => write!(buf_writer, "{},", b)?,
-                    Cell::Int(i) => write!(buf_writer, "{},", i)?,
-                    Cell::Float(f) => write!(buf_writer, "{},", f)?,
-                }
-            }
-            writeln!(buf_writer)?; // Move to the next line after each row
+        if gzip {
+            #[cfg(feature = "gzip")]
+            return Self::load_from_reader_with(
+                flate2::read::GzDecoder::new(BufReader::new(f)),
+                options,
+            );
+            #[cfg(not(feature = "gzip"))]
+            return Err(Box::from(GZIP_DISABLED));
         }
 
-        buf_writer.flush()?; // Ensure any remaining data is written to the file
-        Ok(())
+        Self::load_from_reader_with(BufReader::new(f), options)
     }
 
-    /// insert_row appends a row to the data sheet at the last position
-    ///
-    /// The function takes a comma seperated input string, trim the whitespace, parse it into a
-    /// vector oc Cell and then push it to the sheet.
-    ///
-    /// # Arguments
+    /// Loads CSV data from any reader into a Sheet.
     ///
-    /// * `input` - input string to be inserted.
+    /// This makes it possible to load data coming from sockets, in-memory buffers, decompressors
+    /// or test fixtures, without going through a file with a ".csv" extension. The whole input is
+    /// read before being parsed, so wrapping the reader in a `BufReader` isn't needed.
     ///
     /// # Errors
     ///
-    /// Returns a `Result` indicating success or an error if the input is of unvalid format
+    /// Returns an error if the reader fails, or if the input isn't valid UTF-8.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let row1 = vec![Cell::String("Hello, Rust!".to_string()), Cell::Bool(true), Cell::Int(42)];
-    /// let sheet = Sheet { data: vec![row1] };
+    /// use datatroll::{Cell, Sheet};
     ///
-    /// sheet.insert_row(",3.14,World")?;
+    /// let input: &[u8] = b"id,title\n1,old\n2,her";
+    /// let sheet = Sheet::load_from_reader(input).unwrap();
     ///
-    /// assert_eq!(sheet[0], row1);
-    /// assert_eq!(sheet[1], vec![Cell::Null, Cell::Float(3.14), Cell::String("World".to_string()]);
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::String("her".to_string()));
     /// ```
-    pub fn insert_row(&mut self, input: &str) -> Result<(), Box<dyn Error>> {
-        let row: Row = input
-            .split(',')
-            .map(|s| s.trim())
-            .map(parse_token)
-            .collect();
-        if row.len() != self.data[0].len() {
-            return Err(Box::from("invalid input"));
-        }
-
-        self.data.push(row);
-        Ok(())
+    pub fn load_from_reader<R: Read>(reader: R) -> Result<Self, Box<dyn Error>> {
+        Self::load_from_reader_with(reader, &LoadOptions::default())
     }
 
-    /// fill_col replace the value of a column in every row
-    ///
-    /// The function takes a column name and the value to be filled, and iterate through every row
-    /// and effectively replace its old cell values with the new value
-    ///
-    /// # Arguments
+    /// Loads CSV data from the standard input into a Sheet.
     ///
-    /// * `column` - the column to be mutated
-    /// * `value` - the value which every row will be filled with
+    /// This lets datatroll-based tools be composed with Unix pipelines, such as
+    /// `cat big.csv | mytool`. The whole input is read before being parsed.
     ///
     /// # Errors
     ///
-    /// Returns a `Result` indicating success or an error
+    /// Returns an error if the standard input can't be read, or isn't valid UTF-8.
     ///
     /// # Examples
     ///
-    /// ```rust
-    /// let row1 = vec![Cell::String("greeting".to_string()), Cell::String("is_good".to_string()), Cell::String("count".to_string())];
-    /// let row2 = vec![Cell::String("Hello, Rust!".to_string()), Cell::Bool(false), Cell::Int(42)];
-    /// let row3 = vec![Cell::String("Hello, World!".to_string()), Cell::Bool(true), Cell::Int(145)];
-    /// let sheet = Sheet { data: vec![row1, row2, row3] };
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_stdin().unwrap();
+    /// sheet.export_stdout().unwrap();
+    /// ```
+    pub fn load_stdin() -> Result<Self, Box<dyn Error>> {
+        Self::load_from_reader(std::io::stdin().lock())
+    }
+
+    /// Loads delimited data from any reader, parsed according to the given options.
+    ///
+    /// See [`Sheet::load_from_reader`] and [`Sheet::load_data_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader fails, if the input isn't valid in the configured encoding,
+    /// or if it holds a non-finite value under [`NonFinitePolicy::Reject`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{LoadOptions, Sheet};
+    ///
+    /// let options = LoadOptions {
+    ///     delimiter: '\t',
+    ///     ..Default::default()
+    /// };
+    /// let sheet = Sheet::load_from_reader_with(std::io::stdin(), &options).unwrap();
+    /// ```
+    pub fn load_from_reader_with<R: Read>(
+        mut reader: R,
+        options: &LoadOptions,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        Self::load_from_bytes(&bytes, options)
+    }
+
+    /// Loads delimited data from a byte buffer, parsed according to the given options.
+    ///
+    /// This is the bulk entry point of every delimited loader: the bytes are decoded according
+    /// to [`LoadOptions::encoding`], without copying when they're UTF-8, then parsed in a single
+    /// pass. It suits benchmarks and memory-mapped files alike.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't valid in the configured encoding, or if it holds a
+    /// non-finite value under [`NonFinitePolicy::Reject`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, LoadOptions, Sheet};
+    ///
+    /// let sheet = Sheet::load_from_bytes(b"id,title\n1,old", &LoadOptions::default()).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::String("old".to_string()));
+    /// ```
+    pub fn load_from_bytes(bytes: &[u8], options: &LoadOptions) -> Result<Self, Box<dyn Error>> {
+        let data = options.encoding.decode(bytes)?;
+
+        Self::load_data_from_str_with(&data, options)
+    }
+
+    pub fn load_data_from_str(data: &str) -> Self {
+        Self::parse_delimited(data, &LoadOptions::default()).0
+    }
+
+    /// Loads data from a delimited string, parsed according to the given options.
+    ///
+    /// See [`Sheet::load_data_with`] for the available options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the thousands separator is `.`, if the input holds a non-finite value
+    /// under [`NonFinitePolicy::Reject`], a row of the wrong width under [`RaggedRows::Error`],
+    /// or a [`ParseError`] if it holds malformed values under [`ParseMode::Strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, LoadOptions, Sheet};
+    ///
+    /// let options = LoadOptions {
+    ///     delimiter: ';',
+    ///     ..Default::default()
+    /// };
+    /// let sheet = Sheet::load_data_from_str_with("title;review\nold;3.5", &options).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::Float(3.5));
+    /// ```
+    pub fn load_data_from_str_with(
+        data: &str,
+        options: &LoadOptions,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::load_delimited_at(data, options, 0)
+    }
+
+    /// load_delimited_at loads delimited text which starts `line_offset` lines into its source,
+    /// so the lines reported in errors and warnings are those of the source
+    pub(crate) fn load_delimited_at(
+        data: &str,
+        options: &LoadOptions,
+        line_offset: usize,
+    ) -> Result<Self, Box<dyn Error>> {
+        if options.thousands_separator == Some('.') {
+            return Err(Box::from(
+                "'.' is the decimal mark, it can't be the thousands separator",
+            ));
+        }
+
+        let (mut sheet, mut lines) = Self::parse_delimited(data, options);
+        lines.iter_mut().for_each(|line| *line += line_offset);
+        if options.ragged_rows == RaggedRows::Error {
+            sheet.reject_ragged_rows(&lines)?;
+        }
+        sheet.apply_parse_mode(options.parse_mode, &lines)?;
+        sheet.handle_non_finite(options.non_finite)?;
+
+        Ok(sheet)
+    }
+
+    /// parse_delimited splits every record of the input on the configured delimiter and infers
+    /// the type of every value
+    #[allow(deprecated)]
+    fn parse_delimited(data: &str, options: &LoadOptions) -> (Self, Vec<usize>) {
+        let data = skip_lines(strip_bom(data), options.skip_rows, options.skip_footer);
+        let mut sheet = Self::new_sheet();
+        sheet.delimiter = options.delimiter;
+
+        let (records, comments) = split_records(data, options);
+        sheet.comments = comments.into_iter().map(str::to_string).collect();
+        let mut records = records.into_iter();
+        let header: Option<Row> = if options.header {
+            records.next().map(|(_, fields)| {
+                fields
+                    .into_iter()
+                    .map(|field| match trim_field(field, options) {
+                        (token, true) => Cell::String(token.into_owned()),
+                        (token, false) => parse_token(&token),
+                    })
+                    .collect()
+            })
+        } else {
+            // every record is data, the columns are named after their position
+            let width = records
+                .as_slice()
+                .iter()
+                .map(|(_, fields)| fields.len())
+                .max();
+            width.map(|width| generated_names(width).map(Cell::String).collect())
+        };
+        sheet.data.extend(header);
+
+        // the scientific notation policy of every column, in header order
+        let policies: Vec<ScientificNotation> = sheet
+            .data
+            .first()
+            .map(|header| {
+                header
+                    .iter()
+                    .map(|name| {
+                        let name = name.to_string();
+                        options
+                            .scientific_notation_columns
+                            .iter()
+                            .find(|(column, _)| *column == name)
+                            .map_or(options.scientific_notation, |(_, policy)| *policy)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut lines = Vec::new();
+        records.for_each(|(line, fields)| {
+            lines.push(line + options.skip_rows);
+            let row: Row = fields
+                .into_iter()
+                .map(|field| trim_field(field, options))
+                .enumerate()
+                .map(|(j, (token, quoted))| {
+                    let policy = policies.get(j);
+                    // grouped numbers are usually quoted, as they hold the delimiter
+                    let grouped = options
+                        .thousands_separator
+                        .filter(|_| policy != Some(&ScientificNotation::KeepAsString))
+                        .and_then(|separator| parse_grouped(&token, separator));
+                    if let Some(number) = grouped {
+                        return number;
+                    }
+                    match policy {
+                        _ if quoted => Cell::String(token.into_owned()),
+                        Some(ScientificNotation::KeepAsString) if is_scientific(&token) => {
+                            Cell::String(token.into_owned())
+                        }
+                        _ => parse_token(&token),
+                    }
+                })
+                .collect();
+            sheet.data.push(row);
+        });
+
+        sheet.fit_rows(options.ragged_rows);
+
+        (sheet, lines)
+    }
+
+    /// fit_rows gives every row the width of the header according to a ragged rows policy,
+    /// leaving the rows untouched under [`RaggedRows::Error`]
+    #[allow(deprecated)]
+    fn fit_rows(&mut self, policy: RaggedRows) {
+        let Some(header_len) = self.data.first().map(|header| header.len()) else {
+            return;
+        };
+        match policy {
+            RaggedRows::Error => return,
+            RaggedRows::Truncate => {
+                for row in self.data.iter_mut().skip(1) {
+                    row.truncate(header_len);
+                }
+            }
+            RaggedRows::PadNull => {
+                let width = self.data.iter().map(|row| row.len()).max().unwrap_or(0);
+                for name in generated_names(width).skip(header_len) {
+                    // the header may already hold a generated name, such as `col_3`
+                    let mut unique = name.clone();
+                    let mut n = 1;
+                    while self.get_col_index(&unique).is_some() {
+                        n += 1;
+                        unique = format!("{name}_{n}");
+                    }
+                    self.data[0].push(Cell::String(unique));
+                }
+            }
+        }
+
+        // if some column values are absent from a row, then fill it with a default Cell::Null
+        self.normalize_cols();
+    }
+
+    /// reject_ragged_rows fails on the first row whose width differs from the header's, `lines`
+    /// holding the line every data row starts on
+    #[allow(deprecated)]
+    fn reject_ragged_rows(&self, lines: &[usize]) -> Result<(), Box<dyn Error>> {
+        let Some(header_len) = self.data.first().map(|header| header.len()) else {
+            return Ok(());
+        };
+        let mut ragged = self
+            .data
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, row)| row.len() != header_len);
+
+        let Some((i, row)) = ragged.next() else {
+            return Ok(());
+        };
+        let mut msg = format!(
+            "line {} holds {} values, expected {header_len}",
+            lines.get(i - 1).copied().unwrap_or(i + 1),
+            row.len()
+        );
+        match ragged.count() {
+            0 => {}
+            1 => msg.push_str(", along with 1 other ragged row"),
+            n => msg.push_str(&format!(", along with {n} other ragged rows")),
+        }
+
+        Err(Box::from(msg))
+    }
+
+    #[allow(deprecated)]
+    fn normalize_cols(&mut self) {
+        let Some(col_len) = self.data.first().map(|header| header.len()) else {
+            return;
+        };
+        for i in 1..self.data.len() {
+            let row_len = self.data[i].len();
+            if row_len < col_len {
+                for _ in 0..col_len - row_len {
+                    self.data[i].push(Cell::Null);
+                }
+            }
+        }
+    }
+
+    /// Exports the content of a Sheet to a CSV file.
+    ///
+    /// The function writes the content of the Sheet into a CSV file specified by `file_path`,
+    /// separating values with the sheet's `delimiter`. If the file already exists, it truncates
+    /// the file and overwrites its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the CSV file, with a ".csv", ".tsv" or ".txt" extension,
+    ///   optionally followed by ".gz" to write it gzip compressed (requires the `gzip` feature).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::from_rows(
+    ///     &["greeting", "is_good", "count"],
+    ///     vec![
+    ///         vec![Cell::String(String::from("Hello, Rust!")), Cell::Bool(true), Cell::Int(42)],
+    ///         vec![Cell::Null, Cell::Float(3.14), Cell::String(String::from("World"))],
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// if let Err(err) = sheet.export("output.csv") {
+    ///     eprintln!("Error exporting data: {}", err);
+    /// } else {
+    ///     println!("Data exported successfully to output.csv");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Result` indicating success or failure.
+    ///
+    pub fn export(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        write_delimited_file(file_path, |w| self.write_delimited(w))
+    }
+
+    /// Exports the content of a Sheet to a delimited file, formatted according to the given
+    /// options.
+    ///
+    /// Unlike [`Sheet::export`], values are only separated by the delimiter, without one at the
+    /// end of every line. Named profiles, such as `"excel-eu"` or `"postgres-copy"`, capture the
+    /// formats expected by common consumers, see [`ExportOptions::profile`].
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file, with a ".csv", ".tsv" or ".txt" extension,
+    ///   optionally followed by ".gz" to write it gzip compressed (requires the `gzip` feature).
+    /// * `options` - How the values are written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file has an unsupported extension, or can't be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{ExportOptions, Sheet};
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet
+    ///     .export_with("movies_excel.csv", &ExportOptions::profile("excel-eu").unwrap())
+    ///     .unwrap();
+    /// ```
+    pub fn export_with(
+        &self,
+        file_path: &str,
+        options: &ExportOptions,
+    ) -> Result<(), Box<dyn Error>> {
+        write_delimited_file(file_path, |w| self.write_with_options(w, options))
+    }
+
+    /// Exports the content of a Sheet into any writer, formatted according to the given options.
+    ///
+    /// See [`Sheet::export_with`] and [`Sheet::export_to_writer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to or flushing the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{ExportOptions, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,,");
+    /// let mut out = Vec::new();
+    /// sheet
+    ///     .export_to_writer_with(&mut out, &ExportOptions::profile("postgres-copy").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(out, b"1\told\t3.5\n2\t\\N\t\\N\n");
+    /// ```
+    pub fn export_to_writer_with<W: Write>(
+        &self,
+        mut writer: W,
+        options: &ExportOptions,
+    ) -> Result<(), Box<dyn Error>> {
+        self.write_with_options(&mut writer, options)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// write_with_options writes the rows of the Sheet, formatted according to the given options
+    #[allow(deprecated)]
+    fn write_with_options<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        options: &ExportOptions,
+    ) -> std::io::Result<()> {
+        if options.encoding != TextEncoding::Utf8 {
+            let utf8_options = ExportOptions {
+                encoding: TextEncoding::Utf8,
+                ..options.clone()
+            };
+            let mut utf8 = Vec::new();
+            self.write_with_options(&mut utf8, &utf8_options)?;
+            let text = String::from_utf8(utf8).expect("cells are valid UTF-8");
+            return w.write_all(&options.encoding.encode(&text)?);
+        }
+
+        if options.bom {
+            w.write_all("\u{feff}".as_bytes())?;
+        }
+
+        let delimiter = options.delimiter.to_string();
+        let skip = if options.header { 0 } else { 1 };
+        for row in self.data.iter().skip(skip) {
+            for (j, cell) in row.iter().enumerate() {
+                if j > 0 {
+                    w.write_all(delimiter.as_bytes())?;
+                }
+
+                let mut value = match cell {
+                    Cell::Null => {
+                        w.write_all(options.null.as_bytes())?;
+                        continue;
+                    }
+                    Cell::Float(f) if options.decimal_separator != '.' => f
+                        .to_string()
+                        .replace('.', &options.decimal_separator.to_string()),
+                    cell => cell.to_string(),
+                };
+                if options.backslash_escapes {
+                    value = backslash_escape(&value, options.delimiter);
+                } else if let Cell::String(_) = cell {
+                    value = quote_field(
+                        &value,
+                        options.delimiter,
+                        options.quote_char,
+                        options.escape_char,
+                    )
+                    .into_owned();
+                }
+                w.write_all(value.as_bytes())?;
+            }
+            w.write_all(options.line_terminator.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Exports the content of a Sheet as CSV into any writer.
+    ///
+    /// Rows are written the same way as by [`Sheet::export`], using the sheet's `delimiter`, which
+    /// makes it possible to stream a Sheet into an HTTP response body, a pipe or an in-memory
+    /// buffer. The writer is flushed once every row is written, but isn't buffered: wrap it in a
+    /// `BufWriter` when writing to a file or a socket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to or flushing the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title\n1,old");
+    /// let mut out = Vec::new();
+    /// sheet.export_to_writer(&mut out).unwrap();
+    ///
+    /// assert_eq!(out, b"id,title,\n1,old,\n");
+    /// ```
+    pub fn export_to_writer<W: Write>(&self, mut writer: W) -> Result<(), Box<dyn Error>> {
+        self.write_delimited(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Exports the content of a Sheet as CSV to the standard output.
+    ///
+    /// Rows are written the same way as by [`Sheet::export`]. When the reading end of the pipe
+    /// is closed early, as in `mytool | head`, the remaining rows are silently dropped instead of
+    /// failing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the standard output can't be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title\n1,old");
+    /// sheet.export_stdout().unwrap();
+    /// ```
+    pub fn export_stdout(&self) -> Result<(), Box<dyn Error>> {
+        let stdout = std::io::stdout();
+        ignore_broken_pipe(self.export_to_writer(BufWriter::new(stdout.lock())))
+    }
+
+    /// Renders the content of a Sheet as a CSV string, as written by [`Sheet::export`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title\n1,old\n2,");
+    ///
+    /// assert_eq!(sheet.to_csv_string(), "id,title,\n1,old,\n2,,\n");
+    /// ```
+    pub fn to_csv_string(&self) -> String {
+        let mut out = Vec::new();
+        self.write_delimited(&mut out)
+            .expect("writing to a Vec<u8> can't fail");
+
+        String::from_utf8(out).expect("cells are valid UTF-8")
+    }
+
+    /// write_delimited writes the rows of the Sheet, separated by its delimiter
+    #[allow(deprecated)]
+    fn write_delimited<W: Write + ?Sized>(&self, buf_writer: &mut W) -> std::io::Result<()> {
+        for row in &self.data {
+            for cell in row {
+                let d = self.delimiter;
+                match cell {
+                    Cell::Null => write!(buf_writer, "{}", d)?,
+                    Cell::String(s) => write!(buf_writer, "{}{}", quote_field(s, d, '"', None), d)?,
+                    Cell::Bool(b) => write!(buf_writer, "{}{}", b, d)?,
+                    Cell::Int(i) => write!(buf_writer, "{}{}", i, d)?,
+                    Cell::UInt(u) => write!(buf_writer, "{}{}", u, d)?,
+                    Cell::Float(f) => write!(buf_writer, "{}{}", f, d)?,
+                }
+            }
+            writeln!(buf_writer)?; // Move to the next line after each row
+        }
+
+        Ok(())
+    }
+
+    /// insert_row appends a row to the data sheet at the last position
+    ///
+    /// The function takes a comma seperated input string, trim the whitespace, parse it into a
+    /// vector oc Cell and then push it to the sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - input string to be inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Result` indicating success or an error if the input is of unvalid format
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::from_rows(&["greeting", "is_good", "count"], vec![]).unwrap();
+    ///
+    /// sheet.insert_row(",3.14,World").unwrap();
+    ///
+    /// assert_eq!(sheet.row_count(), 1);
+    /// assert_eq!(sheet.row(0).unwrap(), vec![Cell::Null, Cell::Float(3.14), Cell::String("World".to_string())]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn insert_row(&mut self, input: &str) -> Result<(), Box<dyn Error>> {
+        let row: Row = input
+            .split(',')
+            .map(|s| s.trim())
+            .map(parse_token)
+            .collect();
+        if row.len() != self.data[0].len() {
+            return Err(Box::from("invalid input"));
+        }
+        self.validate_row(&row)?;
+
+        self.data.push(row);
+        Ok(())
+    }
+
+    /// merge_updates upserts the rows of another sheet, matching them by a key column
+    ///
+    /// Rows of `other` whose key doesn't exist in the sheet are appended, with the columns
+    /// `other` lacks filled with `Cell::Null`. Rows whose key already exists are resolved
+    /// according to `strategy`. Columns are matched by name, so `other` may hold a subset of the
+    /// sheet's columns in any order. This is handy for applying incremental correction files.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - the sheet holding the updates
+    /// * `key` - the name of the key column, present in both sheets
+    /// * `strategy` - how to resolve keys present in both sheets
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key column is missing from either sheet, if `other` holds a
+    /// column the sheet doesn't have, or if two rows conflict under [`MergeStrategy::Error`].
+    /// The sheet is left untouched in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, MergeStrategy, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,her,4.2");
+    /// let fixes = Sheet::load_data_from_str("id,review\n2,4.8\n3,2.0");
+    ///
+    /// sheet.merge_updates(&fixes, "id", MergeStrategy::PreferOther).unwrap();
+    ///
+    /// assert_eq!(sheet.row(1).unwrap()[2], Cell::Float(4.8));
+    /// assert_eq!(sheet.row(2).unwrap(), vec![Cell::Int(3), Cell::Null, Cell::Float(2.0)]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn merge_updates(
+        &mut self,
+        other: &Sheet,
+        key: &str,
+        strategy: MergeStrategy,
+    ) -> Result<(), Box<dyn Error>> {
+        let self_key = self.col_index(key)?;
+        let other_key = other.col_index(key)?;
+        let mapping = other.data[0]
+            .iter()
+            .map(|name| self.col_index(name.to_string().as_str()))
+            .collect::<Result<Vec<usize>, String>>()?;
+        let width = self.data[0].len();
+
+        // applies a row of `other` on top of a row of the sheet
+        let apply = |target: &Row, source: &Row| -> Result<Option<Row>, Box<dyn Error>> {
+            let conflict = mapping
+                .iter()
+                .enumerate()
+                .any(|(j, &t)| j < source.len() && CellKey(&target[t]) != CellKey(&source[j]));
+            match strategy {
+                MergeStrategy::PreferSelf => Ok(None),
+                MergeStrategy::Error if conflict => Err(Box::from(format!(
+                    "conflicting values for key '{}'",
+                    source[other_key]
+                ))),
+                MergeStrategy::Error => Ok(None),
+                MergeStrategy::PreferOther => {
+                    let mut row = target.clone();
+                    for (j, &t) in mapping.iter().enumerate() {
+                        row[t] = source.get(j).cloned().unwrap_or(Cell::Null);
+                    }
+                    Ok(Some(row))
+                }
+            }
+        };
+
+        // the updated rows, keyed by row index
+        let mut updates: HashMap<usize, Row> = HashMap::new();
+        let mut inserts: Vec<Row> = Vec::new();
+        {
+            let mut existing: HashMap<CellKey, usize> = HashMap::new();
+            for (i, row) in self.data.iter().enumerate().skip(1) {
+                existing.entry(CellKey(&row[self_key])).or_insert(i);
+            }
+            let mut pending: HashMap<CellKey, usize> = HashMap::new();
+
+            for source in other.data.iter().skip(1) {
+                let k = CellKey(&source[other_key]);
+                if let Some(&i) = existing.get(&k) {
+                    let target = updates.get(&i).unwrap_or(&self.data[i]);
+                    if let Some(row) = apply(target, source)? {
+                        updates.insert(i, row);
+                    }
+                } else if let Some(&p) = pending.get(&k) {
+                    if let Some(row) = apply(&inserts[p], source)? {
+                        inserts[p] = row;
+                    }
+                } else {
+                    let mut row = Row(vec![Cell::Null; width]);
+                    for (j, &t) in mapping.iter().enumerate() {
+                        row[t] = source.get(j).cloned().unwrap_or(Cell::Null);
+                    }
+                    pending.insert(k, inserts.len());
+                    inserts.push(row);
+                }
+            }
+        }
+
+        for (i, row) in updates {
+            self.data[i] = row;
+        }
+        self.data.extend(inserts);
+
+        Ok(())
+    }
+
+    /// approx_equals compares two sheets, allowing numbers to differ by a tolerance
+    ///
+    /// Both sheets must have the same shape, and their cells are compared pairwise. A float is
+    /// equal to another float or to an integer when they differ by at most `float_tolerance`,
+    /// and two NaN values are considered equal. Every other cell, integers compared to integers
+    /// included, must be strictly equal. This is meant for checking pipeline outputs against
+    /// golden files, where floating point noise in the last bits shouldn't fail the comparison.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - the sheet to compare against
+    /// * `float_tolerance` - the largest absolute difference allowed between two numbers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let got = Sheet::load_data_from_str("id,review\n1,0.30000000000000004");
+    /// let want = Sheet::load_data_from_str("id,review\n1,0.3");
+    ///
+    /// assert!(got.approx_equals(&want, 1e-9));
+    /// assert!(!got.approx_equals(&want, 0.0));
+    /// ```
+    #[allow(deprecated)]
+    pub fn approx_equals(&self, other: &Sheet, float_tolerance: f64) -> bool {
+        let as_number = |cell: &Cell| match cell {
+            Cell::Int(x) => Some(*x as f64),
+            Cell::UInt(u) => Some(*u as f64),
+            Cell::Float(f) => Some(*f),
+            _ => None,
+        };
+
+        self.data.len() == other.data.len()
+            && self.data.iter().zip(&other.data).all(|(a, b)| {
+                a.len() == b.len()
+                    && a.iter().zip(b.iter()).all(|(x, y)| match (x, y) {
+                        (Cell::Int(_) | Cell::UInt(_), Cell::Int(_) | Cell::UInt(_)) => x == y,
+                        _ => match (as_number(x), as_number(y)) {
+                            (Some(x), Some(y)) if x.is_nan() || y.is_nan() => {
+                                x.is_nan() && y.is_nan()
+                            }
+                            (Some(x), Some(y)) => (x - y).abs() <= float_tolerance,
+                            _ => x == y,
+                        },
+                    })
+            })
+    }
+
+    /// insert_row_cells appends a row made of already typed cells to the data sheet
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row doesn't have as many cells as the header, or if it is
+    /// rejected by one of the sheet's validators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,title");
+    /// sheet.insert_row_cells(vec![Cell::Int(1), Cell::String("old".to_string())]).unwrap();
+    ///
+    /// assert_eq!(sheet.row_count(), 1);
+    /// ```
+    #[allow(deprecated)]
+    pub fn insert_row_cells(&mut self, cells: Vec<Cell>) -> Result<(), Box<dyn Error>> {
+        let row = Row(cells);
+        if row.len() != self.data[0].len() {
+            return Err(Box::from("invalid input"));
+        }
+        self.validate_row(&row)?;
+
+        self.data.push(row);
+        Ok(())
+    }
+
+    /// extend appends several rows of typed cells to the data sheet
+    ///
+    /// Every row is checked before any of them is inserted, so either all of the rows are
+    /// appended or none of them are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a row doesn't have as many cells as the header, or if it is rejected
+    /// by one of the sheet's validators.
+    #[allow(deprecated)]
+    pub fn extend<I>(&mut self, rows: I) -> Result<(), Box<dyn Error>>
+    where
+        I: IntoIterator<Item = Vec<Cell>>,
+    {
+        let rows: Vec<Row> = rows.into_iter().map(Row).collect();
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != self.data[0].len() {
+                return Err(Box::from(format!("invalid input for row '{i}'")));
+            }
+            self.validate_row(row)
+                .map_err(|err| format!("row '{i}': {err}"))?;
+        }
+
+        self.data.extend(rows);
+        Ok(())
+    }
+
+    /// add_validator registers a rule that the values of `column` must satisfy for a row to be
+    /// inserted by [`Sheet::insert_row`], [`Sheet::insert_row_cells`] or [`Sheet::extend`]
+    ///
+    /// Rules can check types as well as business constraints. Rows already in the sheet are not
+    /// re-validated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,review\n1,3.5");
+    /// sheet.add_validator("review", |c| matches!(c, Cell::Float(r) if (0.0..=5.0).contains(r)));
+    ///
+    /// assert!(sheet.insert_row("2, 4.5").is_ok());
+    /// assert!(sheet.insert_row("3, 9.5").is_err());
+    /// ```
+    pub fn add_validator<F>(&mut self, column: &str, rule: F)
+    where
+        F: Fn(&Cell) -> bool + Send + Sync + 'static,
+    {
+        self.validators.push(Validator::new(column, rule));
+    }
+
+    /// validate_row checks a row against every validator of the sheet
+    fn validate_row(&self, row: &Row) -> Result<(), Box<dyn Error>> {
+        for validator in &self.validators {
+            let col_index = self.col_index(validator.column.as_str())?;
+            let cell = &row[col_index];
+            if !(validator.rule)(cell) {
+                return Err(Box::from(format!(
+                    "value '{cell}' in column '{}' failed validation",
+                    validator.column
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// fill_col replace the value of a column in every row
+    ///
+    /// The function takes a column name and the value to be filled, and iterate through every row
+    /// and effectively replace its old cell values with the new value
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the column to be mutated
+    /// * `value` - the value which every row will be filled with
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Result` indicating success or an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::from_rows(
+    ///     &["greeting", "is_good", "count"],
+    ///     vec![
+    ///         vec![Cell::String("Hello, Rust!".to_string()), Cell::Bool(false), Cell::Int(42)],
+    ///         vec![Cell::String("Hello, World!".to_string()), Cell::Bool(true), Cell::Int(145)],
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// sheet.fill_col("greeting", Cell::Null).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[0], Cell::Null);
+    /// assert_eq!(sheet.row(1).unwrap()[0], Cell::Null);
+    /// ```
+    #[allow(deprecated)]
+    pub fn fill_col<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        value: Cell,
+    ) -> Result<(), Box<dyn Error>> {
+        let col_index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
+        for i in 1..self.data.len() {
+            let cell = self.data[i]
+                .get_mut(col_index)
+                .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", col_index, i));
+
+            *cell = value.clone();
+        }
+
+        Ok(())
+    }
+
+    /// paginate takes part of a sheet with a fixed size and return it
+    ///
+    /// The function takes a page number and a page size, and slice the sheet and returns it as a page
+    /// of fixed size
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - the number of the page
+    /// * `size` - number of rows for every page
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Result` indicating success or an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let greetings = ["Rust", "World", "Dzair", "Africa", "Algeria", "Friday"];
+    /// let rows = greetings
+    ///     .iter()
+    ///     .map(|g| vec![Cell::String(format!("Hello, {g}!")), Cell::Bool(true), Cell::Int(145)])
+    ///     .collect();
+    /// let sheet = Sheet::from_rows(&["greeting", "is_good", "count"], rows).unwrap();
+    ///
+    /// let page = sheet.paginate(1, 2).unwrap();
+    ///
+    /// assert_eq!(page[0][0], Cell::String("Hello, Rust!".to_string()));
+    /// assert_eq!(page[1][0], Cell::String("Hello, World!".to_string()));
+    /// ```
+    #[allow(deprecated)]
+    pub fn paginate(&self, page: usize, size: usize) -> Result<Vec<Row>, Box<dyn Error>> {
+        if page < 1 || size > 50 {
+            return Err(Box::from(
+                "page should more than or equal 1, size should 50 per page at max",
+            ));
+        }
+        if self.data.len() < size {
+            return Err(Box::from("page unavailabe"));
+        }
+
+        let mut res: Vec<Row> = Default::default();
+        let offset = ((page - 1) * size) + 1;
+
+        for i in offset..(offset + size) {
+            let row = self.data.get(i).unwrap_or_else(|| {
+                panic!(
+                    "offset '{}' and amount '{}' are out of bounds",
+                    offset, size
+                )
+            });
+            res.push(row.clone())
+        }
+
+        Ok(res)
+    }
+
+    /// split_into divides the sheet into `n` shards of roughly equal size
+    ///
+    /// The data rows are distributed in order, with the first shards receiving one extra row when
+    /// the rows can't be divided evenly. Every shard carries a copy of the header row, so each one
+    /// can be processed or exported on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - the number of shards to produce
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,name\n1,a\n2,b\n3,c");
+    /// let shards = sheet.split_into(2).unwrap();
+    ///
+    /// assert_eq!(shards[0].row_count(), 2);
+    /// assert_eq!(shards[1].row_count(), 1);
+    /// ```
+    #[allow(deprecated)]
+    pub fn split_into(&self, n: usize) -> Result<Vec<Sheet>, Box<dyn Error>> {
+        if n == 0 {
+            return Err(Box::from("the number of shards should be more than 0"));
+        }
+
+        let rows = &self.data[1..];
+        let (size, rem) = (rows.len() / n, rows.len() % n);
+        let mut shards = Vec::with_capacity(n);
+        let mut offset = 0;
+
+        for i in 0..n {
+            let len = if i < rem { size + 1 } else { size };
+            shards.push(self.shard(&rows[offset..offset + len]));
+            offset += len;
+        }
+
+        Ok(shards)
+    }
+
+    /// split_by_rows divides the sheet into shards of at most `chunk_size` rows
+    ///
+    /// The last shard holds the remaining rows. Every shard carries a copy of the header row.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - the maximum number of data rows per shard
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `chunk_size` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,name\n1,a\n2,b\n3,c");
+    /// let shards = sheet.split_by_rows(2).unwrap();
+    ///
+    /// assert_eq!(shards.len(), 2);
+    /// ```
+    #[allow(deprecated)]
+    pub fn split_by_rows(&self, chunk_size: usize) -> Result<Vec<Sheet>, Box<dyn Error>> {
+        if chunk_size == 0 {
+            return Err(Box::from("chunk size should be more than 0"));
+        }
+
+        Ok(self.data[1..]
+            .chunks(chunk_size)
+            .map(|rows| self.shard(rows))
+            .collect())
+    }
+
+    /// shard builds a new sheet out of the header row and the given rows
+    #[allow(deprecated)]
+    fn shard(&self, rows: &[Row]) -> Sheet {
+        let mut sheet = Self::new_sheet();
+        sheet.delimiter = self.delimiter;
+        sheet.data.push(self.data[0].clone());
+        sheet.data.extend_from_slice(rows);
+
+        sheet
+    }
+
+    /// Finds the first row in the table that matches a predicate applied to a specific column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified column doesn't exist or is absent for a row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let sheet = Sheet::load_data_from_str(data);
+    /// let first_matching_row = sheet.find_first_row("review", |cell| matches!(cell, Cell::Float(r) if *r >= 4.0));
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// The `predicate` argument is a generic function that allows for flexible filtering criteria.
+    /// It accepts a reference to a `Cell` and returns a boolean indicating whether the row matches.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&Row>`:
+    /// - `Some(&row)` if a matching row is found, where `row` is a reference to the first matching row.
+    /// - `None` if no matching row is found.
+    #[allow(deprecated)]
+    pub fn find_first_row<'a, F>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        predicate: F,
+    ) -> Option<(Row, usize)>
+    where
+        F: Fn(&Cell) -> bool,
+    {
+        let col_index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
+
+        for i in 1..self.data.len() {
+            let cell = self.data[i]
+                .get(col_index)
+                .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", col_index, i));
+            if predicate(cell) {
+                return Some((self.data[i].clone(), i));
+            }
+        }
+
+        None
+    }
+
+    #[allow(deprecated)]
+    pub fn edit_cell<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        row_index: usize,
+        new_value: Cell,
+    ) -> Result<(), String> {
+        let i = self.col_index(column)?;
+        self.data[row_index][i] = new_value;
+
+        Ok(())
+    }
+
+    /// Finds rows in the table that match a predicate applied to a specific column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified column doesn't exist or is absent for a row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let sheet = Sheet::load_data_from_str(data);
+    /// let matching_rows = sheet.filter("review", |cell| matches!(cell, Cell::Float(r) if *r >= 4.0));
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// The `predicate` argument is a generic function that allows for flexible filtering criteria.
+    /// It accepts a reference to a `Cell` and returns a boolean indicating whether the row matches.
+    ///
+    /// # Returns
+    ///
+    /// A vector of vectors, where each inner vector represents a row that matches the predicate.
+    #[allow(deprecated)]
+    pub fn filter<'a, F>(&self, column: impl Into<ColumnRef<'a>>, predicate: F) -> Vec<Row>
+    where
+        F: Fn(&Cell) -> bool,
+    {
+        let col_index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
+        let mut res: Vec<Row> = Default::default();
+
+        for i in 1..self.data.len() {
+            let cell = self.data[i]
+                .get(col_index)
+                .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", col_index, i));
+            if predicate(cell) {
+                res.push(self.data[i].clone());
+            }
+        }
+
+        res
+    }
+
+    /// The map function applies a given transformation to each column value of rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Result` indicating success or an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Sheet, Cell};
+    ///
+    ///let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let mut sheet = Sheet::load_data_from_str(data);
+    ///
+    /// let result = sheet.map("title", |c| match c {
+    ///     Cell::String(s) => Cell::String(s.to_uppercase()),
+    ///     _ => c,
+    /// });
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    #[allow(deprecated)]
+    pub fn map<'a, F>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        transform: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(Cell) -> Cell,
+    {
+        let i = self.col_index(column)?;
+        self.data
+            .iter_mut()
+            .for_each(|row| row[i] = transform(row[i].clone()));
+
+        Ok(())
+    }
+
+    /// filtered returns a new Sheet holding the rows whose cell in `column` matches a predicate
+    ///
+    /// Unlike [`Sheet::filter`], the header row is kept, so the result can be chained with other
+    /// Sheet methods. The original Sheet is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("title,review\nold,3.5\nher,4.2\nwho,5.0");
+    /// let top = sheet
+    ///     .filtered("review", |c| matches!(c, Cell::Float(r) if *r >= 4.0))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(top.row_count(), 2);
+    /// assert_eq!(sheet.row_count(), 3);
+    /// ```
+    #[allow(deprecated)]
+    pub fn filtered<'a, F>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        predicate: F,
+    ) -> Result<Sheet, Box<dyn Error>>
+    where
+        F: Fn(&Cell) -> bool,
+    {
+        let col_index = self.col_index(column)?;
+        let rows: Vec<Row> = self
+            .data
+            .iter()
+            .skip(1)
+            .filter(|row| predicate(&row[col_index]))
+            .cloned()
+            .collect();
+
+        Ok(self.shard(&rows))
+    }
+
+    /// filter_in returns a new Sheet holding the rows whose cell in `column` is one of `values`
+    ///
+    /// The values are looked up in a hash set, so long whitelists stay cheap. Cells are compared
+    /// by type and value: `Cell::Int(1)` doesn't match `Cell::Float(1.0)`, while floats match
+    /// when their bits are equal, NaN included. The original Sheet is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title\n1,old\n2,her\n3,easy");
+    /// let picked = sheet.filter_in("id", &[Cell::Int(1), Cell::Int(3)]).unwrap();
+    ///
+    /// assert_eq!(picked.row_count(), 2);
+    /// assert_eq!(picked.row(1).unwrap()[1], Cell::String("easy".to_string()));
+    /// ```
+    pub fn filter_in<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        values: &[Cell],
+    ) -> Result<Sheet, Box<dyn Error>> {
+        self.filter_membership(column, values, true)
+    }
+
+    /// filter_not_in returns a new Sheet holding the rows whose cell in `column` is none of
+    /// `values`
+    ///
+    /// See [`Sheet::filter_in`] for how cells are compared. The original Sheet is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title\n1,old\n2,her\n3,easy");
+    /// let rest = sheet.filter_not_in("title", &[Cell::String("her".to_string())]).unwrap();
+    ///
+    /// assert_eq!(rest.row_count(), 2);
+    /// assert_eq!(rest.row(1).unwrap()[0], Cell::Int(3));
+    /// ```
+    pub fn filter_not_in<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        values: &[Cell],
+    ) -> Result<Sheet, Box<dyn Error>> {
+        self.filter_membership(column, values, false)
+    }
+
+    /// filter_membership keeps the rows whose cell in `column` belongs to `values` when `keep`
+    /// is true, or doesn't belong to them otherwise
+    #[allow(deprecated)]
+    fn filter_membership<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        values: &[Cell],
+        keep: bool,
+    ) -> Result<Sheet, Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        let set: HashSet<CellKey> = values.iter().map(CellKey).collect();
+        let rows: Vec<Row> = self
+            .data
+            .iter()
+            .skip(1)
+            .filter(|row| set.contains(&CellKey(&row[col_index])) == keep)
+            .cloned()
+            .collect();
+
+        Ok(self.shard(&rows))
+    }
+
+    /// filter_between returns a new Sheet holding the rows whose cell in `column` lies between
+    /// `low` and `high`
+    ///
+    /// Numeric bounds select numeric cells, integers and floats being compared by value, while
+    /// string bounds select string cells in lexicographic order, which is chronological for ISO
+    /// `YYYY-MM-DD` dates. Null cells and cells of another kind never match. The original Sheet
+    /// is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the column to filter on
+    /// * `low` - the lower bound
+    /// * `high` - the upper bound
+    /// * `inclusive` - whether values equal to a bound are kept
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, if a bound is neither a number nor a
+    /// string, if the bounds are of different kinds, or if `low` is greater than `high`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("title,review\nold,3.5\nher,4.2\nwho,5.0");
+    /// let mid = sheet
+    ///     .filter_between("review", &Cell::Int(4), &Cell::Float(5.0), false)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mid.row_count(), 1);
+    /// assert_eq!(mid.row(0).unwrap()[0], Cell::String("her".to_string()));
+    /// ```
+    #[allow(deprecated)]
+    pub fn filter_between<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        low: &Cell,
+        high: &Cell,
+        inclusive: bool,
+    ) -> Result<Sheet, Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        if !matches!(
+            low,
+            Cell::Int(_) | Cell::UInt(_) | Cell::Float(_) | Cell::String(_)
+        ) {
+            return Err(Box::from("the bounds should be numbers or strings"));
+        }
+        match compare_values(low, high) {
+            None => return Err(Box::from("the bounds should be of the same kind")),
+            Some(std::cmp::Ordering::Greater) => {
+                return Err(Box::from(
+                    "the low bound should not be greater than the high bound",
+                ))
+            }
+            _ => {}
+        }
+
+        let rows: Vec<Row> = self
+            .data
+            .iter()
+            .skip(1)
+            .filter(|row| {
+                let cell = &row[col_index];
+                match (compare_values(cell, low), compare_values(cell, high)) {
+                    (Some(std::cmp::Ordering::Greater), Some(std::cmp::Ordering::Less)) => true,
+                    (Some(l), Some(h)) => inclusive && l.is_ge() && h.is_le(),
+                    _ => false,
+                }
+            })
+            .cloned()
+            .collect();
+
+        Ok(self.shard(&rows))
+    }
+
+    /// mapped returns a new Sheet where a transformation is applied to every value of `column`
+    ///
+    /// Unlike [`Sheet::map`], the header cell is left out of the transformation. The original
+    /// Sheet is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("title,review\nold,3.5");
+    /// let upper = sheet
+    ///     .mapped("title", |c| match c {
+    ///         Cell::String(s) => Cell::String(s.to_uppercase()),
+    ///         c => c,
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(upper.header().unwrap()[0], Cell::String("title".to_string()));
+    /// assert_eq!(upper.row(0).unwrap()[0], Cell::String("OLD".to_string()));
+    /// assert_eq!(sheet.row(0).unwrap()[0], Cell::String("old".to_string()));
+    /// ```
+    #[allow(deprecated)]
+    pub fn mapped<'a, F>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        transform: F,
+    ) -> Result<Sheet, Box<dyn Error>>
+    where
+        F: Fn(Cell) -> Cell,
+    {
+        let col_index = self.col_index(column)?;
+        let mut sheet = self.shard(&self.data[1..]);
+        for row in sheet.data.iter_mut().skip(1) {
+            row[col_index] = transform(row[col_index].clone());
+        }
+
+        Ok(sheet)
+    }
+
+    /// sorted returns a new Sheet with the rows ordered by the values of `column`
+    ///
+    /// The sort is stable, so rows holding equal values keep their relative order and sorts can
+    /// be chained to order by several columns. Null values always come last, numbers are ordered
+    /// by value whatever their type, and values of different kinds are ordered by kind (strings,
+    /// bools, then numbers). The original Sheet is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the column to sort by
+    /// * `ascending` - whether the smallest values come first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("title,review\nold,3.5\nher,\nwho,5.0");
+    /// let best = sheet.sorted("review", false).unwrap();
+    ///
+    /// assert_eq!(best.row(0).unwrap()[0], Cell::String("who".to_string()));
+    /// assert_eq!(best.row(2).unwrap()[0], Cell::String("her".to_string()));
+    /// ```
+    #[allow(deprecated)]
+    pub fn sorted<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        ascending: bool,
+    ) -> Result<Sheet, Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        let mut sheet = self.shard(&self.data[1..]);
+        if !self.is_sorted(col_index, ascending)? {
+            sheet.data[1..].sort_by(|a, b| sort_order(&a[col_index], &b[col_index], ascending));
+        }
+
+        Ok(sheet)
+    }
+
+    /// is_sorted tells whether the rows are already ordered by the values of `column`, the way
+    /// [`Sheet::sorted`] orders them, nulls last
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("day,review\n1,4.5\n2,3.0\n3,");
+    ///
+    /// assert!(sheet.is_sorted("day", true).unwrap());
+    /// assert!(sheet.is_sorted("review", false).unwrap());
+    /// assert!(!sheet.is_sorted("review", true).unwrap());
+    /// ```
+    #[allow(deprecated)]
+    pub fn is_sorted<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        ascending: bool,
+    ) -> Result<bool, Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+
+        Ok(self
+            .data
+            .get(1..)
+            .unwrap_or_default()
+            .is_sorted_by(|a, b| sort_order(&a[col_index], &b[col_index], ascending).is_le()))
+    }
+
+    /// is_monotonic tells whether the values of `column` never decrease, or never increase, from
+    /// one row to the next, e.g. to validate timestamps or running totals
+    ///
+    /// Null cells are skipped. Numbers are compared by value whatever their type and strings
+    /// lexicographically, while a column holding values which can't be compared, such as bools,
+    /// NaN or values of different kinds, isn't monotonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("total,stock\n1,9\n2.5,\n2.5,7\n4,8");
+    ///
+    /// assert!(sheet.is_monotonic("total").unwrap());
+    /// assert!(!sheet.is_monotonic("stock").unwrap());
+    /// ```
+    #[allow(deprecated)]
+    pub fn is_monotonic<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        let values: Vec<&Cell> = self
+            .data
+            .iter()
+            .skip(1)
+            .map(|row| &row[col_index])
+            .filter(|cell| **cell != Cell::Null)
+            .collect();
+
+        // the direction of the values, set by the first pair of different values
+        let mut direction = std::cmp::Ordering::Equal;
+        for pair in values.windows(2) {
+            match compare_values(pair[0], pair[1]) {
+                None => return Ok(false),
+                Some(std::cmp::Ordering::Equal) => {}
+                Some(order) if direction.is_eq() => direction = order,
+                Some(order) if order != direction => return Ok(false),
+                Some(_) => {}
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// apply_cols computes a new column out of several existing columns
+    ///
+    /// The function is called once per row with the cells of `columns`, in the given order, and
+    /// its result is appended to the row as the value of the new column. This is handy for
+    /// derived values spanning several columns, such as a yearly total out of quarterly columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the new column
+    /// * `columns` - the columns passed to `f`
+    /// * `f` - computes the value of the new column out of the cells of a row
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the columns doesn't exist, or if a column named `name` already
+    /// exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("store,q1,q2,q3,q4\nnorth,10,20,30,40\nsouth,5,5,,5");
+    /// sheet
+    ///     .apply_cols("total", &["q1", "q2", "q3", "q4"], |cells| {
+    ///         let total = cells
+    ///             .iter()
+    ///             .map(|c| match c {
+    ///                 Cell::Int(x) => *x,
+    ///                 _ => 0,
+    ///             })
+    ///             .sum();
+    ///         Cell::Int(total)
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[5], Cell::Int(100));
+    /// assert_eq!(sheet.row(1).unwrap()[5], Cell::Int(15));
+    /// ```
+    #[allow(deprecated)]
+    pub fn apply_cols<'a, C, F>(
+        &mut self,
+        name: &str,
+        columns: &[C],
+        f: F,
+    ) -> Result<(), Box<dyn Error>>
+    where
+        C: Into<ColumnRef<'a>> + Copy,
+        F: Fn(&[&Cell]) -> Cell,
+    {
+        if self.get_col_index(name).is_some() {
+            return Err(Box::from(format!("column '{name}' already exists")));
+        }
+        let col_indices = self.col_indices(columns)?;
+
+        let mut cells: Vec<&Cell> = Vec::with_capacity(col_indices.len());
+        let values: Vec<Cell> = self
+            .data
+            .iter()
+            .skip(1)
+            .map(|row| {
+                cells.clear();
+                cells.extend(col_indices.iter().map(|&j| &row[j]));
+                f(&cells)
+            })
+            .collect();
+
+        self.data[0].push(Cell::String(name.to_string()));
+        for (row, value) in self.data.iter_mut().skip(1).zip(values) {
+            row.push(value);
+        }
+
+        Ok(())
+    }
+
+    /// hash_rows appends a column holding a hash of the selected cells of every row
+    ///
+    /// The hash is a 16 hex digits string which only depends on the values and types of the
+    /// selected cells, in the given order: it stays the same across runs and machines, so it can
+    /// be stored and used as a surrogate key for joins, or compared between two versions of a
+    /// dataset to detect changed rows. `Cell::Int(1)` and `Cell::String("1")` hash differently,
+    /// and so do `("ab", "c")` and `("a", "bc")`.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - the columns the hash is computed over
+    /// * `name` - the name of the new column
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the columns doesn't exist, or if a column named `name` already
+    /// exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("first,last,city\nada,byron,london\nada,byron,paris");
+    /// sheet.hash_rows(&["first", "last"], "row_key").unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[3], sheet.row(1).unwrap()[3]);
+    /// assert_eq!(sheet.row(0).unwrap()[3].to_string().len(), 16);
+    /// ```
+    pub fn hash_rows<'a, C>(&mut self, columns: &[C], name: &str) -> Result<(), Box<dyn Error>>
+    where
+        C: Into<ColumnRef<'a>> + Copy,
+    {
+        self.apply_cols(name, columns, |cells| {
+            let mut buf = Vec::new();
+            for cell in cells {
+                let tag: u8 = match cell {
+                    Cell::Null => 0,
+                    Cell::String(_) => 1,
+                    Cell::Bool(_) => 2,
+                    Cell::Int(_) => 3,
+                    Cell::UInt(_) => 4,
+                    Cell::Float(_) => 5,
+                };
+                let value = cell.to_string();
+                buf.push(tag);
+                buf.extend_from_slice(&(value.len() as u64).to_le_bytes());
+                buf.extend_from_slice(value.as_bytes());
+            }
+            Cell::String(format!("{:016x}", fnv1a(0, &buf)))
+        })
+    }
+
+    /// with_row_numbers appends a column numbering the rows in their current order
+    ///
+    /// Rows are numbered from `start`, incrementing by one, which gives datasets lacking a key a
+    /// stable identifier before they are sorted, filtered, joined or exported.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the new column
+    /// * `start` - the number of the first row
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a column named `name` already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("title,review\nold,3.5\nher,4.2");
+    /// sheet.with_row_numbers("row_id", 1).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[2], Cell::Int(1));
+    /// assert_eq!(sheet.row(1).unwrap()[2], Cell::Int(2));
+    /// ```
+    #[allow(deprecated)]
+    pub fn with_row_numbers(&mut self, name: &str, start: i64) -> Result<(), Box<dyn Error>> {
+        if self.get_col_index(name).is_some() {
+            return Err(Box::from(format!("column '{name}' already exists")));
+        }
+
+        self.data[0].push(Cell::String(name.to_string()));
+        for (number, row) in (start..).zip(self.data.iter_mut().skip(1)) {
+            row.push(Cell::Int(number));
+        }
+
+        Ok(())
+    }
+
+    /// generate_uuid_col appends a column holding a random UUID for every row
+    ///
+    /// The UUIDs are version 4 UUIDs in their hyphenated lowercase form, such as
+    /// `"0b7c1f3e-5a2d-4c8e-9f10-6d3b2a1c4e5f"`. Unlike [`Sheet::hash_rows`], they don't depend on
+    /// the content of the rows, so rows holding the same values still get distinct identifiers.
+    /// The generator isn't cryptographically secure, so the UUIDs shouldn't be used as secrets.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the new column
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a column named `name` already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("title,review\nold,3.5\nold,3.5");
+    /// sheet.generate_uuid_col("uid").unwrap();
+    ///
+    /// assert_ne!(sheet.row(0).unwrap()[2], sheet.row(1).unwrap()[2]);
+    /// assert_eq!(sheet.row(0).unwrap()[2].to_string().len(), 36);
+    /// ```
+    #[allow(deprecated)]
+    pub fn generate_uuid_col(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
+        if self.get_col_index(name).is_some() {
+            return Err(Box::from(format!("column '{name}' already exists")));
+        }
+
+        // RandomState is seeded with random keys by the standard library
+        let seed = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        let mut rng = sample::SplitMix64(seed);
+        self.data[0].push(Cell::String(name.to_string()));
+        for row in self.data.iter_mut().skip(1) {
+            row.push(Cell::String(uuid_v4(&mut rng)));
+        }
+
+        Ok(())
+    }
+
+    /// Removes rows from the table based on a predicate applied to a specific column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let mut sheet = Sheet::load_data_from_str(data);
+    /// sheet.drop_rows("review", |cell| matches!(cell, Cell::Float(r) if *r >= 4.0)); // Removes rows where review is 4.0 or higher
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// The `predicate` argument is a generic function that allows for flexible filtering criteria.
+    /// It accepts a reference to a `Cell` and returns a boolean indicating whether to keep the row.
+    #[allow(deprecated)]
+    pub fn drop_rows<'a, F>(&mut self, column: impl Into<ColumnRef<'a>>, predicate: F)
+    where
+        F: Fn(&Cell) -> bool,
+    {
+        let col_index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
+        self.data.retain(|row| !predicate(&row[col_index]));
+    }
+
+    /// Removes a specified column from the table and returns its cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified column doesn't exist.
+    ///
+    /// # Returns
+    ///
+    /// The removed cells in row order, starting with the header cell, so the column can be
+    /// transformed and re-inserted or moved into another Sheet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let mut sheet = Sheet::load_data_from_str(data);
+    /// let titles = sheet.drop_col("title"); // Removes the "title" column and returns its 6 cells
+    ///
+    /// assert_eq!(titles[0], Cell::String("title".to_string()));
+    /// assert_eq!(titles.len(), 6);
+    /// ```
+    #[allow(deprecated)]
+    pub fn drop_col<'a>(&mut self, column: impl Into<ColumnRef<'a>>) -> Vec<Cell> {
+        let col_index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
+
+        self.data
+            .iter_mut()
+            .map(|row| row.remove(col_index))
+            .collect()
+    }
+
+    /// Removes several columns from the table in a single pass.
+    ///
+    /// Every column is resolved before the sheet is touched, so either all of the columns are
+    /// removed or none of them are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the columns doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,title,director,review\n1,old,quintin,3.5");
+    /// sheet.drop_cols(&["title", "review"]).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap(), vec![Cell::Int(1), Cell::String("quintin".to_string())]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn drop_cols<'a, C>(&mut self, columns: &[C]) -> Result<(), Box<dyn Error>>
+    where
+        C: Into<ColumnRef<'a>> + Copy,
+    {
+        let drop = self.col_indices(columns)?;
+        let keep: Vec<usize> = (0..self.data[0].len())
+            .filter(|i| !drop.contains(i))
+            .collect();
+
+        self.project(&keep);
+        Ok(())
+    }
+
+    /// Keeps only the given columns, in the given order, removing every other column in a single
+    /// pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the columns doesn't exist or is listed twice, in which case the
+    /// sheet is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,title,director,review\n1,old,quintin,3.5");
+    /// sheet.keep_cols(&["review", "id"]).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap(), vec![Cell::Float(3.5), Cell::Int(1)]);
+    /// ```
+    pub fn keep_cols<'a, C>(&mut self, columns: &[C]) -> Result<(), Box<dyn Error>>
+    where
+        C: Into<ColumnRef<'a>> + Copy,
+    {
+        let keep = self.col_indices(columns)?;
+        if keep.iter().enumerate().any(|(i, c)| keep[..i].contains(c)) {
+            return Err(Box::from("columns to keep should be unique"));
+        }
+
+        self.project(&keep);
+        Ok(())
+    }
+
+    /// Renames columns according to a two-column mapping CSV file, whose rows hold an old column
+    /// name followed by its new name, after a header row such as `old,new`.
+    ///
+    /// This is handy to standardize vendor-specific column names in ingestion jobs, keeping the
+    /// mapping next to the job instead of in code. Every rename is applied at once, so names can
+    /// be swapped, and mapped names the sheet doesn't have are ignored, so a single mapping file
+    /// can serve several vendors.
+    ///
+    /// # Arguments
     ///
-    /// sheet.fill_col("greeting", Cell::Null)?;
+    /// * `path` - The path to the mapping file, with a ".csv" extension.
     ///
-    /// assert_eq!(sheet[1][0], Cell::Null);
-    /// assert_eq!(sheet[1][0], Cell::Null);
+    /// # Returns
+    ///
+    /// The number of columns that were renamed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mapping file can't be loaded, if it doesn't have exactly two
+    /// columns, if an old name is listed twice or a new name is empty, or if the renames would
+    /// leave two columns with the same name. The sheet is left untouched on error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let mut sheet = Sheet::load_data("vendor_export.csv").unwrap();
+    /// let renamed = sheet.apply_renames_from("mappings/vendor.csv").unwrap();
+    /// println!("renamed {renamed} columns");
     /// ```
-    pub fn fill_col(&mut self, column: &str, value: Cell) -> Result<(), Box<dyn Error>> {
-        let col_index = self.get_col_index(column).expect("column doesn't exist");
-        for i in 1..self.data.len() {
-            let cell = self.data[i]
-                .get_mut(col_index)
-                .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", col_index, i));
+    #[allow(deprecated)]
+    pub fn apply_renames_from(&mut self, path: &str) -> Result<usize, Box<dyn Error>> {
+        let mapping = Self::load_data(path)?;
+        if mapping.data[0].len() != 2 {
+            return Err(Box::from(format!(
+                "the rename mapping '{path}' should have exactly two columns"
+            )));
+        }
 
-            *cell = value.clone();
+        let mut renames: HashMap<String, String> = HashMap::new();
+        for row in mapping.data.iter().skip(1) {
+            let (old, new) = (row[0].to_string(), row[1].to_string());
+            if new.is_empty() {
+                return Err(Box::from(format!(
+                    "the new name of column '{old}' is empty"
+                )));
+            }
+            if renames.insert(old.clone(), new).is_some() {
+                return Err(Box::from(format!(
+                    "column '{old}' is renamed more than once"
+                )));
+            }
+        }
+
+        self.rename_cols(&renames)
+    }
+
+    /// rename_cols renames the columns found in the mapping, returning how many were renamed
+    ///
+    /// Returns an error, leaving the header untouched, if two columns would share a name.
+    #[allow(deprecated)]
+    fn rename_cols(&mut self, renames: &HashMap<String, String>) -> Result<usize, Box<dyn Error>> {
+        let header: Vec<String> = self.data[0]
+            .iter()
+            .map(|c| {
+                let name = c.to_string();
+                renames.get(&name).cloned().unwrap_or(name)
+            })
+            .collect();
+        for (i, name) in header.iter().enumerate() {
+            if header[..i].contains(name) {
+                return Err(Box::from(format!("column '{name}' already exists")));
+            }
+        }
+
+        let mut renamed = 0;
+        for (cell, name) in self.data[0].iter_mut().zip(header) {
+            if cell.to_string() != name {
+                *cell = Cell::String(name);
+                renamed += 1;
+            }
+        }
+
+        Ok(renamed)
+    }
+
+    /// col_indices resolves several column references at once
+    fn col_indices<'a, C>(&self, columns: &[C]) -> Result<Vec<usize>, String>
+    where
+        C: Into<ColumnRef<'a>> + Copy,
+    {
+        columns.iter().map(|&c| self.col_index(c)).collect()
+    }
+
+    /// project rebuilds every row out of the cells at the given column indices
+    #[allow(deprecated)]
+    fn project(&mut self, col_indices: &[usize]) {
+        for row in self.data.iter_mut() {
+            let mut cells = std::mem::take(&mut row.0);
+            *row = col_indices
+                .iter()
+                .map(|&i| std::mem::replace(&mut cells[i], Cell::Null))
+                .collect();
+        }
+    }
+
+    /// candidate_keys reports the columns that could serve as a key for the sheet
+    ///
+    /// A column is a candidate key when every one of its values is non-null and unique. Pairs of
+    /// columns are checked as well, and a pair is reported when its combined values are unique
+    /// and non-null while neither of its columns is a key on its own.
+    ///
+    /// # Returns
+    ///
+    /// A vector of column name sets, single columns first, followed by column pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,first,last\n1,ali,ben\n2,ali,sid\n3,sam,ben");
+    ///
+    /// assert_eq!(sheet.candidate_keys(), vec![vec!["id".to_string()], vec!["first".to_string(), "last".to_string()]]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn candidate_keys(&self) -> Vec<Vec<String>> {
+        let names: Vec<String> = self.data[0].iter().map(|c| c.to_string()).collect();
+        let mut keys: Vec<Vec<String>> = Vec::new();
+
+        let single: Vec<bool> = (0..names.len()).map(|i| self.is_unique_key(&[i])).collect();
+        for (i, name) in names.iter().enumerate() {
+            if single[i] {
+                keys.push(vec![name.clone()]);
+            }
+        }
+
+        for i in 0..names.len() {
+            for j in i + 1..names.len() {
+                if !single[i] && !single[j] && self.is_unique_key(&[i, j]) {
+                    keys.push(vec![names[i].clone(), names[j].clone()]);
+                }
+            }
+        }
+
+        keys
+    }
+
+    /// duplicated marks the rows which duplicate an earlier row under the given key columns
+    ///
+    /// The first occurrence of every key is not considered a duplicate. When `keys` is empty,
+    /// whole rows are compared. Null values are compared like any other value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the key columns doesn't exist.
+    ///
+    /// # Returns
+    ///
+    /// One boolean per data row (the header excluded), true when the row is a duplicate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,director\n1,nolan\n2,martin\n3,nolan");
+    ///
+    /// assert_eq!(sheet.duplicated(&["director"]).unwrap(), vec![false, false, true]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn duplicated<'a, C>(&self, keys: &[C]) -> Result<Vec<bool>, Box<dyn Error>>
+    where
+        C: Into<ColumnRef<'a>> + Copy,
+    {
+        let mut col_indices = self.col_indices(keys)?;
+        if col_indices.is_empty() {
+            col_indices = (0..self.data[0].len()).collect();
+        }
+
+        let mut seen: HashSet<Vec<CellKey>> = HashSet::new();
+        Ok(self
+            .data
+            .iter()
+            .skip(1)
+            .map(|row| {
+                let key: Vec<CellKey> = col_indices.iter().map(|&i| CellKey(&row[i])).collect();
+                !seen.insert(key)
+            })
+            .collect())
+    }
+
+    /// is_unique_key checks whether the given columns hold unique, non-null values for every row
+    #[allow(deprecated)]
+    fn is_unique_key(&self, col_indices: &[usize]) -> bool {
+        let mut seen: HashSet<Vec<CellKey>> = HashSet::new();
+
+        for row in self.data.iter().skip(1) {
+            let key: Vec<CellKey> = col_indices.iter().map(|&i| CellKey(&row[i])).collect();
+            if key.iter().any(|k| *k.0 == Cell::Null) || !seen.insert(key) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// mark_first_occurrence appends a bool column telling whether each row holds the first
+    /// occurrence of its value in a column
+    ///
+    /// Rows are visited in order, and the flag is true the first time a value is seen and false
+    /// afterwards, which is handy to count new users per cohort or first visits in a funnel. The
+    /// new column is named after the source column with a `_first_seen` suffix. Null values are
+    /// compared like any other value.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the column whose values are tracked
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, or if the flag column already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("day,user\n1,ada\n1,bob\n2,ada");
+    /// sheet.mark_first_occurrence("user").unwrap();
+    ///
+    /// assert_eq!(sheet.header().unwrap()[2], Cell::String("user_first_seen".to_string()));
+    /// assert_eq!(sheet.row(1).unwrap()[2], Cell::Bool(true));
+    /// assert_eq!(sheet.row(2).unwrap()[2], Cell::Bool(false));
+    /// ```
+    #[allow(deprecated)]
+    pub fn mark_first_occurrence<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<(), Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        let name = format!("{}_first_seen", self.data[0][col_index]);
+        if self.get_col_index(&name).is_some() {
+            return Err(Box::from(format!("column '{name}' already exists")));
+        }
+
+        let duplicated = self.duplicated(&[col_index])?;
+        self.data[0].push(Cell::String(name));
+        for (row, duplicate) in self.data.iter_mut().skip(1).zip(duplicated) {
+            row.push(Cell::Bool(!duplicate));
         }
 
         Ok(())
     }
 
-    /// paginate takes part of a sheet with a fixed size and return it
+    /// clamp caps the values of a numeric column to the `[min, max]` range
     ///
-    /// The function takes a page number and a page size, and slice the sheet and returns it as a page
-    /// of fixed size
+    /// Values below `min` are replaced by `min` and values above `max` by `max`. Integer cells stay
+    /// integers, with the bounds rounded inwards to the nearest integer, unless no integer lies
+    /// in the range, in which case they become floats. Null cells are left as-is.
     ///
     /// # Arguments
     ///
-    /// * `page` - the number of the page
-    /// * `size` - number of rows for every page
+    /// * `column` - the numeric column to be clamped
+    /// * `min` - the lower bound
+    /// * `max` - the upper bound
     ///
     /// # Errors
     ///
-    /// Returns a `Result` indicating success or an error
+    /// Returns an error if the column doesn't exist, a bound is NaN, `min` is greater than `max`,
+    /// or the column contains non-numeric values. The sheet is left untouched in that case.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let row1 = vec![Cell::String("greeting".to_string()), Cell::String("is_good".to_string()), Cell::String("count".to_string())];
-    /// let row2 = vec![Cell::String("Hello, Rust!".to_string()), Cell::Bool(false), Cell::Int(42)];
-    /// let row3 = vec![Cell::String("Hello, World!".to_string()), Cell::Bool(true), Cell::Int(145)];
-    /// let row4 = vec![Cell::String("Hello, Dzair!".to_string()), Cell::Bool(true), Cell::Int(145)];
-    /// let row5 = vec![Cell::String("Hello, Africa!".to_string()), Cell::Bool(true), Cell::Int(145)];
-    /// let row6 = vec![Cell::String("Hello, Algeria!".to_string()), Cell::Bool(true), Cell::Int(145)];
-    /// let row7 = vec![Cell::String("Hello, Friday!".to_string()), Cell::Bool(true), Cell::Int(145)];
-    /// let sheet = Sheet { data: vec![row1, row2, row3, row4, row5, row6, row7] };
+    /// use datatroll::{Cell, Sheet};
     ///
-    /// let page = sheet.paginate(1, 2)?;
+    /// let mut sheet = Sheet::load_data_from_str("id,temp\n1,-40.5\n2,21.3\n3,180.0");
+    /// sheet.clamp("temp", -20.0, 60.0).unwrap();
     ///
-    /// assert_eq!(page[0][0], Cell::String("Hello, Rust!".to_string()));
-    /// assert_eq!(page[1][0], Cell::String("Hello, World!".to_string()));
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::Float(-20.0));
+    /// assert_eq!(sheet.row(2).unwrap()[1], Cell::Float(60.0));
     /// ```
-    pub fn paginate(&self, page: usize, size: usize) -> Result<Vec<Row>, Box<dyn Error>> {
-        if page < 1 || size > 50 {
-            return Err(Box::from(
-                "page should more than or equal 1, size should 50 per page at max",
-            ));
+    #[allow(deprecated)]
+    pub fn clamp<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        min: f64,
+        max: f64,
+    ) -> Result<(), Box<dyn Error>> {
+        if min.is_nan() || max.is_nan() {
+            return Err(Box::from("min and max should not be NaN"));
         }
-        if self.data.len() < size {
-            return Err(Box::from("page unavailabe"));
+        if min > max {
+            return Err(Box::from("min should be less than or equal to max"));
         }
+        let col_index = self.col_index(column)?;
+        // validate the whole column before touching any cell
+        self.numeric_values(col_index)?;
 
-        let mut res: Vec<Row> = Default::default();
-        let offset = ((page - 1) * size) + 1;
+        // the integer bounds, which cross when no integer lies in the range
+        let (low, high) = (min.ceil(), max.floor());
+        for row in self.data.iter_mut().skip(1) {
+            row[col_index] = match row[col_index] {
+                Cell::Int(i) if low > high => Cell::Float((i as f64).clamp(min, max)),
+                Cell::Int(i) => Cell::Int(i.clamp(low as i64, high as i64)),
+                Cell::UInt(u) if low > high => Cell::Float((u as f64).clamp(min, max)),
+                // every unsigned value is above a negative max
+                Cell::UInt(_) if high < 0.0 => Cell::Int(high as i64),
+                Cell::UInt(u) => uint_cell(u.clamp(low.max(0.0) as u64, high as u64)),
+                Cell::Float(f) => Cell::Float(f.clamp(min, max)),
+                _ => continue,
+            };
+        }
 
-        for i in offset..(offset + size) {
-            let row = self.data.get(i).unwrap_or_else(|| {
-                panic!(
-                    "offset '{}' and amount '{}' are out of bounds",
-                    offset, size
-                )
-            });
-            res.push(row.clone())
+        Ok(())
+    }
+
+    /// winsorize caps the extreme values of a numeric column at the given percentile
+    ///
+    /// Values below the `pct` percentile are raised to it, and values above the `1 - pct`
+    /// percentile are lowered to it, which limits the influence of outliers in noisy measurements.
+    /// Null cells are ignored and left as-is.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the numeric column to be winsorized
+    /// * `pct` - the fraction of values to cap at each end, in the `[0, 0.5)` range
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, `pct` is out of range, or the column contains
+    /// non-numeric or NaN values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("v\n1\n2\n3\n4\n5\n6\n7\n8\n9\n1000");
+    /// sheet.winsorize("v", 0.1).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[0], Cell::Int(2));
+    /// assert_eq!(sheet.row(9).unwrap()[0], Cell::Int(9));
+    /// ```
+    pub fn winsorize<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        pct: f64,
+    ) -> Result<(), Box<dyn Error>> {
+        if !(0.0..0.5).contains(&pct) {
+            return Err(Box::from("pct should be in the [0, 0.5) range"));
         }
+        let col_index = self.col_index(column)?;
 
-        Ok(res)
+        let mut values = self.numeric_values(col_index)?;
+        if values.is_empty() {
+            return Ok(());
+        }
+        if values.iter().any(|v| v.is_nan()) {
+            return Err(Box::from(NAN_ERROR));
+        }
+        if !values.is_sorted_by(|a, b| a <= b) {
+            values.sort_by(|a, b| a.total_cmp(b));
+        }
+
+        let k = (pct * values.len() as f64).floor() as usize;
+        let (low, high) = (values[k], values[values.len() - 1 - k]);
+
+        self.clamp(col_index, low, high)
     }
 
-    /// Finds the first row in the table that matches a predicate applied to a specific column.
+    /// round rounds the float values of a column to the given number of decimal digits
     ///
-    /// # Panics
+    /// Halfway values are rounded away from zero. Integer and null cells are left as-is.
     ///
-    /// Panics if the specified column doesn't exist or is absent for a row.
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist or contains non-numeric values.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut sheet = Sheet::new_sheet();
-    /// sheet.load_data("test_data.csv").unwrap();
-    /// let first_matching_rows = sheet.find_rows("Age", |cell| cell.as_int() >= 30);
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("item,price\npen,1.2345\nbook,12.5");
+    /// sheet.round("price", 2).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::Float(1.23));
     /// ```
+    pub fn round<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        digits: u32,
+    ) -> Result<(), Box<dyn Error>> {
+        self.apply_with_precision(column.into(), digits, f64::round)
+    }
+
+    /// floor rounds the float values of a column down to the given number of decimal digits
     ///
-    /// # Generics
+    /// Integer and null cells are left as-is.
     ///
-    /// The `predicate` argument is a generic function that allows for flexible filtering criteria.
-    /// It accepts a reference to a `Cell` and returns a boolean indicating whether the row matches.
+    /// # Errors
     ///
-    /// # Returns
+    /// Returns an error if the column doesn't exist or contains non-numeric values.
+    pub fn floor<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        digits: u32,
+    ) -> Result<(), Box<dyn Error>> {
+        self.apply_with_precision(column.into(), digits, f64::floor)
+    }
+
+    /// ceil rounds the float values of a column up to the given number of decimal digits
     ///
-    /// An `Option<&Row>`:
-    /// - `Some(&row)` if a matching row is found, where `row` is a reference to the first matching row.
-    /// - `None` if no matching row is found.
-    pub fn find_first_row<F>(&self, column: &str, predicate: F) -> Option<(Row, usize)>
+    /// Integer and null cells are left as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist or contains non-numeric values.
+    pub fn ceil<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        digits: u32,
+    ) -> Result<(), Box<dyn Error>> {
+        self.apply_with_precision(column.into(), digits, f64::ceil)
+    }
+
+    /// truncate drops the decimal digits of the float values of a column past the given precision
+    ///
+    /// Unlike [`Sheet::floor`], truncation always rounds towards zero. Integer and null cells are
+    /// left as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist or contains non-numeric values.
+    pub fn truncate<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        digits: u32,
+    ) -> Result<(), Box<dyn Error>> {
+        self.apply_with_precision(column.into(), digits, f64::trunc)
+    }
+
+    /// apply_with_precision applies a rounding function to the float values of a column, after
+    /// scaling them by 10^digits
+    #[allow(deprecated)]
+    fn apply_with_precision<F>(
+        &mut self,
+        column: ColumnRef<'_>,
+        digits: u32,
+        rounding: F,
+    ) -> Result<(), Box<dyn Error>>
     where
-        F: FnOnce(&Cell) -> bool + Copy,
+        F: Fn(f64) -> f64,
     {
-        let col_index = self.get_col_index(column).expect("column doesn't exist");
+        let col_index = self.col_index(column)?;
+        // validate the whole column before touching any cell
+        self.numeric_values(col_index)?;
 
-        for i in 1..self.data.len() {
-            let cell = self.data[i]
-                .get(col_index)
-                .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", col_index, i));
-            if predicate(cell) {
-                return Some((self.data[i].clone(), i));
+        let factor = 10_f64.powi(digits as i32);
+        for row in self.data.iter_mut().skip(1) {
+            if let Cell::Float(f) = &mut row[col_index] {
+                *f = rounding(*f * factor) / factor;
             }
         }
 
-        None
+        Ok(())
     }
 
-    pub fn edit_cell(
+    /// transform applies a numeric transformation to every value of a column
+    ///
+    /// Null cells are left as-is. Values the transformation isn't defined for (e.g. the log of a
+    /// negative number) are handled according to `policy`. Except for [`NumericTransform::Abs`]
+    /// on integers, the transformed values are stored as floats.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the numeric column to be transformed
+    /// * `transform` - the transformation to apply
+    /// * `policy` - what to do with values outside of the transformation's domain
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, contains non-numeric values, or holds a value
+    /// outside the domain while `policy` is [`DomainPolicy::Error`]. The sheet is left untouched
+    /// in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, DomainPolicy, NumericTransform, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,area\n1,16\n2,-4\n3,");
+    /// sheet.transform("area", NumericTransform::Sqrt, DomainPolicy::Null).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::Float(4.0));
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::Null);
+    /// assert_eq!(sheet.row(2).unwrap()[1], Cell::Null);
+    /// ```
+    #[allow(deprecated)]
+    pub fn transform<'a>(
         &mut self,
-        column: &str,
-        row_index: usize,
-        new_value: Cell,
-    ) -> Result<(), String> {
-        match self.get_col_index(column) {
-            Some(i) => {
-                self.data[row_index][i] = new_value.clone();
-                Ok(())
+        column: impl Into<ColumnRef<'a>>,
+        transform: NumericTransform,
+        policy: DomainPolicy,
+    ) -> Result<(), Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+
+        let mut results = Vec::with_capacity(self.data.len());
+        for i in 1..self.data.len() {
+            let cell = &self.data[i][col_index];
+            let value = match cell {
+                Cell::Null => Some(Cell::Null),
+                Cell::Int(x) if transform == NumericTransform::Abs => {
+                    x.checked_abs().map(Cell::Int)
+                }
+                Cell::Int(x) => apply_numeric_transform(transform, *x as f64).map(Cell::Float),
+                Cell::UInt(_) if transform == NumericTransform::Abs => Some(cell.clone()),
+                Cell::UInt(u) => apply_numeric_transform(transform, *u as f64).map(Cell::Float),
+                Cell::Float(f) => apply_numeric_transform(transform, *f).map(Cell::Float),
+                _ => return Err(Box::from("column value should be an i64 or a f64")),
+            };
+
+            results.push(match (value, policy) {
+                (Some(v), _) => v,
+                (None, DomainPolicy::Null) => Cell::Null,
+                (None, DomainPolicy::Keep) => cell.clone(),
+                (None, DomainPolicy::Error) => {
+                    return Err(Box::from(format!(
+                        "value '{cell}' at row '{i}' is outside the domain of {transform:?}"
+                    )))
+                }
+            });
+        }
+
+        for (row, value) in self.data.iter_mut().skip(1).zip(results) {
+            row[col_index] = value;
+        }
+
+        Ok(())
+    }
+
+    /// convert_units applies a linear unit conversion to every value of a numeric column
+    ///
+    /// Every value is replaced by `value * factor + offset` and stored as a float. Null cells are
+    /// left as-is.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the numeric column to be converted
+    /// * `factor` - the multiplier applied to every value
+    /// * `offset` - the constant added after the multiplication
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist or contains non-numeric values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("city,temp\nalgiers,212");
+    /// sheet.convert_units("temp", 5.0 / 9.0, -160.0 / 9.0).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::Float(100.0));
+    /// ```
+    #[allow(deprecated)]
+    pub fn convert_units<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        factor: f64,
+        offset: f64,
+    ) -> Result<(), Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        // validate the whole column before touching any cell
+        self.numeric_values(col_index)?;
+
+        for row in self.data.iter_mut().skip(1) {
+            let cell = &mut row[col_index];
+            match cell {
+                Cell::Int(x) => *cell = Cell::Float(*x as f64 * factor + offset),
+                Cell::UInt(u) => *cell = Cell::Float(*u as f64 * factor + offset),
+                Cell::Float(f) => *f = *f * factor + offset,
+                _ => {}
             }
-            None => Err(format!("could not find column '{column}'")),
         }
+
+        Ok(())
     }
 
-    /// Finds rows in the table that match a predicate applied to a specific column.
+    /// convert applies one of the built-in unit conversions to a numeric column
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the specified column doesn't exist or is absent for a row.
+    /// Returns an error if the column doesn't exist or contains non-numeric values.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut sheet = Sheet::new_sheet();
-    /// sheet.load_data("test_data.csv").unwrap();
-    /// let matching_rows = sheet.filter("Age", |cell| cell.as_int() >= 30);
+    /// use datatroll::{Cell, Sheet, UnitConversion};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("route,distance\nA,10");
+    /// sheet.convert("distance", UnitConversion::MilesToKm).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::Float(16.09344));
     /// ```
+    pub fn convert<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        conversion: UnitConversion,
+    ) -> Result<(), Box<dyn Error>> {
+        let (factor, offset) = conversion.factor_offset();
+        self.convert_units(column.into(), factor, offset)
+    }
+
+    /// handle_non_finite applies a policy to the NaN and infinite values of the sheet
     ///
-    /// # Generics
+    /// Tokens such as `NaN`, `inf` or `-infinity` are parsed as floats when loading data, and
+    /// kept as-is by default. Aggregations like [`Sheet::mean`] refuse to silently propagate
+    /// NaN values and return an error instead, while infinite values follow the usual float
+    /// arithmetic. This method lets you reject or drop such values right after loading. The
+    /// header row is never affected.
     ///
-    /// The `predicate` argument is a generic function that allows for flexible filtering criteria.
-    /// It accepts a reference to a `Cell` and returns a boolean indicating whether the row matches.
+    /// # Arguments
+    ///
+    /// * `policy` - what to do with non-finite values
     ///
     /// # Returns
     ///
-    /// A vector of vectors, where each inner vector represents a row that matches the predicate.
-    pub fn filter<F>(&self, column: &str, predicate: F) -> Vec<Row>
-    where
-        F: FnOnce(&Cell) -> bool + Copy,
-    {
-        let col_index = self.get_col_index(column).expect("column doesn't exist");
-        let mut res: Vec<Row> = Default::default();
-
-        for i in 1..self.data.len() {
-            let cell = self.data[i]
-                .get(col_index)
-                .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", col_index, i));
-            if predicate(cell) {
-                res.push(self.data[i].clone());
-            }
-        }
-
-        res
-    }
-
-    /// The map function applies a given transformation to each column value of rows.
+    /// The number of non-finite values found.
     ///
     /// # Errors
     ///
-    /// Returns a `Result` indicating success or an error
+    /// Returns an error under [`NonFinitePolicy::Reject`] if the sheet holds a non-finite value,
+    /// in which case the sheet is left untouched.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use datatroll::{Sheet, Cell};
-    ///
-    ///let data = "id ,title , director, release date, review
-    ///1, old, quintin, 2011, 3.5
-    ///2, her, quintin, 2013, 4.2
-    ///3, easy, scorces, 2005, 1.0
-    ///4, hey, nolan, 1997, 4.7
-    ///5, who, martin, 2017, 5.0";
+    /// use datatroll::{Cell, NonFinitePolicy, Sheet};
     ///
-    /// let mut sheet = Sheet::load_data_from_str(data);
+    /// let mut sheet = Sheet::load_data_from_str("id,review\n1,3.5\n2,NaN\n3,inf");
+    /// assert!(sheet.mean("review").is_err());
     ///
-    /// let result = sheet.map("title", |c| match c {
-    ///     Cell::String(s) => Cell::String(s.to_uppercase()),
-    ///     _ => return c,
-    /// });
+    /// let count = sheet.handle_non_finite(NonFinitePolicy::Null).unwrap();
     ///
-    /// assert!(result.is_ok());
+    /// assert_eq!(count, 2);
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::Null);
+    /// assert_eq!(sheet.row(2).unwrap()[1], Cell::Null);
     /// ```
-    pub fn map<F>(&mut self, column: &str, transform: F) -> Result<(), String>
-    where
-        F: Fn(Cell) -> Cell,
-    {
-        match self.get_col_index(column) {
-            Some(i) => {
-                self.data
-                    .iter_mut()
-                    .for_each(|row| row[i] = transform(row[i].clone()));
-                Ok(())
+    #[allow(deprecated)]
+    pub fn handle_non_finite(&mut self, policy: NonFinitePolicy) -> Result<usize, Box<dyn Error>> {
+        let is_non_finite = |cell: &Cell| matches!(cell, Cell::Float(f) if !f.is_finite());
+
+        let mut count = 0;
+        for (i, row) in self.data.iter().enumerate().skip(1) {
+            for (j, cell) in row.iter().enumerate() {
+                if !is_non_finite(cell) {
+                    continue;
+                }
+                if policy == NonFinitePolicy::Reject {
+                    return Err(Box::from(format!(
+                        "non-finite value '{cell}' in column '{}' at row '{i}'",
+                        self.data[0][j]
+                    )));
+                }
+                count += 1;
+            }
+        }
+
+        if policy == NonFinitePolicy::Null {
+            for row in self.data.iter_mut().skip(1) {
+                for cell in row.iter_mut().filter(|c| is_non_finite(c)) {
+                    *cell = Cell::Null;
+                }
             }
-            None => Err(format!("could not find column '{column}'")),
         }
+
+        Ok(count)
     }
 
-    /// Removes rows from the table based on a predicate applied to a specific column.
+    /// numeric_values collects the non-null values of a numeric column as f64
     ///
-    /// # Panics
+    /// Returns an error if the column holds a value which is neither an int nor a float.
+    #[allow(deprecated)]
+    fn numeric_values(&self, col_index: usize) -> Result<Vec<f64>, Box<dyn Error>> {
+        let mut values = Vec::with_capacity(self.data.len());
+
+        for i in 1..self.data.len() {
+            match self.data[i]
+                .get(col_index)
+                .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", col_index, i))
+            {
+                Cell::Int(x) => values.push(*x as f64),
+                Cell::UInt(u) => values.push(*u as f64),
+                Cell::Float(f) => values.push(*f),
+                Cell::Null => {}
+                _ => return Err(Box::from("column value should be an i64 or a f64")),
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// is_numeric_col tells whether a column holds at least one number and nothing else but nulls
+    #[allow(deprecated)]
+    fn is_numeric_col(&self, col_index: usize) -> bool {
+        let mut cells = self.data.iter().skip(1).map(|row| &row[col_index]);
+
+        cells.clone().any(|c| *c != Cell::Null)
+            && cells.all(|c| {
+                matches!(
+                    c,
+                    Cell::Null | Cell::Int(_) | Cell::UInt(_) | Cell::Float(_)
+                )
+            })
+    }
+
+    /// count_where counts the rows whose value in `column` matches a predicate
     ///
-    /// Panics if the specified column doesn't exist.
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut sheet = Sheet::new_sheet();
-    /// sheet.load_data("test_data.csv").unwrap();
-    /// sheet.drop_rows("Age", |cell| cell.as_int() >= 30); // Removes rows where age is 30 or older
-    /// ```
+    /// use datatroll::{Cell, Sheet};
     ///
-    /// # Generics
+    /// let sheet = Sheet::load_data_from_str("title,director\nold,quintin\nher,quintin\nhey,nolan");
+    /// let count = sheet
+    ///     .count_where("director", |c| *c == Cell::String("quintin".to_string()))
+    ///     .unwrap();
     ///
-    /// The `predicate` argument is a generic function that allows for flexible filtering criteria.
-    /// It accepts a reference to a `Cell` and returns a boolean indicating whether to keep the row.
-    pub fn drop_rows<F>(&mut self, column: &str, predicate: F)
+    /// assert_eq!(count, 2);
+    /// ```
+    #[allow(deprecated)]
+    pub fn count_where<'a, F>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        predicate: F,
+    ) -> Result<usize, Box<dyn Error>>
     where
-        F: FnOnce(&Cell) -> bool + Copy,
+        F: Fn(&Cell) -> bool,
     {
-        let col_index = self.get_col_index(column).expect("column doesn't exist");
-        self.data.retain(|row| !predicate(&row[col_index]));
+        let col_index = self.col_index(column)?;
+
+        Ok(self
+            .data
+            .iter()
+            .skip(1)
+            .filter(|row| predicate(&row[col_index]))
+            .count())
     }
 
-    /// Removes a specified column from the table and returns the number of rows affected.
-    ///
-    /// # Panics
+    /// sum_where sums the values of `value_col` for the rows whose value in `cond_col` matches a
+    /// predicate
     ///
-    /// Panics if the specified column doesn't exist.
+    /// Null values in `value_col` are ignored.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// The number of rows that were modified by removing the column.
+    /// Returns an error if either column doesn't exist, or if a matching row holds a non-numeric
+    /// or NaN value in `value_col`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut sheet = Sheet::new_sheet();
-    /// sheet.load_data("test_data.csv").unwrap();
-    /// let rows_affected = sheet.drop_col("id") // Removes the "id" column and returns 5
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("director,review\nquintin,3.5\nquintin,4.5\nnolan,4.7");
+    /// let total = sheet
+    ///     .sum_where("review", "director", |c| *c == Cell::String("quintin".to_string()))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(total, 8.0);
     /// ```
-    pub fn drop_col(&mut self, column: &str) -> i32 {
-        let col_index = self.get_col_index(column).expect("column doesn't exist");
-        let mut rows_affected = 0;
-        for i in 0..self.data.len() {
-            self.data[i].remove(col_index);
-            rows_affected += 1;
+    #[allow(deprecated)]
+    pub fn sum_where<'a, F>(
+        &self,
+        value_col: impl Into<ColumnRef<'a>>,
+        cond_col: impl Into<ColumnRef<'a>>,
+        predicate: F,
+    ) -> Result<f64, Box<dyn Error>>
+    where
+        F: Fn(&Cell) -> bool,
+    {
+        let value_index = self.col_index(value_col)?;
+        let cond_index = self.col_index(cond_col)?;
+
+        let mut sum = 0_f64;
+        for row in self.data.iter().skip(1) {
+            if !predicate(&row[cond_index]) {
+                continue;
+            }
+
+            sum += match &row[value_index] {
+                Cell::Int(x) => *x as f64,
+                Cell::UInt(u) => *u as f64,
+                Cell::Float(f) if f.is_nan() => return Err(Box::from(NAN_ERROR)),
+                Cell::Float(f) => *f,
+                Cell::Null => 0.0,
+                _ => return Err(Box::from("column value should be an i64 or a f64")),
+            };
         }
 
-        rows_affected
+        Ok(sum)
     }
 
     /// Calculates the mean (average) of a specified column.
@@ -651,20 +3735,30 @@ impl Sheet {
     ///
     /// - The specified column doesn't exist.
     /// - The specified column contains non-numeric values (i.e., not `i64` or `f64`).
+    /// - The specified column contains NaN values.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut sheet = Sheet::new_sheet();
-    /// sheet.load_data("test_data.csv").unwrap();
-    /// let re_mean = sheet.mean("release year")?; // Returns the mean of the "Age" column
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let sheet = Sheet::load_data_from_str(data);
+    /// let re_mean = sheet.mean("release date").unwrap(); // Returns the mean of the "release date" column
     /// ```
     ///
     /// # Returns
     ///
     /// The mean of the specified column as an `f64`, or an error if one occurs.
-    pub fn mean(&self, column: &str) -> Result<f64, Box<dyn Error>> {
-        let index = self.get_col_index(column).expect("column doesn't exist");
+    #[allow(deprecated)]
+    pub fn mean<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Result<f64, Box<dyn Error>> {
+        let index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
         let mut sum = 0_f64;
 
         for i in 1..self.data.len() {
@@ -673,6 +3767,8 @@ impl Sheet {
                 .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", index, i))
             {
                 Cell::Int(x) => *x as f64,
+                Cell::UInt(u) => *u as f64,
+                Cell::Float(f) if f.is_nan() => return Err(Box::from(NAN_ERROR)),
                 Cell::Float(f) => *f,
                 _ => return Err(Box::from("column value should be an i64 or a f64")),
             };
@@ -704,22 +3800,33 @@ impl Sheet {
     ///
     /// - The specified column doesn't exist.
     /// - The specified column contains non-numeric values (i.e., not `i64` or `f64`).
+    /// - The specified column contains NaN values.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut sheet = Sheet::new_sheet();
-    /// sheet.load_data("test_data.csv").unwrap();
-    /// let re_variance = sheet.variance("release year")?; // Returns the variance of the "release year" column
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let sheet = Sheet::load_data_from_str(data);
+    /// let re_variance = sheet.variance("release date").unwrap(); // Returns the variance of the "release date" column
     /// ```
     ///
     /// # Returns
     ///
     /// The variance of the specified column as an `f64`, or an error if one occurs.
-    pub fn variance(&self, column: &str) -> Result<f64, Box<dyn Error>> {
+    #[allow(deprecated)]
+    pub fn variance<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Result<f64, Box<dyn Error>> {
+        let column = column.into();
         let mean = self.mean(column)?;
 
-        let index = self.get_col_index(column).expect("column doesn't exist");
+        let index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
         let mut total_sum = 0_f64;
         for i in 1..self.data.len() {
             let val = match self.data[i]
@@ -727,6 +3834,8 @@ impl Sheet {
                 .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", index, i))
             {
                 Cell::Int(x) => *x as f64,
+                Cell::UInt(u) => *u as f64,
+                Cell::Float(f) if f.is_nan() => return Err(Box::from(NAN_ERROR)),
                 Cell::Float(f) => *f,
                 _ => return Err(Box::from("column value should be an i64 or a f64")),
             };
@@ -740,32 +3849,58 @@ impl Sheet {
     /// Calculates the median value of a specified column.
     ///
     /// The median is the value that separates the higher half of a data set from the lower half.
-    /// In this case, it's the value that falls in the middle of the column when the data is sorted.
+    /// In this case, it's the value that falls in the middle of the column when the data is sorted,
+    /// the lower one of the two middle values for an even count. Null cells are skipped, and the
+    /// values are only ordered when the column isn't sorted already.
     ///
     /// # Panics
     ///
-    /// Panics if:
-    ///
-    /// - The specified column doesn't exist.
-    /// - The specified column is absent for the middle row.
+    /// Panics if the specified column doesn't exist.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut sheet = Sheet::new_sheet();
-    /// sheet.load_data("test_data.csv").unwrap();
-    /// let median_id = sheet.median("id")?; // Returns a &Int(3)
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let sheet = Sheet::load_data_from_str(data);
+    /// let median_date = sheet.median("release date"); // Returns a &Int(2011)
     /// ```
     /// # Returns
     ///
-    /// A reference to the `Cell` containing the median value of the specified column.
-    pub fn median(&self, column: &str) -> &Cell {
-        let col_index = self.get_col_index(column).expect("column doesn't exist");
-        let row_index = ((self.data.len() - 1) + 1) / 2;
+    /// A reference to the `Cell` containing the median value of the specified column, or to
+    /// `Cell::Null` if it holds no value.
+    #[allow(deprecated)]
+    pub fn median<'a>(&self, column: impl Into<ColumnRef<'a>>) -> &Cell {
+        let col_index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
+        let mut values: Vec<&Cell> = self
+            .data
+            .iter()
+            .skip(1)
+            .map(|row| &row[col_index])
+            .filter(|cell| **cell != Cell::Null)
+            .collect();
+        if values.is_empty() {
+            return &Cell::Null;
+        }
+
+        // the lower median, the values are only ordered when they aren't already
+        let k = (values.len() - 1) / 2;
+        if values.is_sorted_by(|a, b| sort_order(a, b, true).is_le()) {
+            return values[k];
+        }
+        if values.is_sorted_by(|a, b| sort_order(a, b, false).is_le()) {
+            return values[values.len() - 1 - k];
+        }
+        let (_, median, _) = values.select_nth_unstable_by(k, |a, b| sort_order(a, b, true));
 
-        self.data[row_index]
-            .get(col_index)
-            .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", col_index, row_index))
+        median
     }
 
     /// mode get the most frequent items of a column
@@ -780,14 +3915,22 @@ impl Sheet {
     /// # Examples
     ///
     /// ```rust
-    /// let mut sheet = Sheet::new_sheet();
-    /// sheet.load_data("test_data.csv").unwrap();
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let data = "id ,title , director, release date, review
+    ///1, old, quintin, 2011, 3.5
+    ///2, her, quintin, 2013, 4.2
+    ///3, easy, scorces, 2005, 1.0
+    ///4, hey, nolan, 1997, 4.7
+    ///5, who, martin, 2017, 5.0";
+    ///
+    /// let sheet = Sheet::load_data_from_str(data);
     ///
     /// let multimodal = sheet.mode("director");
     /// println!("mode: {:?}", multimodal) // mode: [(String("quintin"), 2), (String("martin"), 2)]
     ///```
-    pub fn mode(&self, column: &str) -> Vec<(Cell, i32)> {
-        let col_index = self.get_col_index(column).expect("column doesn't exist");
+    pub fn mode<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Vec<(Cell, i32)> {
+        let col_index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
         let fq = self.build_frequency_table(col_index);
         let mut max = 0;
         let mut multi_mode: Vec<(Cell, i32)> = Vec::new();
@@ -813,6 +3956,7 @@ impl Sheet {
     /// A vector of tuples `(Cell, i32)`, where:
     /// - `Cell` is the unique value from the column.
     /// - `i32` is the frequency (count) of that value in the column.
+    #[allow(deprecated)]
     fn build_frequency_table(&self, col_index: usize) -> Vec<(Cell, i32)> {
         let mut fq: Vec<(Cell, i32)> = Vec::new();
 
@@ -848,8 +3992,9 @@ impl Sheet {
     /// # Returns
     ///
     /// The maximum `i64` value in the specified column, or an error if one occurs.
-    pub fn max_int64(&self, column: &str) -> Result<i64, Box<dyn Error>> {
-        let index = self.get_col_index(column).expect("column doesn't exist");
+    #[allow(deprecated)]
+    pub fn max_int64<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Result<i64, Box<dyn Error>> {
+        let index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
         let mut max = 0_i64;
 
         for i in 1..self.data.len() {
@@ -877,12 +4022,14 @@ impl Sheet {
     ///
     /// - The specified column doesn't exist.
     /// - The specified column contains non-numeric values (i.e., not `f64` or `i64`).
+    /// - The specified column contains NaN values.
     ///
     /// # Returns
     ///
     /// The maximum value in the specified column, either an `f64` or an `i64` cast to `f64`, or an error if one occurs.
-    pub fn max_float64(&self, column: &str) -> Result<f64, Box<dyn Error>> {
-        let index = self.get_col_index(column).expect("column doesn't exist");
+    #[allow(deprecated)]
+    pub fn max_float64<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Result<f64, Box<dyn Error>> {
+        let index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
         let mut max = 0_f64;
 
         for i in 1..self.data.len() {
@@ -890,8 +4037,10 @@ impl Sheet {
                 .get(index)
                 .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", index, i))
             {
+                Cell::Float(f) if f.is_nan() => return Err(Box::from(NAN_ERROR)),
                 Cell::Float(f) => *f,
                 Cell::Int(i) => *i as f64,
+                Cell::UInt(u) => *u as f64,
                 _ => {
                     return Err(Box::from(
                         "max_float64 should only works on float and int values",
@@ -919,8 +4068,9 @@ impl Sheet {
     /// # Returns
     ///
     /// The minimum `i64` value in the specified column, or an error if one occurs.
-    pub fn min_int64(&self, column: &str) -> Result<i64, Box<dyn Error>> {
-        let index = self.get_col_index(column).expect("column doesn't exist");
+    #[allow(deprecated)]
+    pub fn min_int64<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Result<i64, Box<dyn Error>> {
+        let index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
         let mut min = 0_i64;
 
         for i in 1..self.data.len() {
@@ -953,12 +4103,14 @@ impl Sheet {
     ///
     /// - The specified column doesn't exist.
     /// - The specified column contains non-numeric values (i.e., not `f64` or `i64`).
+    /// - The specified column contains NaN values.
     ///
     /// # Returns
     ///
     /// The minimum value in the specified column, either an `f64` or an `i64` cast to `f64`, or an error if one occurs.
-    pub fn min_float64(&self, column: &str) -> Result<f64, Box<dyn Error>> {
-        let index = self.get_col_index(column).expect("column doesn't exist");
+    #[allow(deprecated)]
+    pub fn min_float64<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Result<f64, Box<dyn Error>> {
+        let index = self.col_index(column).unwrap_or_else(|err| panic!("{err}"));
         let mut min = 0_f64;
 
         for i in 1..self.data.len() {
@@ -966,8 +4118,10 @@ impl Sheet {
                 .get(index)
                 .unwrap_or_else(|| panic!("column '{}' is absent for row '{}'", index, i))
             {
+                Cell::Float(f) if f.is_nan() => return Err(Box::from(NAN_ERROR)),
                 Cell::Float(f) => *f,
                 Cell::Int(i) => *i as f64,
+                Cell::UInt(u) => *u as f64,
                 _ => {
                     return Err(Box::from(
                         "min_float64 should only works on float and int values",
@@ -996,6 +4150,7 @@ impl Sheet {
     /// - A separator line.
     /// - The last 5 rows of the sheet.
     /// - The total number of rows and columns
+    #[allow(deprecated)]
     pub fn describe(&self) {
         println!("[");
         for i in 0..5 {
@@ -1004,6 +4159,7 @@ impl Sheet {
                 Cell::String(s) => print!("{s},"),
                 Cell::Bool(b) => print!("{b},"),
                 Cell::Int(x) => print!("{x},"),
+                Cell::UInt(x) => print!("{x},"),
                 Cell::Float(f) => print!("{f},"),
                 Cell::Null => print!(" ,"),
             });
@@ -1023,6 +4179,7 @@ impl Sheet {
                 Cell::String(s) => print!("{s},"),
                 Cell::Bool(b) => print!("{b},"),
                 Cell::Int(x) => print!("{x},"),
+                Cell::UInt(x) => print!("{x},"),
                 Cell::Float(f) => print!("{f},"),
                 Cell::Null => print!("NULL,"),
             });
@@ -1040,6 +4197,7 @@ impl Sheet {
     /// Prints the entire sheet to the standard output in a formatted manner.
     ///
     /// Each row is enclosed in parentheses and separated by commas, providing a visual representation of the sheet's structure and content.
+    #[allow(deprecated)]
     pub fn pretty_print(&self) {
         println!("[");
         self.data.iter().for_each(|row| {
@@ -1048,6 +4206,7 @@ impl Sheet {
                 Cell::String(s) => print!("{s},"),
                 Cell::Bool(b) => print!("{b},"),
                 Cell::Int(x) => print!("{x},"),
+                Cell::UInt(x) => print!("{x},"),
                 Cell::Float(f) => print!("{f},"),
                 Cell::Null => print!(" ,"),
             });
@@ -1057,19 +4216,431 @@ impl Sheet {
     }
 
     /// get_col_index returns the index of a given column, and None otherwise
+    ///
+    /// Column names are compared according to the sheet's [`ColumnMatching`] mode.
     fn get_col_index(&self, column: &str) -> Option<usize> {
-        for i in 0..self.data[0].len() {
-            if let Cell::String(colname) = &self.data[0][i] {
-                if colname == column {
-                    return Some(i);
+        self.header().unwrap_or_default().iter().position(|cell| {
+            matches!(cell, Cell::String(colname) if self.column_matching.matches(colname, column))
+        })
+    }
+
+    /// col_index resolves a column reference to its index, or returns an error suggesting the
+    /// closest column name when it can't be found
+    fn col_index<'a>(&self, column: impl Into<ColumnRef<'a>>) -> Result<usize, String> {
+        let column = match column.into() {
+            ColumnRef::Index(i) if i < self.col_count() => return Ok(i),
+            ColumnRef::Index(i) => return Err(format!("column index '{i}' is out of bounds")),
+            ColumnRef::Name(name) => name,
+        };
+        if let Some(i) = self.get_col_index(column) {
+            return Ok(i);
+        }
+
+        let wanted = ColumnMatching::Relaxed.normalize(column);
+        let suggestion = self
+            .header()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|c| match c {
+                Cell::String(colname) => Some(colname),
+                _ => None,
+            })
+            .map(|colname| {
+                let dist = levenshtein(&ColumnMatching::Relaxed.normalize(colname), &wanted);
+                (colname, dist)
+            })
+            .filter(|(colname, dist)| *dist <= (colname.chars().count() / 3).max(2))
+            .min_by_key(|(_, dist)| *dist);
+
+        match suggestion {
+            Some((colname, _)) => Err(format!(
+                "could not find column '{column}', did you mean '{colname}'?"
+            )),
+            None => Err(format!("could not find column '{column}'")),
+        }
+    }
+}
+
+/// Applies a numeric transformation to a value, returning None if the value is outside the
+/// transformation's domain or the result isn't a finite number.
+fn apply_numeric_transform(transform: NumericTransform, x: f64) -> Option<f64> {
+    let res = match transform {
+        NumericTransform::Abs => x.abs(),
+        NumericTransform::Ln if x > 0.0 => x.ln(),
+        NumericTransform::Log10 if x > 0.0 => x.log10(),
+        NumericTransform::Sqrt if x >= 0.0 => x.sqrt(),
+        NumericTransform::Pow(p) => x.powf(p),
+        _ => return None,
+    };
+
+    res.is_finite().then_some(res)
+}
+
+/// Removes the UTF-8 byte order mark starting files saved by Excel and other Windows tools, so
+/// that it doesn't end up in the first header name.
+fn strip_bom(data: &str) -> &str {
+    data.strip_prefix('\u{feff}').unwrap_or(data)
+}
+
+/// Applies the trim policy of the options to a raw field, telling whether it was a quoted value
+/// whose quotes got removed. The escape sequences of quoted values are unescaped whatever the
+/// policy, which only decides the whitespace removed.
+fn trim_field<'a>(field: &'a str, options: &LoadOptions) -> (Cow<'a, str>, bool) {
+    let raw = match options.trim {
+        TrimPolicy::None => field,
+        TrimPolicy::UnquotedOnly | TrimPolicy::All => field.trim(),
+    };
+    let Some(inner) = raw
+        .strip_prefix(options.quote_char)
+        .and_then(|rest| rest.strip_suffix(options.quote_char))
+    else {
+        return (Cow::Borrowed(raw), false);
+    };
+
+    let value = match unescape(inner, options.quote_char, options.escape_char) {
+        Cow::Borrowed(value) if options.trim == TrimPolicy::All => Cow::Borrowed(value.trim()),
+        Cow::Owned(value) if options.trim == TrimPolicy::All => {
+            Cow::Owned(value.trim().to_string())
+        }
+        value => value,
+    };
+
+    (value, true)
+}
+
+/// Removes the escape characters, or the doubling of quotes, from the inside of a quoted value.
+fn unescape(inner: &str, quote: char, escape: Option<char>) -> Cow<'_, str> {
+    match escape {
+        Some(escape) if inner.contains(escape) => {
+            let mut out = String::with_capacity(inner.len());
+            let mut chars = inner.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    c if c == escape => out.extend(chars.next()),
+                    c => out.push(c),
                 }
-            };
+            }
+            Cow::Owned(out)
+        }
+        None => {
+            let doubled = format!("{quote}{quote}");
+            if inner.contains(&doubled) {
+                Cow::Owned(inner.replace(&doubled, &quote.to_string()))
+            } else {
+                Cow::Borrowed(inner)
+            }
         }
+        _ => Cow::Borrowed(inner),
+    }
+}
 
-        None
+/// Removes `head` lines from the start of a text and `tail` lines from its end.
+fn skip_lines(text: &str, head: usize, tail: usize) -> &str {
+    let mut start = 0;
+    for _ in 0..head {
+        match text[start..].find('\n') {
+            Some(i) => start += i + 1,
+            None => return "",
+        }
+    }
+
+    let body = &text[start..];
+    // a final line break doesn't start another line
+    let mut end = body.strip_suffix('\n').map_or(body.len(), str::len);
+    for _ in 0..tail {
+        end = body[..end].rfind('\n').unwrap_or(0);
+    }
+
+    &body[..end]
+}
+
+/// Names the columns of headerless input after their position: `col_0`, `col_1`...
+fn generated_names(count: usize) -> impl Iterator<Item = String> {
+    (0..count).map(|j| format!("col_{j}"))
+}
+
+/// a record of raw fields, after the one-based line it starts on
+type Record<'a> = (usize, Vec<&'a str>);
+
+/// Splits delimited text into records of raw fields, followed by the text of the comment lines.
+///
+/// Fields starting with the quote character may contain delimiters, line breaks and escaped
+/// quotes until their closing quote. The raw fields keep their quotes, which are removed by
+/// [`trim_field`]. Like `str::lines`, records end with `\n` or `\r\n`.
+fn split_records<'a>(data: &'a str, options: &LoadOptions) -> (Vec<Record<'a>>, Vec<&'a str>) {
+    let mut records = Vec::new();
+    let mut comments = Vec::new();
+    let mut fields = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    // whether only whitespace was seen since the start of the current field
+    let mut opening = true;
+    // the current line, and the line the current record starts on
+    let mut line_no = 1;
+    let mut record_line = 1;
+
+    let mut chars = data.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '\n' {
+            line_no += 1;
+        }
+        if in_quotes {
+            if Some(c) == options.escape_char {
+                if chars.next().is_some_and(|(_, escaped)| escaped == '\n') {
+                    line_no += 1;
+                }
+            } else if c == options.quote_char {
+                let doubled = chars
+                    .peek()
+                    .is_some_and(|&(_, next)| next == options.quote_char);
+                if doubled && options.escape_char.is_none() {
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            }
+            continue;
+        }
+
+        if fields.is_empty() && i == start && Some(c) == options.comment_char {
+            let end = data[i..].find('\n').map_or(data.len(), |n| i + n);
+            let line = &data[i + c.len_utf8()..end];
+            comments.push(line.strip_suffix('\r').unwrap_or(line).trim());
+            while chars.next_if(|&(j, _)| j <= end).is_some() {}
+            start = (end + 1).min(data.len());
+            line_no += 1;
+            record_line = line_no;
+            continue;
+        }
+
+        match c {
+            c if c == options.quote_char && opening => {
+                in_quotes = true;
+                opening = false;
+            }
+            c if c == options.delimiter => {
+                fields.push(&data[start..i]);
+                start = i + c.len_utf8();
+                opening = true;
+            }
+            '\n' => {
+                let line = &data[start..i];
+                fields.push(line.strip_suffix('\r').unwrap_or(line));
+                records.push((record_line, std::mem::take(&mut fields)));
+                record_line = line_no;
+                start = i + 1;
+                opening = true;
+            }
+            c if c.is_whitespace() => {}
+            _ => opening = false,
+        }
+    }
+    if start < data.len() || !fields.is_empty() {
+        fields.push(&data[start..]);
+        records.push((record_line, fields));
+    }
+
+    (records, comments)
+}
+
+/// Wraps a value in quotes when it contains the delimiter, a quote or a line break. Inner
+/// quotes, and escape characters, are preceded by the escape character, or doubled without one.
+fn quote_field(value: &str, delimiter: char, quote: char, escape: Option<char>) -> Cow<'_, str> {
+    if !value.contains([delimiter, quote, '\n', '\r']) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push(quote);
+    for c in value.chars() {
+        match escape {
+            Some(escape) if c == quote || c == escape => out.push(escape),
+            None if c == quote => out.push(quote),
+            _ => {}
+        }
+        out.push(c);
+    }
+    out.push(quote);
+
+    Cow::Owned(out)
+}
+
+/// Creates a delimited file, gzip compressed when its path ends with ".gz", and fills it with
+/// the given function.
+fn write_delimited_file<F>(file_path: &str, write: F) -> Result<(), Box<dyn Error>>
+where
+    F: FnOnce(&mut dyn Write) -> std::io::Result<()>,
+{
+    let (stem, gzip) = strip_gzip(file_path);
+    // check for ext
+    if !stem
+        .rsplit('.')
+        .next()
+        .is_some_and(|ext| DELIMITED_EXTENSIONS.contains(&ext))
+    {
+        return Err(Box::from(
+            "the provided file path is invalid, or of unsupported format",
+        ));
+    }
+    if gzip && !cfg!(feature = "gzip") {
+        return Err(Box::from(GZIP_DISABLED));
+    }
+
+    let file = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .create(true)
+        .open(file_path)?;
+
+    let mut buf_writer = BufWriter::new(file);
+
+    #[cfg(feature = "gzip")]
+    if gzip {
+        let mut encoder = flate2::write::GzEncoder::new(buf_writer, flate2::Compression::default());
+        write(&mut encoder)?;
+        encoder.finish()?.flush()?;
+        return Ok(());
+    }
+
+    write(&mut buf_writer)?;
+    buf_writer.flush()?; // Ensure any remaining data is written to the file
+    Ok(())
+}
+
+/// Escapes backslashes, delimiters and line breaks with a backslash.
+fn backslash_escape(value: &str, delimiter: char) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c == delimiter => {
+                out.push('\\');
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Treats a broken pipe, i.e. a reader which went away, as a success.
+fn ignore_broken_pipe(res: Result<(), Box<dyn Error>>) -> Result<(), Box<dyn Error>> {
+    match res {
+        Err(err)
+            if err
+                .downcast_ref::<std::io::Error>()
+                .is_some_and(|err| err.kind() == std::io::ErrorKind::BrokenPipe) =>
+        {
+            Ok(())
+        }
+        res => res,
     }
 }
 
+/// Splits the ".gz" suffix off a file path, telling whether the file is gzip compressed.
+fn strip_gzip(file_path: &str) -> (&str, bool) {
+    match file_path.strip_suffix(".gz") {
+        Some(stem) => (stem, true),
+        None => (file_path, false),
+    }
+}
+
+/// Tells whether a token is a number written in scientific notation, such as `1e5` or `2.5E-3`.
+fn is_scientific(token: &str) -> bool {
+    token.contains(['e', 'E']) && token.parse::<f64>().is_ok_and(|f| f.is_finite())
+}
+
+/// Parses a number whose integer digits are grouped by threes with a thousands separator, such
+/// as `1,234,567` or `-12_000.5`, returning None for every other token, ungrouped numbers
+/// included.
+fn parse_grouped(token: &str, separator: char) -> Option<Cell> {
+    let (int_part, fraction) = token.split_once('.').unwrap_or((token, ""));
+    let digits = int_part.strip_prefix(['-', '+']).unwrap_or(int_part);
+    let is_digits = |text: &str| text.bytes().all(|b| b.is_ascii_digit());
+    if !digits.contains(separator) || !is_digits(fraction) {
+        return None;
+    }
+
+    let mut groups = digits.split(separator);
+    let leading = groups
+        .next()
+        .filter(|group| (1..=3).contains(&group.len()) && is_digits(group))?;
+    let mut cleaned = String::with_capacity(token.len());
+    // the single sign, if any, followed by the digits of every group
+    cleaned.push_str(&int_part[..int_part.len() - digits.len()]);
+    cleaned.push_str(leading);
+    for group in groups {
+        if group.len() != 3 || !is_digits(group) {
+            return None;
+        }
+        cleaned.push_str(group);
+    }
+    if token.contains('.') {
+        cleaned.push('.');
+        cleaned.push_str(fraction);
+    }
+
+    Some(parse_token(&cleaned))
+}
+
+/// Formats a random version 4 UUID.
+fn uuid_v4(rng: &mut sample::SplitMix64) -> String {
+    let mut bytes = [0u8; 16];
+    bytes[..8].copy_from_slice(&rng.next_u64().to_le_bytes());
+    bytes[8..].copy_from_slice(&rng.next_u64().to_le_bytes());
+    // the version and variant bits
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Hashes bytes with 64-bit FNV-1a, mixing the seed in first. Unlike the std hashers, the
+/// output is stable across Rust releases, so it can be persisted.
+pub(crate) fn fnv1a(seed: u64, bytes: &[u8]) -> u64 {
+    let mut h: u64 = 0xcbf29ce484222325;
+    for b in seed.to_le_bytes().iter().chain(bytes) {
+        h ^= *b as u64;
+        h = h.wrapping_mul(0x100000001b3);
+    }
+
+    h
+}
+
+/// Stores an unsigned integer as a `Cell::Int` when it fits, and as a `Cell::UInt` otherwise.
+pub(crate) fn uint_cell(u: u64) -> Cell {
+    i64::try_from(u).map_or(Cell::UInt(u), Cell::Int)
+}
+
+/// Computes the Levenshtein edit distance between two strings.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+
+    prev[b.len()]
+}
+
 /// Parses a string token into the appropriate Cell type.
 ///
 /// # Behavior
@@ -1077,6 +4648,7 @@ impl Sheet {
 /// - Returns `Cell::Bool(true)` for the token "true".
 /// - Returns `Cell::Bool(false)` for the token "false".
 /// - Returns `Cell::Int(i64)` if the token can be parsed as an integer.
+/// - Returns `Cell::UInt(u64)` if the token is an integer too large for an `i64`.
 /// - Returns `Cell::Float(f64)` if the token can be parsed as a floating-point number.
 /// - Returns `Cell::Null` if the token is empty.
 /// - Returns `Cell::String(token.to_string())` for any other string value.
@@ -1093,6 +4665,10 @@ fn parse_token(token: &str) -> Cell {
         return Cell::Int(i);
     }
 
+    if let Ok(u) = token.parse::<u64>() {
+        return Cell::UInt(u);
+    }
+
     if let Ok(f) = token.parse::<f64>() {
         return Cell::Float(f);
     }
@@ -1104,5 +4680,97 @@ fn parse_token(token: &str) -> Cell {
     Cell::String(token.to_string())
 }
 
+#[cfg(feature = "zip")]
+mod archive;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "avro")]
+mod avro;
+mod binary;
+mod cache;
+#[cfg(feature = "clipboard")]
+mod clipboard;
+#[cfg(feature = "object-store")]
+mod cloud;
+mod coerce;
+mod dbf;
+mod encoding;
+mod fixed_width;
+mod gaps;
+mod generator;
+mod glob;
+#[cfg(feature = "google-sheets")]
+mod google_sheets;
+mod group_summary;
+mod html;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "ipc")]
+mod ipc;
+mod json;
+mod latex;
+mod markdown;
+mod mask;
+mod msgpack;
+#[cfg(feature = "ods")]
+mod ods;
+#[cfg(feature = "parquet")]
+mod parquet;
+mod parse_mode;
+mod partition;
+mod pipeline;
+#[cfg(feature = "postgres")]
+mod postgres;
+pub mod predicate;
+mod redundant;
+mod reshape;
+mod sample;
+mod sniff;
+#[cfg(any(feature = "xlsx", feature = "ods"))]
+mod spreadsheet;
+mod sql;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod standardize;
+mod stream;
+mod string_stats;
+mod style;
+mod tail;
+mod tracking;
+mod transformers;
+mod view;
+mod workbook;
+#[cfg(feature = "xlsx")]
+mod xlsx;
+mod xml;
+mod yaml;
+
+pub use coerce::{CoercionReport, CoercionTarget, ColumnCoercion};
+pub use encoding::TextEncoding;
+pub use gaps::Gap;
+pub use generator::SheetGenerator;
+pub use glob::GlobOptions;
+#[cfg(feature = "google-sheets")]
+pub use google_sheets::GoogleCredentials;
+pub use latex::TableStyle;
+pub use mask::MaskStrategy;
+pub use parse_mode::{ParseError, ParseIssue, ParseMode};
+pub use pipeline::Pipeline;
+pub use redundant::RedundantCol;
+pub use sample::SampledStats;
+pub use sniff::{Dialect, LineTerminator};
+#[cfg(any(feature = "xlsx", feature = "ods"))]
+pub use spreadsheet::WorksheetRef;
+pub use sql::SqlDialect;
+pub use standardize::{StandardizeReport, ValueMapping};
+pub use stream::CsvChunks;
+pub use string_stats::StringStats;
+pub use style::{CellStyle, StyleRule};
+pub use tail::CsvTail;
+pub use tracking::ChangeSummary;
+pub use transformers::TransformerRegistry;
+pub use view::ColumnView;
+pub use workbook::Workbook;
+
 #[cfg(test)]
 mod tests;
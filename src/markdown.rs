@@ -0,0 +1,82 @@
+use crate::{Cell, Sheet};
+
+impl Sheet {
+    /// Renders the Sheet as a GitHub-flavored Markdown table.
+    ///
+    /// The header row becomes the table header. Columns holding only numbers (nulls aside) are
+    /// right-aligned and every other column is left-aligned. Pipes inside values are escaped and
+    /// line breaks replaced by spaces, so that every value stays in its own cell. Values are
+    /// padded to the width of their column to keep the raw text readable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("title,review\nold,3.5\nher,4.25");
+    ///
+    /// assert_eq!(
+    ///     sheet.to_markdown(),
+    ///     "| title | review |\n| :---- | -----: |\n| old   |    3.5 |\n| her   |   4.25 |\n"
+    /// );
+    /// ```
+    #[allow(deprecated)]
+    pub fn to_markdown(&self) -> String {
+        let Some(header) = self.data.first() else {
+            return String::new();
+        };
+
+        let rows: Vec<Vec<String>> = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(markdown_value).collect())
+            .collect();
+        let widths: Vec<usize> = (0..header.len())
+            .map(|j| {
+                rows.iter()
+                    .map(|row| row[j].chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(3)
+            })
+            .collect();
+        let numeric: Vec<bool> = (0..header.len()).map(|j| self.is_numeric_col(j)).collect();
+
+        let mut out = String::new();
+        for (i, row) in rows.iter().enumerate() {
+            out.push('|');
+            for (j, value) in row.iter().enumerate() {
+                let value = if numeric[j] && i > 0 {
+                    format!("{value:>width$}", width = widths[j])
+                } else {
+                    format!("{value:<width$}", width = widths[j])
+                };
+                out.push_str(&format!(" {value} |"));
+            }
+            out.push('\n');
+
+            if i == 0 {
+                out.push('|');
+                for (width, numeric) in widths.iter().zip(&numeric) {
+                    let dashes = "-".repeat(width - 1);
+                    if *numeric {
+                        out.push_str(&format!(" {dashes}: |"));
+                    } else {
+                        out.push_str(&format!(" :{dashes} |"));
+                    }
+                }
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+}
+
+/// Formats a cell so it can't break out of its Markdown table cell.
+fn markdown_value(cell: &Cell) -> String {
+    cell.to_string()
+        .replace('|', "\\|")
+        .replace("\r\n", " ")
+        .replace(['\n', '\r'], " ")
+}
@@ -0,0 +1,146 @@
+use std::error::Error;
+
+use crate::{fnv1a, Cell, ColumnRef, Sheet};
+
+const FIRST_NAMES: [&str; 16] = [
+    "Alice", "Bruno", "Chloe", "Dmitri", "Elena", "Farid", "Grace", "Hugo", "Ines", "Jonas",
+    "Karim", "Lina", "Marco", "Nadia", "Omar", "Priya",
+];
+
+const LAST_NAMES: [&str; 16] = [
+    "Adler", "Benali", "Costa", "Dubois", "Evans", "Fischer", "Garcia", "Haddad", "Ivanov",
+    "Jensen", "Kowalski", "Larsen", "Moreau", "Novak", "Okafor", "Petrov",
+];
+
+/// The way [`Sheet::mask`] replaces the values of a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaskStrategy {
+    /// replaces every value with the 16 hex digits SipHash-2-4 of it, keyed with a secret 128
+    /// bits key
+    Hash([u8; 16]),
+    /// replaces every value with `***`
+    Redact,
+    /// replaces every value with a made-up "first last" name picked from the value and the seed
+    FakeName(u64),
+}
+
+impl Sheet {
+    /// mask replaces the values of a column holding personal data, to produce a shareable
+    /// version of the sheet
+    ///
+    /// [`MaskStrategy::Hash`] and [`MaskStrategy::FakeName`] are pseudonymizations: equal values
+    /// are always replaced by the same output, within a sheet as well as across sheets and runs
+    /// using the same key or seed, so that masked columns can still be joined and grouped.
+    /// Different values may end up with the same fake name. Null cells are left as-is.
+    ///
+    /// Hashes are keyed: without the key, values from a small domain, such as phone numbers,
+    /// can't be recovered by hashing every candidate. The key has to be kept secret, and
+    /// generated randomly rather than derived from the data. Fake names are only picked among 256
+    /// combinations, and the seed is easily guessed, so they don't hide the values from someone
+    /// who knows the candidates; use them for display only.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the column to be masked
+    /// * `strategy` - how the values are replaced
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, MaskStrategy, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("name,email\nada,ada@mail.com\nbob,\nada,ada@mail.com");
+    /// sheet.mask("email", MaskStrategy::Redact).unwrap();
+    /// sheet.mask("name", MaskStrategy::FakeName(42)).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::String("***".to_string()));
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::Null);
+    /// assert_eq!(sheet.row(0).unwrap()[0], sheet.row(2).unwrap()[0]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn mask<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        strategy: MaskStrategy,
+    ) -> Result<(), Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+
+        for row in self.data.iter_mut().skip(1) {
+            let cell = &mut row[col_index];
+            if *cell == Cell::Null {
+                continue;
+            }
+
+            let value = cell.to_string();
+            *cell = Cell::String(match strategy {
+                MaskStrategy::Hash(key) => format!("{:016x}", siphash24(&key, value.as_bytes())),
+                MaskStrategy::Redact => "***".to_string(),
+                MaskStrategy::FakeName(seed) => {
+                    let h = fnv1a(seed, value.as_bytes());
+                    let first = FIRST_NAMES[(h % 16) as usize];
+                    let last = LAST_NAMES[((h >> 32) % 16) as usize];
+                    format!("{first} {last}")
+                }
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Hashes bytes with SipHash-2-4 under a 128 bits key. Unlike [`fnv1a`], the output can't be
+/// computed without the key, so it doesn't give the input away.
+pub(crate) fn siphash24(key: &[u8; 16], bytes: &[u8]) -> u64 {
+    fn round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+    fn compress(v: &mut [u64; 4], m: u64) {
+        v[3] ^= m;
+        round(v);
+        round(v);
+        v[0] ^= m;
+    }
+
+    let word = |b: &[u8]| u64::from_le_bytes(b.try_into().expect("8 bytes"));
+    let (k0, k1) = (word(&key[..8]), word(&key[8..]));
+    let mut v = [
+        k0 ^ 0x736f6d6570736575,
+        k1 ^ 0x646f72616e646f6d,
+        k0 ^ 0x6c7967656e657261,
+        k1 ^ 0x7465646279746573,
+    ];
+
+    let chunks = bytes.chunks_exact(8);
+    let tail = chunks.remainder();
+    for chunk in chunks {
+        compress(&mut v, word(chunk));
+    }
+    // the last word holds the remaining bytes, and the length of the input in its top byte
+    let last = tail
+        .iter()
+        .enumerate()
+        .fold((bytes.len() as u64) << 56, |m, (i, b)| {
+            m | (*b as u64) << (8 * i)
+        });
+    compress(&mut v, last);
+
+    v[2] ^= 0xff;
+    for _ in 0..4 {
+        round(&mut v);
+    }
+
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
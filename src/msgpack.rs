@@ -0,0 +1,236 @@
+use std::error::Error;
+
+use crate::{uint_cell, Cell, Row, Sheet};
+
+impl Sheet {
+    /// Serializes the Sheet to MessagePack.
+    ///
+    /// The sheet is encoded as an array of rows, header row first, every row being an array of
+    /// values. `Cell::Null` maps to `nil`, strings to `str`, bools to booleans, ints to the
+    /// smallest integer format holding them and floats to `float 64`. Cells keep their types,
+    /// so [`Sheet::from_msgpack`] restores the sheet without re-running type inference, and any
+    /// other MessagePack implementation can decode it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title\n1,old");
+    /// let bytes = sheet.to_msgpack();
+    ///
+    /// assert_eq!(bytes, b"\x92\x92\xa2id\xa5title\x92\x01\xa3old");
+    /// ```
+    #[allow(deprecated)]
+    pub fn to_msgpack(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_len(&mut out, self.data.len(), [0x90, 0xdc, 0xdd], 0x0f);
+        for row in &self.data {
+            write_len(&mut out, row.len(), [0x90, 0xdc, 0xdd], 0x0f);
+            for cell in row.iter() {
+                write_cell(&mut out, cell);
+            }
+        }
+
+        out
+    }
+
+    /// Deserializes a Sheet from MessagePack.
+    ///
+    /// The input is expected to be an array of arrays, as produced by [`Sheet::to_msgpack`], the
+    /// first one being the header row. Integers that fit in an `i64` load as `Cell::Int`, and
+    /// `float 32` values are widened to `Cell::Float`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is truncated, holds trailing bytes, isn't an array of
+    /// arrays, holds rows whose width differs from the header, or holds values with no cell
+    /// equivalent (maps, binaries and extensions).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,review\n1,3.5\n2,");
+    /// let restored = Sheet::from_msgpack(&sheet.to_msgpack()).unwrap();
+    ///
+    /// assert_eq!(restored.row(0).unwrap()[1], Cell::Float(3.5));
+    /// assert_eq!(restored.row(1).unwrap()[1], Cell::Null);
+    /// ```
+    #[allow(deprecated)]
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let mut r = MsgpackReader { bytes, pos: 0 };
+        let mut sheet = Self::new_sheet();
+
+        let row_count = r.read_array_len()?;
+        for _ in 0..row_count {
+            let cell_count = r.read_array_len()?;
+            if let Some(header) = sheet.data.first() {
+                if cell_count != header.len() {
+                    return Err(Box::from(format!(
+                        "row {} has {} cells, but the header has {}",
+                        sheet.data.len(),
+                        cell_count,
+                        header.len()
+                    )));
+                }
+            }
+
+            // every cell takes at least one byte, so a length past the end of the input is bogus
+            let mut row = Row(Vec::with_capacity(cell_count.min(bytes.len() - r.pos)));
+            for _ in 0..cell_count {
+                row.push(r.read_cell()?);
+            }
+            sheet.data.push(row);
+        }
+
+        if r.pos != bytes.len() {
+            return Err(Box::from("trailing bytes after the MessagePack sheet"));
+        }
+
+        Ok(sheet)
+    }
+}
+
+/// Writes the header of an array or a string, picking the fix, 16 or 32 bits format.
+fn write_len(out: &mut Vec<u8>, len: usize, markers: [u8; 3], fix_max: usize) {
+    if len <= fix_max {
+        out.push(markers[0] | len as u8);
+    } else if len <= u16::MAX as usize {
+        out.push(markers[1]);
+        out.extend_from_slice(&(len as u16).to_be_bytes());
+    } else {
+        out.push(markers[2]);
+        out.extend_from_slice(&(len as u32).to_be_bytes());
+    }
+}
+
+/// Writes a cell as a MessagePack value.
+fn write_cell(out: &mut Vec<u8>, cell: &Cell) {
+    match cell {
+        Cell::Null => out.push(0xc0),
+        Cell::Bool(b) => out.push(if *b { 0xc3 } else { 0xc2 }),
+        Cell::String(s) => {
+            if s.len() <= 31 {
+                out.push(0xa0 | s.len() as u8);
+            } else if s.len() <= u8::MAX as usize {
+                out.extend_from_slice(&[0xd9, s.len() as u8]);
+            } else {
+                write_len(out, s.len(), [0xa0, 0xda, 0xdb], 31);
+            }
+            out.extend_from_slice(s.as_bytes());
+        }
+        Cell::Int(i) if *i >= 0 => write_uint(out, *i as u64),
+        Cell::Int(i) if *i >= -32 => out.push(*i as i8 as u8),
+        Cell::Int(i) if *i >= i8::MIN as i64 => out.extend_from_slice(&[0xd0, *i as i8 as u8]),
+        Cell::Int(i) if *i >= i16::MIN as i64 => {
+            out.push(0xd1);
+            out.extend_from_slice(&(*i as i16).to_be_bytes());
+        }
+        Cell::Int(i) if *i >= i32::MIN as i64 => {
+            out.push(0xd2);
+            out.extend_from_slice(&(*i as i32).to_be_bytes());
+        }
+        Cell::Int(i) => {
+            out.push(0xd3);
+            out.extend_from_slice(&i.to_be_bytes());
+        }
+        Cell::UInt(u) => write_uint(out, *u),
+        Cell::Float(f) => {
+            out.push(0xcb);
+            out.extend_from_slice(&f.to_be_bytes());
+        }
+    }
+}
+
+/// Writes an unsigned integer in the smallest format holding it.
+fn write_uint(out: &mut Vec<u8>, u: u64) {
+    if u <= 0x7f {
+        out.push(u as u8);
+    } else if u <= u8::MAX as u64 {
+        out.extend_from_slice(&[0xcc, u as u8]);
+    } else if u <= u16::MAX as u64 {
+        out.push(0xcd);
+        out.extend_from_slice(&(u as u16).to_be_bytes());
+    } else if u <= u32::MAX as u64 {
+        out.push(0xce);
+        out.extend_from_slice(&(u as u32).to_be_bytes());
+    } else {
+        out.push(0xcf);
+        out.extend_from_slice(&u.to_be_bytes());
+    }
+}
+
+/// A cursor decoding MessagePack values from a byte slice.
+struct MsgpackReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl MsgpackReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], Box<dyn Error>> {
+        let slice = self
+            .bytes
+            .get(self.pos..self.pos + N)
+            .ok_or("unexpected end of MessagePack input")?;
+        self.pos += N;
+        Ok(slice.try_into().unwrap())
+    }
+
+    fn take_slice(&mut self, len: usize) -> Result<&[u8], Box<dyn Error>> {
+        let slice = self
+            .bytes
+            .get(self.pos..self.pos + len)
+            .ok_or("unexpected end of MessagePack input")?;
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn read_array_len(&mut self) -> Result<usize, Box<dyn Error>> {
+        match self.take::<1>()?[0] {
+            m @ 0x90..=0x9f => Ok((m & 0x0f) as usize),
+            0xdc => Ok(u16::from_be_bytes(self.take()?) as usize),
+            0xdd => Ok(u32::from_be_bytes(self.take()?) as usize),
+            m => Err(Box::from(format!(
+                "expected a MessagePack array, found marker '0x{m:02x}'"
+            ))),
+        }
+    }
+
+    fn read_cell(&mut self) -> Result<Cell, Box<dyn Error>> {
+        let cell = match self.take::<1>()?[0] {
+            0xc0 => Cell::Null,
+            0xc2 => Cell::Bool(false),
+            0xc3 => Cell::Bool(true),
+            m @ 0x00..=0x7f => Cell::Int(m as i64),
+            m @ 0xe0..=0xff => Cell::Int(m as i8 as i64),
+            0xcc => Cell::Int(self.take::<1>()?[0] as i64),
+            0xcd => Cell::Int(u16::from_be_bytes(self.take()?) as i64),
+            0xce => Cell::Int(u32::from_be_bytes(self.take()?) as i64),
+            0xcf => uint_cell(u64::from_be_bytes(self.take()?)),
+            0xd0 => Cell::Int(i8::from_be_bytes(self.take()?) as i64),
+            0xd1 => Cell::Int(i16::from_be_bytes(self.take()?) as i64),
+            0xd2 => Cell::Int(i32::from_be_bytes(self.take()?) as i64),
+            0xd3 => Cell::Int(i64::from_be_bytes(self.take()?)),
+            0xca => Cell::Float(f32::from_be_bytes(self.take()?) as f64),
+            0xcb => Cell::Float(f64::from_be_bytes(self.take()?)),
+            m @ (0xa0..=0xbf | 0xd9 | 0xda | 0xdb) => {
+                let len = match m {
+                    0xd9 => self.take::<1>()?[0] as usize,
+                    0xda => u16::from_be_bytes(self.take()?) as usize,
+                    0xdb => u32::from_be_bytes(self.take()?) as usize,
+                    m => (m & 0x1f) as usize,
+                };
+                Cell::String(String::from_utf8(self.take_slice(len)?.to_vec())?)
+            }
+            m => {
+                return Err(Box::from(format!(
+                    "MessagePack marker '0x{m:02x}' can't be loaded as a cell"
+                )))
+            }
+        };
+
+        Ok(cell)
+    }
+}
@@ -0,0 +1,56 @@
+use std::error::Error;
+
+use calamine::{open_workbook, Ods, Reader};
+
+use crate::{Sheet, WorksheetRef};
+
+impl Sheet {
+    /// Loads a sheet of an OpenDocument spreadsheet (.ods), as saved by LibreOffice Calc, into
+    /// a Sheet.
+    ///
+    /// Cells are loaded the same way as by [`Sheet::load_xlsx`]: every cell goes through the
+    /// same type inference as CSV loading, empty cells become `Cell::Null`, and rows shorter
+    /// than the header are padded with `Cell::Null`.
+    ///
+    /// This method is only available with the `ods` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the ods file.
+    /// * `sheet` - The name or zero-based position of the sheet to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".ods" extension, can't be read, or doesn't
+    /// contain the requested sheet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let by_name = Sheet::load_ods("budget.ods", "2024").unwrap();
+    /// let first = Sheet::load_ods("budget.ods", 0).unwrap();
+    /// ```
+    pub fn load_ods<'a>(
+        file_path: &str,
+        sheet: impl Into<WorksheetRef<'a>>,
+    ) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("ods") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let mut workbook: Ods<_> = open_workbook(file_path)?;
+        let range = match sheet.into() {
+            WorksheetRef::Name(name) => workbook.worksheet_range(name)?,
+            WorksheetRef::Index(i) => workbook
+                .worksheet_range_at(i)
+                .ok_or_else(|| format!("could not find sheet at index '{i}'"))??,
+        };
+
+        Ok(Self::from_range(&range))
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    error::Error,
+    fs::{File, OpenOptions},
+};
+
+use parquet::arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ArrowWriter};
+
+use crate::Sheet;
+
+impl Sheet {
+    /// Loads data from a Parquet file into a Sheet.
+    ///
+    /// Integer columns become `Cell::Int`, apart from the unsigned 64 bits values too large for
+    /// an `i64`, which become `Cell::UInt`. Floating point columns become `Cell::Float`, boolean
+    /// columns `Cell::Bool` and string columns `Cell::String`, while nulls map to `Cell::Null`.
+    ///
+    /// This method is only available with the `parquet` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the Parquet file to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".parquet" extension, can't be read, or holds
+    /// a column type that can't be mapped to a `Cell`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_parquet("movies.parquet").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_parquet(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("parquet") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let f = File::open(file_path)?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(f)?;
+        let schema = builder.schema().clone();
+        let batches = builder.build()?.collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_record_batches(&schema, &batches)
+    }
+
+    /// Exports the content of a Sheet to a Parquet file.
+    ///
+    /// Every column is written with a single nullable Parquet type, picked from its cells: `INT64`
+    /// for int columns, `DOUBLE` for columns mixing ints and floats, `BOOLEAN` for bool columns,
+    /// and UTF-8 strings for everything else. `Cell::Null` is written as a Parquet null. If the
+    /// file already exists, it truncates the file and overwrites its content.
+    ///
+    /// This method is only available with the `parquet` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the Parquet file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".parquet" extension, or can't be written to.
+    pub fn export_parquet(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("parquet") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let batch = self.to_record_batch()?;
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        Ok(())
+    }
+}
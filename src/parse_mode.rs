@@ -0,0 +1,133 @@
+use std::{error::Error, fmt};
+
+use crate::{Cell, CoercionTarget, Sheet};
+
+/// How values that don't match the type of their column are handled when loading delimited
+/// text, see [`LoadOptions::parse_mode`].
+///
+/// The type of a column is the most frequent one among its non-null values: numbers (ints and
+/// floats alike), bools or strings. A value of another type in a number or bool column is
+/// malformed, like `n/a` among reviews, while string columns accept every value.
+///
+/// [`LoadOptions::parse_mode`]: crate::LoadOptions::parse_mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// malformed values are kept as they were parsed, usually as strings, the default
+    #[default]
+    Infer,
+    /// loading fails with a [`ParseError`] listing every malformed value
+    Strict,
+    /// malformed values are set to `Cell::Null` and listed in [`Sheet::parse_warnings`]
+    Lenient,
+}
+
+/// A malformed value found while loading, see [`ParseMode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseIssue {
+    /// the one-based line of the input the value's row starts on
+    pub line: usize,
+    /// the name of the column
+    pub column: String,
+    /// the offending value, as text
+    pub token: String,
+    /// the type of the column
+    pub expected: CoercionTarget,
+}
+
+impl fmt::Display for ParseIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column '{}': expected a {}, found '{}'",
+            self.line, self.column, self.expected, self.token
+        )
+    }
+}
+
+/// The error returned under [`ParseMode::Strict`] when the input holds malformed values.
+///
+/// Its `Display` implementation renders one line per malformed value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// every malformed value, in input order
+    pub issues: Vec<ParseIssue>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} malformed values", self.issues.len())?;
+        for issue in &self.issues {
+            write!(f, "\n{issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+impl Sheet {
+    /// apply_parse_mode looks for malformed values once the input is parsed, `lines` holding
+    /// the line every data row starts on
+    ///
+    /// # Errors
+    ///
+    /// Returns the malformed values under [`ParseMode::Strict`].
+    #[allow(deprecated)]
+    pub(crate) fn apply_parse_mode(
+        &mut self,
+        mode: ParseMode,
+        lines: &[usize],
+    ) -> Result<(), ParseError> {
+        if mode == ParseMode::Infer {
+            return Ok(());
+        }
+
+        // row and column indices of the malformed cells
+        let mut malformed = Vec::new();
+        for j in 0..self.col_count() {
+            let expected = match self.dominant_type(j) {
+                Some(target @ (CoercionTarget::Number | CoercionTarget::Bool)) => target,
+                _ => continue,
+            };
+            for (i, row) in self.data.iter().enumerate().skip(1) {
+                let matches = match &row[j] {
+                    Cell::Null => true,
+                    Cell::Int(_) | Cell::UInt(_) | Cell::Float(_) => {
+                        expected == CoercionTarget::Number
+                    }
+                    Cell::Bool(_) => expected == CoercionTarget::Bool,
+                    Cell::String(_) => false,
+                };
+                if !matches {
+                    malformed.push((i, j, expected));
+                }
+            }
+        }
+        malformed.sort_by_key(|&(i, j, _)| (i, j));
+
+        let issues: Vec<ParseIssue> = malformed
+            .into_iter()
+            .map(|(i, j, expected)| {
+                let cell = match mode {
+                    ParseMode::Lenient => std::mem::replace(&mut self.data[i][j], Cell::Null),
+                    _ => self.data[i][j].clone(),
+                };
+                ParseIssue {
+                    line: lines.get(i - 1).copied().unwrap_or(i + 1),
+                    column: self.data[0][j].to_string(),
+                    token: cell.to_string(),
+                    expected,
+                }
+            })
+            .collect();
+
+        match mode {
+            ParseMode::Strict if !issues.is_empty() => Err(ParseError { issues }),
+            _ => {
+                self.parse_warnings.extend(issues);
+                Ok(())
+            }
+        }
+    }
+}
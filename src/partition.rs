@@ -0,0 +1,224 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    error::Error,
+    fs::{self, OpenOptions},
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    compare_values,
+    json::{write_json_string, write_json_value},
+    Cell, CellKey, ColumnRef, Row, Sheet,
+};
+
+/// A partition written to disk: its file name along with the rows it holds.
+type Partition = (String, Vec<Row>);
+
+impl Sheet {
+    /// Exports the Sheet into one CSV file per distinct value of a column, alongside a manifest.
+    ///
+    /// Every file holds the shared header row followed by the rows of its group, in their
+    /// original order. Files are named after the group value, with characters that aren't ASCII
+    /// alphanumeric replaced by `_`. A `manifest.json` file is written next to them, listing for
+    /// every partition its file name, group value, row count and the min/max value of every
+    /// column, which downstream loaders can use for incremental ingestion. Ranges are only
+    /// reported for columns whose non-null values are all numbers, or all of the same type.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - the directory to write the files to, created if it doesn't exist
+    /// * `column` - the column to group the rows by
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sheet has no header row, if the column doesn't exist, or if a
+    /// file can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.export_groups("by_director", "director").unwrap();
+    /// ```
+    #[allow(deprecated)]
+    pub fn export_groups<'a>(
+        &self,
+        dir: &str,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<(), Box<dyn Error>> {
+        let index = self.col_index(column)?;
+        let header = &self.data[0];
+
+        let mut manifest = String::from("{\"column\":");
+        write_json_string(&mut manifest, &header[index].to_string());
+        manifest.push_str(",\"partitions\":[");
+
+        for (i, (file_name, group)) in self.partitions(dir, index)?.iter().enumerate() {
+            if i > 0 {
+                manifest.push(',');
+            }
+            manifest.push_str("{\"file\":");
+            write_json_string(&mut manifest, file_name);
+            manifest.push_str(",\"value\":");
+            write_json_value(&mut manifest, &group[0][index]);
+            manifest.push_str(&format!(",\"rows\":{},\"ranges\":{{", group.len()));
+
+            let mut first = true;
+            for (j, name) in header.iter().enumerate() {
+                let Some((min, max)) = value_range(group.iter().map(|row| &row[j])) else {
+                    continue;
+                };
+                if !first {
+                    manifest.push(',');
+                }
+                first = false;
+                write_json_string(&mut manifest, &name.to_string());
+                manifest.push_str(":{\"min\":");
+                write_json_value(&mut manifest, min);
+                manifest.push_str(",\"max\":");
+                write_json_value(&mut manifest, max);
+                manifest.push('}');
+            }
+            manifest.push_str("}}");
+        }
+        manifest.push_str("]}");
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(Path::new(dir).join("manifest.json"))?;
+        let mut buf_writer = BufWriter::new(file);
+        buf_writer.write_all(manifest.as_bytes())?;
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Exports the Sheet into one CSV file per distinct value of a column.
+    ///
+    /// Every file holds the shared header row followed by the rows of its group, in their
+    /// original order, and is named after the group value with characters that aren't ASCII
+    /// alphanumeric replaced by `_` (`null.csv` for null values), and a `_2`, `_3`... suffix
+    /// when the name is already taken, ignoring case. Use [`Sheet::export_groups`] to
+    /// also get a manifest describing the partitions.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - the directory to write the files to, created if it doesn't exist
+    /// * `column` - the column to partition the rows by
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sheet has no header row, if the column doesn't exist, or if a
+    /// file can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// // writes by_director/nolan.csv, by_director/quintin.csv, ...
+    /// sheet.export_partitioned("by_director", "director").unwrap();
+    /// ```
+    pub fn export_partitioned<'a>(
+        &self,
+        dir: &str,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<(), Box<dyn Error>> {
+        let index = self.col_index(column)?;
+        self.partitions(dir, index)?;
+
+        Ok(())
+    }
+
+    /// partitions writes one CSV file per distinct value of the given column, returning the
+    /// name of every file along with the rows written to it
+    #[allow(deprecated)]
+    fn partitions(&self, dir: &str, index: usize) -> Result<Vec<Partition>, Box<dyn Error>> {
+        let mut groups: Vec<Vec<Row>> = Vec::new();
+        {
+            let mut positions: HashMap<CellKey, usize> = HashMap::new();
+            for row in self.data.iter().skip(1) {
+                let position = *positions.entry(CellKey(&row[index])).or_insert_with(|| {
+                    groups.push(Vec::new());
+                    groups.len() - 1
+                });
+                groups[position].push(row.clone());
+            }
+        }
+
+        fs::create_dir_all(dir)?;
+
+        // the names given so far, as a value such as `a_2` may take the name of a collision,
+        // lowercased since case-insensitive file systems would write `A.csv` over `a.csv`
+        let mut used: HashSet<String> = HashSet::new();
+        let mut partitions = Vec::with_capacity(groups.len());
+        for group in groups {
+            let stem = file_stem(&group[0][index]);
+            let mut file_name = format!("{stem}.csv");
+            let mut n = 1;
+            while used.contains(&file_name.to_lowercase()) {
+                n += 1;
+                file_name = format!("{stem}_{n}.csv");
+            }
+            used.insert(file_name.to_lowercase());
+
+            let path = Path::new(dir).join(&file_name);
+            self.shard(&group).export(
+                path.to_str()
+                    .ok_or("the provided directory is not valid UTF-8")?,
+            )?;
+            partitions.push((file_name, group));
+        }
+
+        Ok(partitions)
+    }
+}
+
+/// Builds a file name out of a group value.
+fn file_stem(value: &Cell) -> String {
+    match value {
+        Cell::Null => "null".to_string(),
+        value => value
+            .to_string()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect(),
+    }
+}
+
+/// Finds the smallest and largest non-null cells, if they are all comparable.
+fn value_range<'a>(cells: impl Iterator<Item = &'a Cell>) -> Option<(&'a Cell, &'a Cell)> {
+    let mut range: Option<(&Cell, &Cell)> = None;
+
+    for cell in cells.filter(|c| **c != Cell::Null) {
+        let Some((min, max)) = range else {
+            range = Some((cell, cell));
+            continue;
+        };
+        let (Some(below), Some(above)) = (compare(cell, min), compare(cell, max)) else {
+            return None;
+        };
+        if below == Ordering::Less {
+            range = Some((cell, max));
+        } else if above == Ordering::Greater {
+            range = Some((min, cell));
+        }
+    }
+
+    range
+}
+
+/// Compares two cells of the same kind, numbers by value whatever their type.
+fn compare(a: &Cell, b: &Cell) -> Option<Ordering> {
+    match (a, b) {
+        (Cell::Bool(_), Cell::Bool(_)) => a.partial_cmp(b),
+        _ => compare_values(a, b),
+    }
+}
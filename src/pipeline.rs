@@ -0,0 +1,530 @@
+use std::{cmp::Ordering, collections::HashMap, error::Error, fs};
+
+use crate::{
+    json::JsonValue, parse_token, predicate, yaml::parse_yaml, Cell, LoadOptions, NonFinitePolicy,
+    RaggedRows, Row, ScientificNotation, Sheet, TrimPolicy,
+};
+
+/// aggregation functions available to the `aggregate` step
+const AGGREGATIONS: [&str; 6] = ["count", "sum", "mean", "variance", "min", "max"];
+
+/// A data job described in a JSON or YAML file and run against datatroll: where to load the
+/// data from, the steps transforming it, and where to export the result.
+///
+/// A pipeline is a mapping with three optional keys:
+///
+/// * `load`: the file to load, either as a path or as a mapping with a `path` along with the
+///   `delimiter`, `trim` (`none`, `unquoted_only` or `all`), `non_finite` (`keep`, `null` or
+///   `reject`), `header` (`true` or `false`), `skip_rows`, `skip_footer`, `ragged_rows`
+///   (`error`, `truncate` or `pad_null`), `thousands_separator` and `scientific_notation`
+///   (`parse` or `keep_as_string`) options of [`LoadOptions`]. CSV, TSV and TXT files, gzip
+///   compressed or not, JSON and NDJSON files are supported.
+/// * `steps`: a sequence of steps applied in order, each a mapping with a single key:
+///   * `filter: <expression>` keeps the rows matching an expression such as `review >= 4` or
+///     `director != 'nolan'`: a column name, one of `==`, `!=`, `<`, `<=`, `>` and `>=`, and a
+///     value, quoted when it's a string holding spaces or looking like a number. Values are
+///     compared like the [`predicate`](crate::predicate) functions do: numbers against numbers
+///     and strings against strings, so comparing a null or values of different kinds is false,
+///     `!=` included. `== null` and `!= null` keep the null and the non-null values;
+///   * `rename: {old: new, ...}` renames columns;
+///   * `apply: {column: <name>, transformer: <spec>}` applies a named transformer, see
+///     [`Sheet::apply_named`];
+///   * `drop: [columns]` and `keep: [columns]` drop or keep columns;
+///   * `sort: {column: <name>, ascending: <bool>}` sorts the rows, ascending by default;
+///   * `aggregate: {column: [functions], ...}` replaces the rows with a single row holding the
+///     `count`, `sum`, `mean`, `variance`, `min` or `max` of columns, named `<column>_<function>`.
+/// * `export`: a path, or a sequence of paths, the result is written to. CSV, TSV and TXT files,
+///   gzip compressed or not, JSON, NDJSON and YAML files are supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use datatroll::{Cell, Pipeline, Sheet};
+///
+/// let pipeline = Pipeline::from_yaml(
+///     "
+/// steps:
+///   - filter: review >= 4
+///   - rename: {title: movie}
+///   - apply: {column: movie, transformer: upper}
+///   - keep: [movie, review]
+/// ",
+/// )
+/// .unwrap();
+///
+/// let sheet = Sheet::load_data_from_str("title,review\nold,3.5\nher,4.2");
+/// let sheet = pipeline.apply(sheet).unwrap();
+///
+/// assert_eq!(sheet.row_count(), 1);
+/// assert_eq!(sheet.row(0).unwrap()[0], Cell::String("HER".to_string()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pipeline {
+    source: Option<(String, LoadOptions)>,
+    steps: Vec<Step>,
+    targets: Vec<String>,
+}
+
+/// A step of a pipeline.
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Filter(Filter),
+    Rename(HashMap<String, String>),
+    Apply { column: String, transformer: String },
+    Drop(Vec<String>),
+    Keep(Vec<String>),
+    Sort { column: String, ascending: bool },
+    Aggregate(Vec<(String, Vec<String>)>),
+}
+
+/// A comparison of the values of a column against a value, such as `review >= 4`.
+#[derive(Debug, Clone, PartialEq)]
+struct Filter {
+    column: String,
+    operator: String,
+    value: Cell,
+}
+
+impl Pipeline {
+    /// Reads a pipeline from a JSON (".json") or YAML (".yaml" or ".yml") file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file has another extension, can't be read, or doesn't describe a
+    /// valid pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Pipeline;
+    ///
+    /// let pipeline = Pipeline::load("jobs/monthly_report.yaml").unwrap();
+    /// pipeline.run().unwrap();
+    /// ```
+    pub fn load(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        match file_path.rsplit('.').next() {
+            Some("json") => Self::from_json(&fs::read_to_string(file_path)?),
+            Some("yaml" | "yml") => Self::from_yaml(&fs::read_to_string(file_path)?),
+            _ => Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            )),
+        }
+    }
+
+    /// Reads a pipeline from a JSON document, see [`Pipeline`] for its structure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document isn't valid JSON, or doesn't describe a valid pipeline.
+    pub fn from_json(config: &str) -> Result<Self, Box<dyn Error>> {
+        Self::from_value(JsonValue::parse(config)?)
+    }
+
+    /// Reads a pipeline from a YAML document, see [`Pipeline`] for its structure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document isn't valid YAML, or doesn't describe a valid pipeline.
+    pub fn from_yaml(config: &str) -> Result<Self, Box<dyn Error>> {
+        Self::from_value(parse_yaml(config)?)
+    }
+
+    /// Runs the pipeline: loads its source, applies its steps, and exports the result to every
+    /// target.
+    ///
+    /// # Returns
+    ///
+    /// The resulting Sheet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pipeline has no `load` source, or if loading, a step or an export
+    /// fails.
+    pub fn run(&self) -> Result<Sheet, Box<dyn Error>> {
+        let (path, options) = self
+            .source
+            .as_ref()
+            .ok_or("the pipeline has no 'load' source")?;
+        let sheet = match path.rsplit('.').next() {
+            Some("json") => Sheet::load_json(path)?,
+            Some("ndjson") => Sheet::load_ndjson(path)?,
+            _ => Sheet::load_data_with(path, options)?,
+        };
+        let sheet = self.apply(sheet)?;
+
+        for target in &self.targets {
+            match target.rsplit('.').next() {
+                Some("json") => sheet.export_json(target)?,
+                Some("ndjson") => sheet.export_ndjson(target)?,
+                Some("yaml" | "yml") => sheet.export_yaml(target)?,
+                _ => sheet.export(target)?,
+            }
+        }
+
+        Ok(sheet)
+    }
+
+    /// Applies the steps of the pipeline to a Sheet, without loading or exporting anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a step fails, e.g. because it refers to a column that doesn't exist.
+    pub fn apply(&self, mut sheet: Sheet) -> Result<Sheet, Box<dyn Error>> {
+        for (i, step) in self.steps.iter().enumerate() {
+            sheet = step
+                .apply(sheet)
+                .map_err(|err| format!("step {}: {err}", i + 1))?;
+        }
+
+        Ok(sheet)
+    }
+
+    /// from_value builds a pipeline out of a parsed config document
+    fn from_value(config: JsonValue) -> Result<Self, Box<dyn Error>> {
+        let JsonValue::Object(fields) = config else {
+            return Err(Box::from("a pipeline should be a mapping"));
+        };
+
+        let mut pipeline = Pipeline {
+            source: None,
+            steps: Vec::new(),
+            targets: Vec::new(),
+        };
+        for (key, value) in fields {
+            match key.as_str() {
+                "load" => pipeline.source = Some(parse_source(value)?),
+                "steps" => {
+                    pipeline.steps = as_array(value, "steps")?
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, step)| {
+                            parse_step(step).map_err(|err| format!("step {}: {err}", i + 1))
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                "export" => {
+                    pipeline.targets = match value {
+                        JsonValue::Array(_) => as_strings(value, "export")?,
+                        value => vec![as_string(value, "export")?],
+                    };
+                }
+                key => return Err(Box::from(format!("unknown pipeline key '{key}'"))),
+            }
+        }
+
+        Ok(pipeline)
+    }
+}
+
+impl Step {
+    #[allow(deprecated)]
+    fn apply(&self, mut sheet: Sheet) -> Result<Sheet, Box<dyn Error>> {
+        match self {
+            Step::Filter(filter) => {
+                let col_index = sheet.col_index(filter.column.as_str())?;
+                sheet.retain_rows(|row| filter.matches(&row[col_index]));
+            }
+            Step::Rename(renames) => {
+                sheet.rename_cols(renames)?;
+            }
+            Step::Apply {
+                column,
+                transformer,
+            } => sheet.apply_named(column.as_str(), transformer)?,
+            Step::Drop(columns) => {
+                let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+                sheet.drop_cols(&columns)?;
+            }
+            Step::Keep(columns) => {
+                let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+                sheet.keep_cols(&columns)?;
+            }
+            Step::Sort { column, ascending } => {
+                sheet = sheet.sorted(column.as_str(), *ascending)?;
+            }
+            Step::Aggregate(aggregations) => {
+                let mut header = Vec::new();
+                let mut values = Vec::new();
+                for (column, functions) in aggregations {
+                    for function in functions {
+                        header.push(Cell::String(format!("{column}_{function}")));
+                        values.push(aggregate(&sheet, column, function)?);
+                    }
+                }
+                let mut summary = Sheet::new_sheet();
+                summary.delimiter = sheet.delimiter;
+                summary.data = vec![Row(header), Row(values)];
+                sheet = summary;
+            }
+        }
+
+        Ok(sheet)
+    }
+}
+
+impl Filter {
+    /// parse reads an expression made of a column name, an operator and a value
+    fn parse(expression: &str) -> Result<Self, Box<dyn Error>> {
+        let start = expression
+            .find(['=', '!', '<', '>'])
+            .ok_or_else(|| format!("expression '{expression}' has no comparison operator"))?;
+        let operator = ["==", "!=", "<=", ">=", "<", ">"]
+            .into_iter()
+            .find(|op| expression[start..].starts_with(op))
+            .ok_or_else(|| format!("expression '{expression}' has an invalid operator"))?;
+
+        let column = expression[..start].trim();
+        let value = expression[start + operator.len()..].trim();
+        if column.is_empty() || value.is_empty() {
+            return Err(Box::from(format!(
+                "expression '{expression}' should be '<column> <operator> <value>'"
+            )));
+        }
+        let value = match value
+            .strip_prefix('\'')
+            .and_then(|v| v.strip_suffix('\''))
+            .or_else(|| value.strip_prefix('"').and_then(|v| v.strip_suffix('"')))
+        {
+            Some(quoted) => Cell::String(quoted.to_string()),
+            None if value == "null" => Cell::Null,
+            None => parse_token(value),
+        };
+
+        Ok(Filter {
+            column: column.to_string(),
+            operator: operator.to_string(),
+            value,
+        })
+    }
+
+    /// matches compares a cell to the value like the [`crate::predicate`] functions, apart from
+    /// `== null` and `!= null` which test whether the cell is null
+    fn matches(&self, cell: &Cell) -> bool {
+        let accept: fn(Ordering) -> bool = match self.operator.as_str() {
+            "==" => Ordering::is_eq,
+            "!=" => Ordering::is_ne,
+            "<" => Ordering::is_lt,
+            "<=" => Ordering::is_le,
+            ">" => Ordering::is_gt,
+            _ => Ordering::is_ge,
+        };
+        match (&self.value, self.operator.as_str()) {
+            (Cell::Null, "==") => *cell == Cell::Null,
+            (Cell::Null, "!=") => *cell != Cell::Null,
+            _ => predicate::matches(cell, &self.value, accept),
+        }
+    }
+}
+
+/// Computes an aggregation function over a column.
+fn aggregate(sheet: &Sheet, column: &str, function: &str) -> Result<Cell, Box<dyn Error>> {
+    let cell = match function {
+        "count" => Cell::Int(sheet.count_where(column, |cell| *cell != Cell::Null)? as i64),
+        "sum" => Cell::Float(sheet.sum_where(column, column, |_| true)?),
+        "mean" => Cell::Float(sheet.mean(column)?),
+        "variance" => Cell::Float(sheet.variance(column)?),
+        "min" => Cell::Float(sheet.min_float64(column)?),
+        "max" => Cell::Float(sheet.max_float64(column)?),
+        function => {
+            return Err(Box::from(format!(
+                "unknown aggregation '{function}', expected one of {}",
+                AGGREGATIONS.join(", ")
+            )))
+        }
+    };
+
+    Ok(cell)
+}
+
+/// Reads the `load` section of a pipeline.
+fn parse_source(value: JsonValue) -> Result<(String, LoadOptions), Box<dyn Error>> {
+    let fields = match value {
+        JsonValue::String(path) => return Ok((path, LoadOptions::default())),
+        JsonValue::Object(fields) => fields,
+        _ => return Err(Box::from("'load' should be a path or a mapping")),
+    };
+
+    let mut path = None;
+    let mut options = LoadOptions::default();
+    for (key, value) in fields {
+        match key.as_str() {
+            "path" => path = Some(as_string(value, "path")?),
+            "delimiter" => options.delimiter = as_char(value, "delimiter")?,
+            "thousands_separator" => {
+                options.thousands_separator = Some(as_char(value, "thousands_separator")?);
+            }
+            "scientific_notation" => {
+                options.scientific_notation =
+                    match as_string(value, "scientific_notation")?.as_str() {
+                        "parse" => ScientificNotation::Parse,
+                        "keep_as_string" => ScientificNotation::KeepAsString,
+                        other => {
+                            return Err(Box::from(format!(
+                                "unknown scientific notation policy '{other}'"
+                            )))
+                        }
+                    };
+            }
+            "trim" => {
+                options.trim = match as_string(value, "trim")?.as_str() {
+                    "none" => TrimPolicy::None,
+                    "unquoted_only" => TrimPolicy::UnquotedOnly,
+                    "all" => TrimPolicy::All,
+                    other => return Err(Box::from(format!("unknown trim policy '{other}'"))),
+                };
+            }
+            "non_finite" => {
+                options.non_finite = match as_string(value, "non_finite")?.as_str() {
+                    "keep" => NonFinitePolicy::Keep,
+                    "null" => NonFinitePolicy::Null,
+                    "reject" => NonFinitePolicy::Reject,
+                    other => return Err(Box::from(format!("unknown non-finite policy '{other}'"))),
+                };
+            }
+            "header" => {
+                options.header = match value {
+                    JsonValue::Bool(header) => header,
+                    _ => return Err(Box::from("'header' should be a bool")),
+                };
+            }
+            "ragged_rows" => {
+                options.ragged_rows = match as_string(value, "ragged_rows")?.as_str() {
+                    "error" => RaggedRows::Error,
+                    "truncate" => RaggedRows::Truncate,
+                    "pad_null" => RaggedRows::PadNull,
+                    other => {
+                        return Err(Box::from(format!("unknown ragged rows policy '{other}'")))
+                    }
+                };
+            }
+            "skip_rows" => options.skip_rows = as_count(value, "skip_rows")?,
+            "skip_footer" => options.skip_footer = as_count(value, "skip_footer")?,
+            key => return Err(Box::from(format!("unknown load option '{key}'"))),
+        }
+    }
+
+    Ok((path.ok_or("'load' should have a 'path'")?, options))
+}
+
+/// Reads a step of a pipeline.
+fn parse_step(value: JsonValue) -> Result<Step, Box<dyn Error>> {
+    let (name, value) = match value {
+        JsonValue::Object(mut fields) if fields.len() == 1 => fields.remove(0),
+        _ => return Err(Box::from("a step should be a mapping with a single key")),
+    };
+
+    let step = match name.as_str() {
+        "filter" => Step::Filter(Filter::parse(&as_string(value, "filter")?)?),
+        "rename" => Step::Rename(
+            as_object(value, "rename")?
+                .into_iter()
+                .map(|(old, new)| Ok((old, as_string(new, "rename")?)))
+                .collect::<Result<_, Box<dyn Error>>>()?,
+        ),
+        "apply" => {
+            let mut fields: HashMap<String, JsonValue> =
+                as_object(value, "apply")?.into_iter().collect();
+            Step::Apply {
+                column: as_string(
+                    fields.remove("column").ok_or("'apply' needs a 'column'")?,
+                    "column",
+                )?,
+                transformer: as_string(
+                    fields
+                        .remove("transformer")
+                        .ok_or("'apply' needs a 'transformer'")?,
+                    "transformer",
+                )?,
+            }
+        }
+        "drop" => Step::Drop(as_strings(value, "drop")?),
+        "keep" => Step::Keep(as_strings(value, "keep")?),
+        "sort" => {
+            let mut fields: HashMap<String, JsonValue> =
+                as_object(value, "sort")?.into_iter().collect();
+            Step::Sort {
+                column: as_string(
+                    fields.remove("column").ok_or("'sort' needs a 'column'")?,
+                    "column",
+                )?,
+                ascending: match fields.remove("ascending") {
+                    None => true,
+                    Some(JsonValue::Bool(ascending)) => ascending,
+                    Some(_) => return Err(Box::from("'ascending' should be a bool")),
+                },
+            }
+        }
+        "aggregate" => Step::Aggregate(
+            as_object(value, "aggregate")?
+                .into_iter()
+                .map(|(column, functions)| {
+                    let functions = match functions {
+                        JsonValue::Array(_) => as_strings(functions, "aggregate")?,
+                        function => vec![as_string(function, "aggregate")?],
+                    };
+                    if let Some(unknown) = functions
+                        .iter()
+                        .find(|f| !AGGREGATIONS.contains(&f.as_str()))
+                    {
+                        return Err(Box::from(format!(
+                            "unknown aggregation '{unknown}', expected one of {}",
+                            AGGREGATIONS.join(", ")
+                        )));
+                    }
+                    Ok((column, functions))
+                })
+                .collect::<Result<_, Box<dyn Error>>>()?,
+        ),
+        name => return Err(Box::from(format!("unknown step '{name}'"))),
+    };
+
+    Ok(step)
+}
+
+fn as_string(value: JsonValue, key: &str) -> Result<String, Box<dyn Error>> {
+    match value {
+        JsonValue::String(s) => Ok(s),
+        JsonValue::Number(n) => Ok(n.to_string()),
+        _ => Err(Box::from(format!("'{key}' should be a string"))),
+    }
+}
+
+fn as_char(value: JsonValue, key: &str) -> Result<char, Box<dyn Error>> {
+    let text = as_string(value, key)?;
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(Box::from(format!("'{key}' should be a single character"))),
+    }
+}
+
+fn as_count(value: JsonValue, key: &str) -> Result<usize, Box<dyn Error>> {
+    match value {
+        JsonValue::Number(Cell::Int(n)) if n >= 0 => Ok(n as usize),
+        JsonValue::Number(Cell::UInt(n)) => Ok(n as usize),
+        _ => Err(Box::from(format!(
+            "'{key}' should be a non-negative integer"
+        ))),
+    }
+}
+
+fn as_array(value: JsonValue, key: &str) -> Result<Vec<JsonValue>, Box<dyn Error>> {
+    match value {
+        JsonValue::Array(items) => Ok(items),
+        _ => Err(Box::from(format!("'{key}' should be a sequence"))),
+    }
+}
+
+fn as_strings(value: JsonValue, key: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    as_array(value, key)?
+        .into_iter()
+        .map(|item| as_string(item, key))
+        .collect()
+}
+
+fn as_object(value: JsonValue, key: &str) -> Result<Vec<(String, JsonValue)>, Box<dyn Error>> {
+    match value {
+        JsonValue::Object(fields) => Ok(fields),
+        _ => Err(Box::from(format!("'{key}' should be a mapping"))),
+    }
+}
@@ -0,0 +1,267 @@
+use std::{error::Error, fmt::Write};
+
+use postgres::{
+    types::{FromSql, Type},
+    Client, NoTls,
+};
+
+use crate::{Cell, ColumnRef, Row, Sheet, SqlDialect};
+
+impl Sheet {
+    /// Loads the result of a SQL query against a PostgreSQL database into a Sheet.
+    ///
+    /// The column names of the query make up the header row. `SMALLINT`, `INTEGER` and `BIGINT`
+    /// columns become `Cell::Int`, `REAL` and `DOUBLE PRECISION` columns `Cell::Float`, `BOOLEAN`
+    /// columns `Cell::Bool` and `TEXT`, `VARCHAR`, `CHAR` and `NAME` columns `Cell::String`,
+    /// while SQL NULL maps to `Cell::Null`. `NUMERIC` values become `Cell::Float`, or
+    /// `Cell::String` when they hold more significant digits than a float keeps exactly. Other
+    /// types, such as timestamps, have to be cast in the query, e.g. `SELECT at::text ...`.
+    ///
+    /// The connection doesn't use TLS. This method is only available with the `postgres` feature
+    /// enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn_str` - The connection string, e.g. `"host=localhost user=postgres dbname=movies"`.
+    /// * `query` - The SQL query selecting the data to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or the query fails, or if the result set holds a column
+    /// type that can't be mapped to a `Cell`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_postgres(
+    ///     "host=localhost user=postgres dbname=movies",
+    ///     "SELECT id, title, review FROM movies",
+    /// )
+    /// .unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    #[allow(deprecated)]
+    pub fn load_postgres(conn_str: &str, query: &str) -> Result<Self, Box<dyn Error>> {
+        let mut client = Client::connect(conn_str, NoTls)?;
+        let stmt = client.prepare(query)?;
+
+        let mut sheet = Self::new_sheet();
+        sheet.data.push(
+            stmt.columns()
+                .iter()
+                .map(|c| Cell::String(c.name().to_string()))
+                .collect(),
+        );
+
+        for column in stmt.columns() {
+            if !is_supported(column.type_()) {
+                return Err(Box::from(format!(
+                    "unsupported postgres type '{}' for column '{}'",
+                    column.type_(),
+                    column.name()
+                )));
+            }
+        }
+
+        for row in client.query(&stmt, &[])? {
+            let mut cells = Row(Vec::with_capacity(row.len()));
+            for (i, column) in row.columns().iter().enumerate() {
+                let cell = match *column.type_() {
+                    Type::INT2 => row
+                        .try_get::<_, Option<i16>>(i)?
+                        .map(|v| Cell::Int(v as i64)),
+                    Type::INT4 => row
+                        .try_get::<_, Option<i32>>(i)?
+                        .map(|v| Cell::Int(v as i64)),
+                    Type::INT8 => row.try_get::<_, Option<i64>>(i)?.map(Cell::Int),
+                    Type::FLOAT4 => row
+                        .try_get::<_, Option<f32>>(i)?
+                        .map(|v| Cell::Float(v as f64)),
+                    Type::FLOAT8 => row.try_get::<_, Option<f64>>(i)?.map(Cell::Float),
+                    Type::BOOL => row.try_get::<_, Option<bool>>(i)?.map(Cell::Bool),
+                    Type::NUMERIC => row.try_get::<_, Option<Numeric>>(i)?.map(|n| n.0),
+                    _ => row.try_get::<_, Option<String>>(i)?.map(Cell::String),
+                };
+                cells.push(cell.unwrap_or(Cell::Null));
+            }
+            sheet.data.push(cells);
+        }
+
+        Ok(sheet)
+    }
+
+    /// Upserts the rows of a Sheet into a table of a PostgreSQL database, keyed on a column.
+    ///
+    /// Rows whose key is already in the table update the existing row, and the other rows are
+    /// inserted, so cleaned sheets can maintain a live table. Rows are written in batched
+    /// `INSERT ... ON CONFLICT` statements of up to 500 rows, within a single transaction. If the
+    /// table doesn't exist, it's created with one column per header cell, typed from its cells,
+    /// and the key column as its primary key; an existing table needs a primary key or unique
+    /// constraint on it.
+    ///
+    /// The connection doesn't use TLS. This method is only available with the `postgres` feature
+    /// enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn_str` - The connection string, e.g. `"host=localhost user=postgres dbname=movies"`.
+    /// * `table_name` - The name of the table to upsert into.
+    /// * `key` - The column identifying rows.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows inserted or updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key column doesn't exist or holds null or duplicated values, if
+    /// the connection fails, or if a statement fails, in which case nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet
+    ///     .upsert_postgres("host=localhost user=postgres dbname=movies", "movies", "id")
+    ///     .unwrap();
+    /// ```
+    #[allow(deprecated)]
+    pub fn upsert_postgres<'a>(
+        &self,
+        conn_str: &str,
+        table_name: &str,
+        key: impl Into<ColumnRef<'a>>,
+    ) -> Result<u64, Box<dyn Error>> {
+        let key_index = self.upsert_key(key)?;
+        let header = &self.data[0];
+        let columns = (0..header.len())
+            .map(|j| {
+                let sql_type =
+                    SqlDialect::Postgres.column_type(self.data.iter().skip(1).map(|row| &row[j]));
+                format!(
+                    "{} {sql_type}",
+                    SqlDialect::Postgres.quote_identifier(&header[j].to_string())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut client = Client::connect(conn_str, NoTls)?;
+        let mut tx = client.transaction()?;
+        tx.batch_execute(&format!(
+            "CREATE TABLE IF NOT EXISTS {} ({columns}, PRIMARY KEY ({}))",
+            SqlDialect::Postgres.quote_identifier(table_name),
+            SqlDialect::Postgres.quote_identifier(&header[key_index].to_string())
+        ))?;
+        let mut written = 0;
+        for statement in self.sql_upserts(table_name, key_index, SqlDialect::Postgres) {
+            written += tx.execute(statement.as_str(), &[])?;
+        }
+        tx.commit()?;
+
+        Ok(written)
+    }
+}
+
+/// Tells whether a PostgreSQL type can be mapped to a `Cell`.
+fn is_supported(t: &Type) -> bool {
+    matches!(
+        *t,
+        Type::INT2
+            | Type::INT4
+            | Type::INT8
+            | Type::FLOAT4
+            | Type::FLOAT8
+            | Type::BOOL
+            | Type::NUMERIC
+            | Type::TEXT
+            | Type::VARCHAR
+            | Type::BPCHAR
+            | Type::NAME
+    )
+}
+
+/// the most significant digits a float holds exactly
+const FLOAT_DIGITS: usize = 15;
+
+/// A `NUMERIC` value, as a float or as a string when it's too precise for one.
+struct Numeric(Cell);
+
+impl<'a> FromSql<'a> for Numeric {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        numeric_cell(raw).map(Numeric)
+    }
+
+    fn accepts(t: &Type) -> bool {
+        *t == Type::NUMERIC
+    }
+}
+
+/// Decodes the binary format of a `NUMERIC` value: its number of digits, the weight of the
+/// first one, its sign and its display scale, followed by its digits in base 10000.
+pub(crate) fn numeric_cell(raw: &[u8]) -> Result<Cell, Box<dyn Error + Sync + Send>> {
+    let word = |i: usize| {
+        raw.get(2 * i..2 * i + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .ok_or("truncated numeric value")
+    };
+    let (len, weight, sign, scale) = (word(0)?, word(1)? as i16, word(2)?, word(3)?);
+    let negative = match sign {
+        0x0000 => false,
+        0x4000 => true,
+        0xC000 => return Ok(Cell::Float(f64::NAN)),
+        0xD000 => return Ok(Cell::Float(f64::INFINITY)),
+        0xF000 => return Ok(Cell::Float(f64::NEG_INFINITY)),
+        _ => return Err(Box::from("invalid numeric sign")),
+    };
+    let digits = (0..usize::from(len))
+        .map(|i| word(4 + i))
+        .collect::<Result<Vec<u16>, _>>()?;
+    // the digit at `i` is worth 10000^(weight - i), absent digits are zeros
+    let digit = |i: i32| usize::try_from(i).ok().and_then(|i| digits.get(i)).copied();
+
+    let mut text = String::new();
+    if negative {
+        text.push('-');
+    }
+    if weight < 0 {
+        text.push('0');
+    }
+    for i in 0..=i32::from(weight) {
+        let d = digit(i).unwrap_or(0);
+        if i == 0 {
+            write!(text, "{d}")?;
+        } else {
+            write!(text, "{d:04}")?;
+        }
+    }
+    if scale > 0 {
+        let mut fraction = String::new();
+        for k in 1..=i32::from(scale.div_ceil(4)) {
+            write!(fraction, "{:04}", digit(i32::from(weight) + k).unwrap_or(0))?;
+        }
+        fraction.truncate(usize::from(scale));
+        write!(text, ".{fraction}")?;
+    }
+
+    // trailing zeros of the fraction only pad the value up to the column scale
+    let digits = if text.contains('.') {
+        text.trim_end_matches('0')
+    } else {
+        &text
+    };
+    let significant = digits
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .skip_while(|&b| b == b'0')
+        .count();
+    if significant > FLOAT_DIGITS {
+        return Ok(Cell::String(text));
+    }
+
+    Ok(Cell::Float(text.parse()?))
+}
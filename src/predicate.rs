@@ -0,0 +1,116 @@
+//! Prebuilt predicates over cells, for [`Sheet::filter`], [`Sheet::count_where`],
+//! [`Sheet::add_validator`] and every other method taking a `Fn(&Cell) -> bool`.
+//!
+//! Numbers are compared by value whatever their type, strings lexicographically and bools only
+//! for equality. Comparing a null, NaN or values of different kinds is always false, `ne`
+//! included, so nulls are only matched by [`is_null`], and `ne(Cell::Null)` matches nothing:
+//! like in SQL, a comparison involving an unknown value isn't true. The `filter` step of a
+//! [`Pipeline`] follows the same rules.
+//!
+//! Predicates compose with the [`PredicateExt`] combinators.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use datatroll::predicate::{ge, is_null, lt, PredicateExt};
+//! use datatroll::{Cell, Sheet};
+//!
+//! let sheet = Sheet::load_data_from_str("title,review\nold,3.5\nher,\neasy,1.0\nwho,5.0");
+//!
+//! let mid = ge(Cell::Float(2.0)).and(lt(Cell::Int(5)));
+//! assert_eq!(sheet.filter("review", mid).len(), 1);
+//! assert_eq!(sheet.filter("review", is_null().or(lt(Cell::Int(2)))).len(), 2);
+//! ```
+//!
+//! [`Sheet::filter`]: crate::Sheet::filter
+//! [`Sheet::count_where`]: crate::Sheet::count_where
+//! [`Sheet::add_validator`]: crate::Sheet::add_validator
+//! [`Pipeline`]: crate::Pipeline
+
+use std::cmp::Ordering;
+
+use crate::{compare_values, Cell};
+
+/// matches compares a cell to a value, false for nulls and values of different kinds
+///
+/// The pipeline `filter` step shares these semantics, see [`crate::Pipeline`].
+pub(crate) fn matches(cell: &Cell, value: &Cell, accept: fn(Ordering) -> bool) -> bool {
+    match (cell, value) {
+        (Cell::Null, _) | (_, Cell::Null) => false,
+        // bools are unordered: different ones only satisfy `ne`, which accepts both orderings
+        (Cell::Bool(a), Cell::Bool(b)) if a == b => accept(Ordering::Equal),
+        (Cell::Bool(_), Cell::Bool(_)) => accept(Ordering::Less) && accept(Ordering::Greater),
+        _ => compare_values(cell, value).is_some_and(accept),
+    }
+}
+
+/// gt matches the cells greater than `value`
+pub fn gt(value: Cell) -> impl Fn(&Cell) -> bool + Clone + Send + Sync + 'static {
+    move |cell| matches(cell, &value, Ordering::is_gt)
+}
+
+/// ge matches the cells greater than or equal to `value`
+pub fn ge(value: Cell) -> impl Fn(&Cell) -> bool + Clone + Send + Sync + 'static {
+    move |cell| matches(cell, &value, Ordering::is_ge)
+}
+
+/// lt matches the cells less than `value`
+pub fn lt(value: Cell) -> impl Fn(&Cell) -> bool + Clone + Send + Sync + 'static {
+    move |cell| matches(cell, &value, Ordering::is_lt)
+}
+
+/// le matches the cells less than or equal to `value`
+pub fn le(value: Cell) -> impl Fn(&Cell) -> bool + Clone + Send + Sync + 'static {
+    move |cell| matches(cell, &value, Ordering::is_le)
+}
+
+/// eq matches the cells equal to `value`, so `eq(Cell::Int(4))` matches `Cell::Float(4.0)`
+pub fn eq(value: Cell) -> impl Fn(&Cell) -> bool + Clone + Send + Sync + 'static {
+    move |cell| matches(cell, &value, Ordering::is_eq)
+}
+
+/// ne matches the non-null cells different from `value`, of the same kind, so it isn't the
+/// negation of [`eq`] over nulls and values of other kinds
+pub fn ne(value: Cell) -> impl Fn(&Cell) -> bool + Clone + Send + Sync + 'static {
+    move |cell| matches(cell, &value, Ordering::is_ne)
+}
+
+/// is_null matches the null cells
+pub fn is_null() -> impl Fn(&Cell) -> bool + Clone + Send + Sync + 'static {
+    |cell| *cell == Cell::Null
+}
+
+/// is_not_null matches the non-null cells
+pub fn is_not_null() -> impl Fn(&Cell) -> bool + Clone + Send + Sync + 'static {
+    |cell| *cell != Cell::Null
+}
+
+/// Combinators available on every predicate over cells, prebuilt or not.
+///
+/// The combined predicates can be shared across threads, e.g. by [`Sheet::add_validator`].
+///
+/// [`Sheet::add_validator`]: crate::Sheet::add_validator
+pub trait PredicateExt: Fn(&Cell) -> bool + Send + Sync + Sized {
+    /// and matches the cells matched by both predicates
+    fn and<P>(self, other: P) -> impl Fn(&Cell) -> bool + Send + Sync
+    where
+        P: Fn(&Cell) -> bool + Send + Sync,
+    {
+        move |cell| self(cell) && other(cell)
+    }
+
+    /// or matches the cells matched by either predicate
+    fn or<P>(self, other: P) -> impl Fn(&Cell) -> bool + Send + Sync
+    where
+        P: Fn(&Cell) -> bool + Send + Sync,
+    {
+        move |cell| self(cell) || other(cell)
+    }
+
+    /// not matches the cells the predicate doesn't match, nulls included
+    fn not(self) -> impl Fn(&Cell) -> bool + Send + Sync {
+        move |cell| !self(cell)
+    }
+}
+
+impl<F: Fn(&Cell) -> bool + Send + Sync> PredicateExt for F {}
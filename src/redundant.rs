@@ -0,0 +1,132 @@
+use std::error::Error;
+
+use crate::{Cell, Sheet};
+
+/// A column found to repeat the information of another one, as returned by
+/// [`Sheet::find_redundant_cols`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedundantCol {
+    /// name of the redundant column
+    pub column: String,
+    /// name of the earlier column it repeats
+    pub duplicate_of: String,
+    /// Pearson correlation between both columns, 1.0 when their values are identical
+    pub correlation: f64,
+}
+
+impl Sheet {
+    /// find_redundant_cols flags the columns which repeat the information of an earlier column
+    ///
+    /// A column is redundant when its values are identical to those of an earlier column, or
+    /// when both columns are numeric and the absolute value of their Pearson correlation reaches
+    /// `threshold`. The correlation is computed over the rows where both values are non-null,
+    /// and columns holding a single distinct number are never considered correlated. Every
+    /// column is reported at most once, against the first column it repeats, and columns
+    /// already reported are not used as a reference for later ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - the minimal absolute correlation, between 0 and 1
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `threshold` is not between 0 and 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str(
+    ///     "id,price,price_cents,label,label_copy\n1,2.5,250,a,a\n2,4.0,400,b,b\n3,1.0,100,c,c",
+    /// );
+    /// let redundant = sheet.find_redundant_cols(0.99).unwrap();
+    ///
+    /// assert_eq!(redundant.len(), 2);
+    /// assert_eq!(redundant[0].column, "price_cents");
+    /// assert_eq!(redundant[0].duplicate_of, "price");
+    /// assert_eq!(redundant[1].column, "label_copy");
+    /// assert_eq!(redundant[1].correlation, 1.0);
+    /// ```
+    #[allow(deprecated)]
+    pub fn find_redundant_cols(&self, threshold: f64) -> Result<Vec<RedundantCol>, Box<dyn Error>> {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(Box::from(format!(
+                "threshold should be between 0 and 1, got '{threshold}'"
+            )));
+        }
+
+        if self.data.len() < 2 {
+            return Ok(Vec::new());
+        }
+
+        let names: Vec<String> = self.data[0].iter().map(|c| c.to_string()).collect();
+        let numeric: Vec<bool> = (0..names.len()).map(|i| self.is_numeric_col(i)).collect();
+        let mut redundant: Vec<RedundantCol> = Vec::new();
+        let mut flagged = vec![false; names.len()];
+
+        for j in 1..names.len() {
+            for i in (0..j).filter(|&i| !flagged[i]) {
+                let correlation = if self.data.iter().skip(1).all(|row| row[i] == row[j]) {
+                    Some(1.0)
+                } else if numeric[i] && numeric[j] {
+                    self.pearson(i, j).filter(|r| r.abs() >= threshold)
+                } else {
+                    None
+                };
+
+                if let Some(correlation) = correlation {
+                    flagged[j] = true;
+                    redundant.push(RedundantCol {
+                        column: names[j].clone(),
+                        duplicate_of: names[i].clone(),
+                        correlation,
+                    });
+                    break;
+                }
+            }
+        }
+
+        Ok(redundant)
+    }
+
+    /// pearson computes the correlation of two numeric columns over the rows where both are
+    /// non-null, None when one of them doesn't vary
+    #[allow(deprecated)]
+    fn pearson(&self, i: usize, j: usize) -> Option<f64> {
+        let pairs: Vec<(f64, f64)> = self
+            .data
+            .iter()
+            .skip(1)
+            .filter_map(|row| Some((as_f64(&row[i])?, as_f64(&row[j])?)))
+            .collect();
+        if pairs.len() < 2 {
+            return None;
+        }
+
+        let n = pairs.len() as f64;
+        let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+        for (x, y) in &pairs {
+            cov += (x - mean_x) * (y - mean_y);
+            var_x += (x - mean_x).powi(2);
+            var_y += (y - mean_y).powi(2);
+        }
+        if var_x == 0.0 || var_y == 0.0 {
+            return None;
+        }
+
+        Some(cov / (var_x * var_y).sqrt())
+    }
+}
+
+/// as_f64 reads a numeric cell as a float
+fn as_f64(cell: &Cell) -> Option<f64> {
+    match cell {
+        Cell::Int(x) => Some(*x as f64),
+        Cell::UInt(u) => Some(*u as f64),
+        Cell::Float(f) => Some(*f),
+        _ => None,
+    }
+}
@@ -0,0 +1,158 @@
+use std::error::Error;
+
+use crate::{Cell, ColumnRef, Row, Sheet};
+
+pub(crate) const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+impl Sheet {
+    /// melt_dates reshapes a sheet holding one column per period into one row per period
+    ///
+    /// Spreadsheets often lay out time series with one column per month or year, such as
+    /// `region, Jan 2023, Feb 2023, ...`. Every column which isn't an id column is treated as a
+    /// period: its header is parsed into an ISO `YYYY-MM-DD` date, and every input row yields one
+    /// output row per period made of the id cells, the date and the value found under that
+    /// period. Periods without a day start on the 1st, and year-only periods on January 1st.
+    ///
+    /// Supported headers include `2023`, `2023-01`, `2023/01/15`, `01/2023`, `Jan 2023`,
+    /// `January-2023`, `2023 Jan` and `Jan-23`, where two-digit years are read as 20xx.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_columns` - the columns kept on every output row
+    /// * `date_name` - the name of the new date column
+    /// * `value_name` - the name of the new value column
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an id column doesn't exist, or if a period header can't be parsed as
+    /// a date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("region,Jan 2023,Feb 2023\nnorth,10,12\nsouth,7,9");
+    /// let long = sheet.melt_dates(&["region"], "month", "sales").unwrap();
+    ///
+    /// assert_eq!(long.row_count(), 4);
+    /// assert_eq!(
+    ///     long.row(1).unwrap(),
+    ///     vec![
+    ///         Cell::String("north".to_string()),
+    ///         Cell::String("2023-02-01".to_string()),
+    ///         Cell::Int(12),
+    ///     ]
+    /// );
+    /// ```
+    #[allow(deprecated)]
+    pub fn melt_dates<'a, C>(
+        &self,
+        id_columns: &[C],
+        date_name: &str,
+        value_name: &str,
+    ) -> Result<Sheet, Box<dyn Error>>
+    where
+        C: Into<ColumnRef<'a>> + Copy,
+    {
+        let id_indices = self.col_indices(id_columns)?;
+        let periods = self.data[0]
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| !id_indices.contains(j))
+            .map(|(j, header)| {
+                let (year, month, day) = parse_period(&header.to_string())
+                    .ok_or_else(|| format!("could not parse column '{header}' as a date"))?;
+                Ok((j, Cell::String(format!("{year:04}-{month:02}-{day:02}"))))
+            })
+            .collect::<Result<Vec<(usize, Cell)>, String>>()?;
+
+        let mut sheet = Self::new_sheet();
+        sheet.delimiter = self.delimiter;
+
+        let mut header: Row = id_indices
+            .iter()
+            .map(|&j| self.data[0][j].clone())
+            .collect();
+        header.push(Cell::String(date_name.to_string()));
+        header.push(Cell::String(value_name.to_string()));
+        sheet.data.push(header);
+
+        for row in self.data.iter().skip(1) {
+            for (j, date) in &periods {
+                let mut melted: Row = id_indices.iter().map(|&i| row[i].clone()).collect();
+                melted.push(date.clone());
+                melted.push(row[*j].clone());
+                sheet.data.push(melted);
+            }
+        }
+
+        Ok(sheet)
+    }
+}
+
+/// Parses a period header into a `(year, month, day)` date.
+fn parse_period(header: &str) -> Option<(u32, u32, u32)> {
+    let tokens: Vec<&str> = header
+        .split([' ', '-', '/', '_', '.'])
+        .filter(|t| !t.is_empty())
+        .collect();
+    if tokens.is_empty() || tokens.len() > 3 {
+        return None;
+    }
+
+    let mut year = None;
+    let mut month = None;
+    let mut numbers = Vec::new();
+    for token in &tokens {
+        if token.chars().all(|c| c.is_ascii_digit()) {
+            if token.len() == 4 && year.is_none() {
+                year = Some(token.parse().ok()?);
+            } else if token.len() <= 2 {
+                numbers.push(token.parse::<u32>().ok()?);
+            } else {
+                return None;
+            }
+        } else {
+            let name = token.to_ascii_lowercase();
+            // full names and abbreviations of at least three letters, such as "sept"
+            let position = MONTHS
+                .iter()
+                .position(|m| name.len() >= 3 && m.starts_with(&name))?;
+            if month.is_some() {
+                return None;
+            }
+            month = Some(position as u32 + 1);
+        }
+    }
+
+    let mut numbers = numbers.into_iter();
+    if year.is_none() && month.is_some() {
+        // month names followed by a two-digit year, such as Jan-23
+        year = numbers.next().map(|yy| 2000 + yy);
+    }
+    let year = year?;
+    let month = match month {
+        Some(m) => m,
+        None => numbers.next().unwrap_or(1),
+    };
+    let day = numbers.next().unwrap_or(1);
+    if numbers.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    Some((year, month, day))
+}
@@ -0,0 +1,321 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+};
+
+use crate::{Cell, CellKey, ColumnRef, Sheet};
+
+/// z-score of the 95% confidence level
+const Z_95: f64 = 1.96;
+
+/// Approximate statistics of a numeric column, as returned by [`Sheet::summary_sampled`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampledStats {
+    /// the name of the column
+    pub column: String,
+    /// number of non-null values in the sample
+    pub sample_size: usize,
+    /// share of null values in the sample, between 0 and 1
+    pub null_ratio: f64,
+    /// mean of the sampled values
+    pub mean: f64,
+    /// half-width of the 95% confidence interval of the mean: the mean of the whole column lies
+    /// within `mean ± mean_margin` with a 95% probability, 0 when every row was sampled
+    pub mean_margin: f64,
+    /// sample standard deviation of the sampled values
+    pub std_dev: f64,
+    /// smallest sampled value, the column may hold smaller ones
+    pub min: f64,
+    /// largest sampled value, the column may hold larger ones
+    pub max: f64,
+}
+
+impl Sheet {
+    /// summary_sampled computes approximate statistics of every numeric column out of a random
+    /// sample of rows
+    ///
+    /// Only the sampled rows are read, so exploring a sheet of tens of millions of rows doesn't
+    /// require full scans. Every statistic comes with a hint about its precision: the mean is
+    /// given with the margin of its 95% confidence interval, and min and max are only those of
+    /// the sample. Columns are considered numeric when their sampled non-null values are all
+    /// numbers. When `n` is at least the number of rows, every row is used and the statistics
+    /// are exact.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - the number of rows to sample
+    /// * `seed` - the seed of the random generator, the same seed always picking the same rows
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let data: String = (0..10_000).fold("id,value\n".to_string(), |acc, i| {
+    ///     acc + &format!("{i},{}\n", i % 100)
+    /// });
+    /// let sheet = Sheet::load_data_from_str(&data);
+    /// let stats = sheet.summary_sampled(1_000, 42).unwrap();
+    ///
+    /// let value = &stats[1];
+    /// assert_eq!(value.column, "value");
+    /// assert!((value.mean - 49.5).abs() < 2.0 * value.mean_margin);
+    /// ```
+    #[allow(deprecated)]
+    pub fn summary_sampled(
+        &self,
+        n: usize,
+        seed: u64,
+    ) -> Result<Vec<SampledStats>, Box<dyn Error>> {
+        if n == 0 {
+            return Err(Box::from("sample size should be greater than 0"));
+        }
+
+        let population = self.row_count();
+        let mut rng = SplitMix64(seed);
+        let rows: Vec<usize> = if n >= population {
+            (1..=population).collect()
+        } else {
+            rng.sample_indices(population, n)
+                .into_iter()
+                .map(|i| i + 1)
+                .collect()
+        };
+
+        let mut summaries = Vec::new();
+        'columns: for (j, name) in self.header().unwrap_or_default().iter().enumerate() {
+            let mut values = Vec::with_capacity(rows.len());
+            for &i in &rows {
+                match &self.data[i][j] {
+                    Cell::Null => {}
+                    Cell::Int(x) => values.push(*x as f64),
+                    Cell::UInt(u) => values.push(*u as f64),
+                    Cell::Float(f) => values.push(*f),
+                    _ => continue 'columns,
+                }
+            }
+            if values.is_empty() {
+                continue;
+            }
+
+            let count = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / count;
+            let std_dev = if values.len() > 1 {
+                (values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (count - 1.0)).sqrt()
+            } else {
+                0.0
+            };
+            // finite population correction, which shrinks the margin as the sample grows
+            // towards the whole column
+            let fpc = if population > 1 {
+                ((population - rows.len()) as f64 / (population - 1) as f64).sqrt()
+            } else {
+                0.0
+            };
+
+            summaries.push(SampledStats {
+                column: name.to_string(),
+                sample_size: values.len(),
+                null_ratio: 1.0 - count / rows.len() as f64,
+                mean,
+                mean_margin: Z_95 * std_dev / count.sqrt() * fpc,
+                std_dev,
+                min: values.iter().copied().fold(f64::INFINITY, f64::min),
+                max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            });
+        }
+
+        Ok(summaries)
+    }
+
+    /// sample_weighted draws `n` distinct rows at random, each with a probability proportional
+    /// to its value in a numeric column
+    ///
+    /// Rows are drawn without replacement, as needed for importance sampling or weighted
+    /// surveys: a row of weight 2 is twice as likely as a row of weight 1 to be drawn first.
+    /// Rows with a null or zero weight are never drawn. The drawn rows keep their original
+    /// order, and the original Sheet is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - the number of rows to draw
+    /// * `weight_col` - the numeric column holding the weight of every row
+    /// * `seed` - the seed of the random generator, the same seed always drawing the same rows
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, if it holds a value that isn't a finite
+    /// non-negative number, or if fewer than `n` rows have a positive weight.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("city,population\nparis,2100000\nlyon,520000\nnowhere,0");
+    /// let sample = sheet.sample_weighted(2, "population", 7).unwrap();
+    ///
+    /// assert_eq!(sample.row_count(), 2);
+    /// assert!(sample.rows().all(|row| row[1] != Cell::Int(0)));
+    /// ```
+    #[allow(deprecated)]
+    pub fn sample_weighted<'a>(
+        &self,
+        n: usize,
+        weight_col: impl Into<ColumnRef<'a>>,
+        seed: u64,
+    ) -> Result<Sheet, Box<dyn Error>> {
+        let col_index = self.col_index(weight_col)?;
+
+        let mut rng = SplitMix64(seed);
+        // Efraimidis-Spirakis: drawing the rows with the n largest u^(1/w) keys, u being uniform
+        // in (0, 1], is the same as drawing them one after the other proportionally to w. Keys
+        // are compared through their logarithm, ln(u)/w, to keep small weights precise.
+        let mut keys: Vec<(f64, usize)> = Vec::new();
+        for (i, row) in self.data.iter().enumerate().skip(1) {
+            let weight = match &row[col_index] {
+                Cell::Null => continue,
+                Cell::Int(x) => *x as f64,
+                Cell::UInt(u) => *u as f64,
+                Cell::Float(f) => *f,
+                cell => {
+                    return Err(Box::from(format!(
+                        "weight '{cell}' of row '{i}' is not a number"
+                    )))
+                }
+            };
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(Box::from(format!(
+                    "weight '{weight}' of row '{i}' should be a finite non-negative number"
+                )));
+            }
+            if weight > 0.0 {
+                keys.push(((1.0 - rng.next_f64()).ln() / weight, i));
+            }
+        }
+        if keys.len() < n {
+            return Err(Box::from(format!(
+                "cannot draw {n} rows out of {} rows with a positive weight",
+                keys.len()
+            )));
+        }
+
+        keys.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+        let mut picked: Vec<usize> = keys[..n].iter().map(|(_, i)| *i).collect();
+        picked.sort_unstable();
+        let rows: Vec<_> = picked.into_iter().map(|i| self.data[i].clone()).collect();
+
+        Ok(self.shard(&rows))
+    }
+
+    /// sample_per_group draws up to `n` random rows out of every group of rows sharing the same
+    /// value in a column
+    ///
+    /// Groups holding `n` rows or fewer are kept whole, which is how balanced review or QA subsets
+    /// are usually produced. Null values form a group of their own. The drawn rows keep their
+    /// original order, and the original Sheet is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_col` - the column the rows are grouped by
+    /// * `n` - the maximum number of rows drawn out of every group
+    /// * `seed` - the seed of the random generator, the same seed always drawing the same rows
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,label\n1,spam\n2,spam\n3,spam\n4,ham\n5,spam");
+    /// let balanced = sheet.sample_per_group("label", 2, 42).unwrap();
+    ///
+    /// assert_eq!(balanced.row_count(), 3);
+    /// ```
+    #[allow(deprecated)]
+    pub fn sample_per_group<'a>(
+        &self,
+        group_col: impl Into<ColumnRef<'a>>,
+        n: usize,
+        seed: u64,
+    ) -> Result<Sheet, Box<dyn Error>> {
+        let col_index = self.col_index(group_col)?;
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut positions: HashMap<CellKey, usize> = HashMap::new();
+        for (i, row) in self.data.iter().enumerate().skip(1) {
+            let position = *positions
+                .entry(CellKey(&row[col_index]))
+                .or_insert_with(|| {
+                    groups.push(Vec::new());
+                    groups.len() - 1
+                });
+            groups[position].push(i);
+        }
+
+        let mut rng = SplitMix64(seed);
+        let mut picked: Vec<usize> = Vec::new();
+        for group in groups {
+            if group.len() <= n {
+                picked.extend(group);
+            } else {
+                picked.extend(
+                    rng.sample_indices(group.len(), n)
+                        .into_iter()
+                        .map(|k| group[k]),
+                );
+            }
+        }
+        picked.sort_unstable();
+        let rows: Vec<_> = picked.into_iter().map(|i| self.data[i].clone()).collect();
+
+        Ok(self.shard(&rows))
+    }
+}
+
+/// A small, seedable pseudo-random generator. It's not suitable for cryptography, but it's
+/// fast and gives the same sequence for the same seed on every platform.
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// next_f64 returns a float uniformly distributed in `[0, 1)`
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    /// below returns an integer uniformly distributed in `[0, bound)`
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next_f64() * bound as f64) as usize
+    }
+
+    /// sample_indices picks `n` distinct indices in `[0, population)`, in increasing order,
+    /// using Floyd's algorithm so that only `n` indices are ever stored
+    pub(crate) fn sample_indices(&mut self, population: usize, n: usize) -> Vec<usize> {
+        let mut picked: HashSet<usize> = HashSet::with_capacity(n);
+        for j in population - n..population {
+            let t = self.below(j + 1);
+            if !picked.insert(t) {
+                picked.insert(j);
+            }
+        }
+
+        let mut indices: Vec<usize> = picked.into_iter().collect();
+        indices.sort_unstable();
+        indices
+    }
+}
@@ -0,0 +1,286 @@
+use std::{collections::HashMap, error::Error, fs::File, io::Read};
+
+use crate::{parse_token, strip_bom, Cell, LoadOptions, Sheet, TrimPolicy};
+
+/// number of bytes read from the start of a file to detect its dialect
+const SNIFF_BYTES: u64 = 64 * 1024;
+/// delimiters considered by the sniffer, by order of preference
+const DELIMITERS: [char; 5] = [',', ';', '\t', '|', ':'];
+/// quote characters considered by the sniffer, by order of preference
+const QUOTES: [char; 2] = ['"', '\''];
+
+/// How lines of a delimited file end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineTerminator {
+    /// `\n`, as written on Unix
+    #[default]
+    Lf,
+    /// `\r\n`, as written on Windows
+    CrLf,
+    /// `\r`, as written by classic Mac OS
+    Cr,
+}
+
+/// The layout of a delimited file, as detected by [`Sheet::sniff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dialect {
+    /// the character separating the values of a row
+    pub delimiter: char,
+    /// the character wrapping values that contain special characters, None if no value is quoted
+    pub quote: Option<char>,
+    /// whether the first line holds column names rather than values
+    pub has_header: bool,
+    /// how lines end
+    pub line_terminator: LineTerminator,
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote: None,
+            has_header: true,
+            line_terminator: LineTerminator::default(),
+        }
+    }
+}
+
+impl Dialect {
+    /// load_options returns the options to load a file of this dialect with
+    /// [`Sheet::load_data_with`]
+    ///
+    /// The delimiter, the quote character and the presence of a header are carried over, and
+    /// quoted values get their quotes removed. Lines ending with `\n` or `\r\n` are both read
+    /// by the loaders.
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            delimiter: self.delimiter,
+            trim: match self.quote {
+                Some(_) => TrimPolicy::UnquotedOnly,
+                None => TrimPolicy::default(),
+            },
+            quote_char: self.quote.unwrap_or('"'),
+            header: self.has_header,
+            ..Default::default()
+        }
+    }
+}
+
+impl Sheet {
+    /// sniff detects the dialect of a delimited file out of its first 64 KB
+    ///
+    /// The delimiter is the candidate among `,`, `;`, tab, `|` and `:` that splits the sampled
+    /// lines into the most consistent number of fields, ignoring delimiters within double
+    /// quotes. The quote character is `"` or `'` when values are wrapped in it. The first line
+    /// is considered a header unless it looks like the lines following it, e.g. when it holds
+    /// numbers where the other lines hold numbers too. The line terminator is the most frequent
+    /// one. An empty file gets the default dialect: comma separated, unquoted, with a header
+    /// and `\n` line endings.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file, of any extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let dialect = Sheet::sniff("export.txt").unwrap();
+    /// let sheet = Sheet::load_data_with("export.txt", &dialect.load_options()).unwrap();
+    /// ```
+    pub fn sniff(file_path: &str) -> Result<Dialect, Box<dyn Error>> {
+        let mut sample = Vec::new();
+        File::open(file_path)?
+            .take(SNIFF_BYTES)
+            .read_to_end(&mut sample)?;
+        let truncated = sample.len() as u64 == SNIFF_BYTES;
+
+        let mut text = String::from_utf8_lossy(&sample).into_owned();
+        if truncated {
+            // the last line may be cut short
+            if let Some(end) = text.rfind(['\n', '\r']) {
+                text.truncate(end);
+            }
+        }
+
+        Ok(Self::sniff_str(&text))
+    }
+
+    /// sniff_str detects the dialect of delimited text, see [`Sheet::sniff`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{LineTerminator, Sheet};
+    ///
+    /// let dialect = Sheet::sniff_str("id;title\r\n1;\"old; new\"\r\n2;her\r\n");
+    ///
+    /// assert_eq!(dialect.delimiter, ';');
+    /// assert_eq!(dialect.quote, Some('"'));
+    /// assert!(dialect.has_header);
+    /// assert_eq!(dialect.line_terminator, LineTerminator::CrLf);
+    /// ```
+    pub fn sniff_str(data: &str) -> Dialect {
+        let data = strip_bom(data);
+        let line_terminator = detect_line_terminator(data);
+        let lines: Vec<&str> = match line_terminator {
+            LineTerminator::Cr => data.split('\r').collect(),
+            _ => data.lines().collect(),
+        };
+        let lines: Vec<&str> = lines.into_iter().filter(|l| !l.trim().is_empty()).collect();
+        if lines.is_empty() {
+            return Dialect::default();
+        }
+
+        let delimiter = detect_delimiter(&lines);
+        let rows: Vec<Vec<&str>> = lines
+            .iter()
+            .map(|line| split_quoted(line, delimiter, '"'))
+            .collect();
+        let quote = QUOTES
+            .iter()
+            .map(|q| {
+                let quoted = rows
+                    .iter()
+                    .flatten()
+                    .filter(|field| {
+                        let field = field.trim();
+                        field.len() >= 2 && field.starts_with(*q) && field.ends_with(*q)
+                    })
+                    .count();
+                (*q, quoted)
+            })
+            .filter(|(_, quoted)| *quoted > 0)
+            .max_by_key(|(_, quoted)| *quoted)
+            .map(|(q, _)| q);
+
+        Dialect {
+            delimiter,
+            quote,
+            has_header: detect_header(&rows, quote),
+            line_terminator,
+        }
+    }
+}
+
+/// Picks the most frequent line terminator.
+fn detect_line_terminator(data: &str) -> LineTerminator {
+    let crlf = data.matches("\r\n").count();
+    let lf = data.matches('\n').count() - crlf;
+    let cr = data.matches('\r').count() - crlf;
+
+    if crlf >= lf && crlf >= cr && crlf > 0 {
+        LineTerminator::CrLf
+    } else if cr > lf {
+        LineTerminator::Cr
+    } else {
+        LineTerminator::Lf
+    }
+}
+
+/// Picks the delimiter splitting the lines into the most consistent number of fields.
+fn detect_delimiter(lines: &[&str]) -> char {
+    let mut best = (',', 0.0, 0);
+    for delimiter in DELIMITERS {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for line in lines {
+            *counts
+                .entry(split_quoted(line, delimiter, '"').len())
+                .or_insert(0) += 1;
+        }
+        // the most frequent number of fields, preferring more fields on ties
+        let Some((fields, frequency)) = counts
+            .into_iter()
+            .max_by_key(|(fields, frequency)| (*frequency, *fields))
+        else {
+            continue;
+        };
+        if fields < 2 {
+            continue;
+        }
+
+        let consistency = frequency as f64 / lines.len() as f64;
+        if consistency > best.1 || (consistency == best.1 && fields > best.2) {
+            best = (delimiter, consistency, fields);
+        }
+    }
+
+    best.0
+}
+
+/// Tells whether the first row looks like a header rather than like the rows following it.
+fn detect_header(rows: &[Vec<&str>], quote: Option<char>) -> bool {
+    let Some((first, body)) = rows.split_first() else {
+        return true;
+    };
+    if body.is_empty() {
+        return true;
+    }
+
+    let unquote = |field: &str| -> Cell {
+        let field = field.trim();
+        match quote.and_then(|q| field.strip_prefix(q)?.strip_suffix(q)) {
+            Some(inner) => Cell::String(inner.to_string()),
+            None => parse_token(field),
+        }
+    };
+    let is_value = |cell: &Cell| !matches!(cell, Cell::String(_) | Cell::Null);
+
+    let mut votes = 0;
+    for (j, name) in first.iter().enumerate() {
+        let name = unquote(name);
+        let cells: Vec<Cell> = body
+            .iter()
+            .filter_map(|row| row.get(j))
+            .map(|field| unquote(field))
+            .filter(|cell| *cell != Cell::Null)
+            .collect();
+        if cells.is_empty() {
+            continue;
+        }
+
+        if cells.iter().all(is_value) {
+            // a numeric or bool column: a header names it with text
+            votes += if is_value(&name) { -1 } else { 1 };
+        } else if cells.iter().all(|c| !is_value(c)) {
+            // a text column of fixed length values, such as codes, is named with a different
+            // length
+            let length = cells[0].to_string().chars().count();
+            if cells
+                .iter()
+                .all(|c| c.to_string().chars().count() == length)
+            {
+                votes += if name.to_string().chars().count() == length {
+                    -1
+                } else {
+                    1
+                };
+            }
+        }
+    }
+
+    votes >= 0
+}
+
+/// Splits a line on a delimiter, ignoring the delimiters found between quotes.
+fn split_quoted(line: &str, delimiter: char, quote: char) -> Vec<&str> {
+    let mut fields = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        if c == quote {
+            quoted = !quoted;
+        } else if c == delimiter && !quoted {
+            fields.push(&line[start..i]);
+            start = i + c.len_utf8();
+        }
+    }
+    fields.push(&line[start..]);
+
+    fields
+}
@@ -0,0 +1,47 @@
+use calamine::{Data, Range};
+
+use crate::{parse_token, Row, Sheet};
+
+/// References a worksheet of a workbook either by its name, or by its zero-based position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorksheetRef<'a> {
+    /// the zero-based position of the worksheet
+    Index(usize),
+    /// the name of the worksheet
+    Name(&'a str),
+}
+
+impl<'a> From<&'a str> for WorksheetRef<'a> {
+    fn from(name: &'a str) -> Self {
+        WorksheetRef::Name(name)
+    }
+}
+
+impl From<usize> for WorksheetRef<'_> {
+    fn from(index: usize) -> Self {
+        WorksheetRef::Index(index)
+    }
+}
+
+impl Sheet {
+    /// from_range builds a Sheet out of a spreadsheet range, inferring the type of every cell
+    #[allow(deprecated)]
+    pub(crate) fn from_range(range: &Range<Data>) -> Self {
+        let mut sheet = Self::new_sheet();
+        for cells in range.rows() {
+            let row: Row = cells
+                .iter()
+                .map(|c| parse_token(c.to_string().trim()))
+                .collect();
+            sheet.data.push(row);
+        }
+
+        if sheet.data.is_empty() {
+            sheet.data.push(Row(Vec::new()));
+        }
+        // if some column values are absent from a row, then fill it with a default Cell::Null
+        sheet.normalize_cols();
+
+        sheet
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+    error::Error,
+    fmt::Write as _,
+    fs::OpenOptions,
+    io::{BufWriter, Write},
+};
+
+use crate::{Cell, ColumnRef, Row, Sheet};
+
+/// number of rows grouped into a single `INSERT` statement
+const INSERT_BATCH_SIZE: usize = 500;
+
+/// The SQL flavour targeted by [`Sheet::export_sql_inserts`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SqlDialect {
+    /// SQLite, with `"quoted"` identifiers and bools written as `1`/`0`
+    Sqlite,
+    /// PostgreSQL, with `"quoted"` identifiers
+    Postgres,
+    /// MySQL and MariaDB, with `` `quoted` `` identifiers and backslash escapes in strings
+    MySql,
+}
+
+/// The broadest kind of value held by a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ColumnKind {
+    Int,
+    UInt,
+    /// negative ints mixed with unsigned ints beyond the range of `i64`
+    Decimal,
+    Float,
+    Bool,
+    Text,
+}
+
+impl SqlDialect {
+    /// column_type picks the SQL type able to hold every cell of a column
+    pub(crate) fn column_type<'a>(self, cells: impl Iterator<Item = &'a Cell>) -> &'static str {
+        match (self, column_kind(cells)) {
+            (SqlDialect::Sqlite, ColumnKind::Int) => "INTEGER",
+            (SqlDialect::Sqlite, ColumnKind::Float) => "REAL",
+            (SqlDialect::Postgres | SqlDialect::MySql, ColumnKind::Int) => "BIGINT",
+            (SqlDialect::Sqlite, ColumnKind::UInt) => "TEXT",
+            (SqlDialect::Postgres, ColumnKind::UInt) => "NUMERIC(20)",
+            (SqlDialect::MySql, ColumnKind::UInt) => "BIGINT UNSIGNED",
+            (SqlDialect::Sqlite, ColumnKind::Decimal) => "TEXT",
+            (SqlDialect::Postgres, ColumnKind::Decimal) => "NUMERIC(20)",
+            (SqlDialect::MySql, ColumnKind::Decimal) => "DECIMAL(20,0)",
+            (SqlDialect::Postgres, ColumnKind::Float) => "DOUBLE PRECISION",
+            (SqlDialect::MySql, ColumnKind::Float) => "DOUBLE",
+            (_, ColumnKind::Bool) => "BOOLEAN",
+            (_, ColumnKind::Text) => "TEXT",
+        }
+    }
+
+    /// quote_identifier quotes a table or column name, so that it can hold spaces and keywords
+    pub(crate) fn quote_identifier(self, name: &str) -> String {
+        match self {
+            SqlDialect::MySql => format!("`{}`", name.replace('`', "``")),
+            _ => format!("\"{}\"", name.replace('"', "\"\"")),
+        }
+    }
+
+    /// write_value writes a cell as a SQL literal
+    fn write_value(self, out: &mut String, cell: &Cell) {
+        match cell {
+            Cell::Null => out.push_str("NULL"),
+            Cell::String(s) => {
+                let mut escaped = s.replace('\'', "''");
+                if self == SqlDialect::MySql {
+                    escaped = escaped.replace('\\', "\\\\");
+                }
+                write!(out, "'{escaped}'").unwrap();
+            }
+            Cell::Bool(b) if self == SqlDialect::Sqlite => write!(out, "{}", *b as u8).unwrap(),
+            Cell::Bool(b) => out.push_str(if *b { "TRUE" } else { "FALSE" }),
+            Cell::Int(i) => write!(out, "{i}").unwrap(),
+            // SQLite reads integers above i64::MAX as lossy reals, so they're kept as text there
+            Cell::UInt(u) if self == SqlDialect::Sqlite => write!(out, "'{u}'").unwrap(),
+            Cell::UInt(u) => write!(out, "{u}").unwrap(),
+            Cell::Float(f) if f.is_finite() => write!(out, "{f:?}").unwrap(),
+            Cell::Float(_) => out.push_str("NULL"),
+        }
+    }
+}
+
+impl Sheet {
+    /// Exports the content of a Sheet as a SQL dump of `CREATE TABLE` and `INSERT` statements.
+    ///
+    /// The table gets one column per header cell, typed from its cells (integer, floating point,
+    /// boolean or text, spelled the way `dialect` expects). Rows are written in batched `INSERT`
+    /// statements of up to 500 rows, with identifiers quoted and strings escaped for the
+    /// dialect. NaN and infinite floats have no portable SQL literal and are written as `NULL`.
+    /// If the file already exists, it truncates the file and overwrites its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the SQL file.
+    /// * `table_name` - The name of the table to create and insert into.
+    /// * `dialect` - The database the dump targets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".sql" extension, if the sheet has no header
+    /// row, or if the file can't be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{Sheet, SqlDialect};
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet
+    ///     .export_sql_inserts("movies.sql", "movies", SqlDialect::Postgres)
+    ///     .unwrap();
+    /// ```
+    pub fn export_sql_inserts(
+        &self,
+        file_path: &str,
+        table_name: &str,
+        dialect: SqlDialect,
+    ) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("sql") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+        let mut buf_writer = BufWriter::new(file);
+        buf_writer.write_all(self.to_sql_inserts(table_name, dialect)?.as_bytes())?;
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+
+    /// to_sql_inserts renders the SQL dump written by [`Sheet::export_sql_inserts`]
+    #[allow(deprecated)]
+    pub(crate) fn to_sql_inserts(
+        &self,
+        table_name: &str,
+        dialect: SqlDialect,
+    ) -> Result<String, Box<dyn Error>> {
+        let header = self
+            .data
+            .first()
+            .ok_or("cannot create a table from a sheet without a header row")?;
+        let table = dialect.quote_identifier(table_name);
+        let names: Vec<String> = header
+            .iter()
+            .map(|name| dialect.quote_identifier(&name.to_string()))
+            .collect();
+
+        let mut out = String::new();
+        writeln!(out, "CREATE TABLE {table} (").unwrap();
+        for (j, name) in names.iter().enumerate() {
+            let sql_type = dialect.column_type(self.data.iter().skip(1).map(|row| &row[j]));
+            let separator = if j + 1 < names.len() { "," } else { "" };
+            writeln!(out, "    {name} {sql_type}{separator}").unwrap();
+        }
+        out.push_str(");\n");
+
+        let rows = &self.data[1..];
+        for batch in rows.chunks(INSERT_BATCH_SIZE) {
+            writeln!(out, "INSERT INTO {table} ({}) VALUES", names.join(", ")).unwrap();
+            write_rows(&mut out, batch, dialect);
+            out.push_str(";\n");
+        }
+
+        Ok(out)
+    }
+
+    /// Exports the content of a Sheet as a SQL script of batched upserts, keyed on a column.
+    ///
+    /// Rows are written in `INSERT` statements of up to 500 rows, in which a row whose key
+    /// already exists in the table updates the existing row instead of failing: through
+    /// `ON CONFLICT (key) DO UPDATE` for SQLite and PostgreSQL, and `ON DUPLICATE KEY UPDATE`
+    /// for MySQL. Running the script again after the sheet changed brings the table up to date,
+    /// so cleaned sheets can maintain a live table rather than only seed it once. The table has
+    /// to exist with a primary key or unique constraint on the key column. If the file already
+    /// exists, it truncates the file and overwrites its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the SQL file.
+    /// * `table_name` - The name of the table to upsert into.
+    /// * `key` - The column identifying rows.
+    /// * `dialect` - The database the script targets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".sql" extension, if the key column doesn't
+    /// exist or holds null or duplicated values, or if the file can't be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{Sheet, SqlDialect};
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet
+    ///     .export_sql_upserts("movies.sql", "movies", "id", SqlDialect::Postgres)
+    ///     .unwrap();
+    /// ```
+    pub fn export_sql_upserts<'a>(
+        &self,
+        file_path: &str,
+        table_name: &str,
+        key: impl Into<ColumnRef<'a>>,
+        dialect: SqlDialect,
+    ) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("sql") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let key_index = self.upsert_key(key)?;
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+        let mut buf_writer = BufWriter::new(file);
+        for statement in self.sql_upserts(table_name, key_index, dialect) {
+            buf_writer.write_all(statement.as_bytes())?;
+            buf_writer.write_all(b"\n")?;
+        }
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+
+    /// upsert_key resolves the key column of an upsert, checking that it identifies every row
+    #[allow(deprecated)]
+    pub(crate) fn upsert_key<'a>(
+        &self,
+        key: impl Into<ColumnRef<'a>>,
+    ) -> Result<usize, Box<dyn Error>> {
+        let key_index = self.col_index(key)?;
+        let name = &self.data[0][key_index];
+        if self.data[1..]
+            .iter()
+            .any(|row| row[key_index] == Cell::Null)
+        {
+            return Err(Box::from(format!("key column '{name}' holds null values")));
+        }
+        if self.duplicated(&[key_index])?.into_iter().any(|d| d) {
+            return Err(Box::from(format!(
+                "key column '{name}' holds duplicated values"
+            )));
+        }
+
+        Ok(key_index)
+    }
+
+    /// sql_upserts renders one batched upsert statement per 500 rows, keyed on the column at
+    /// `key_index`
+    #[allow(deprecated)]
+    pub(crate) fn sql_upserts(
+        &self,
+        table_name: &str,
+        key_index: usize,
+        dialect: SqlDialect,
+    ) -> Vec<String> {
+        let table = dialect.quote_identifier(table_name);
+        let names: Vec<String> = self.data[0]
+            .iter()
+            .map(|name| dialect.quote_identifier(&name.to_string()))
+            .collect();
+        let key = &names[key_index];
+        let updates: Vec<String> = names
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != key_index)
+            .map(|(_, name)| match dialect {
+                SqlDialect::MySql => format!("{name} = VALUES({name})"),
+                _ => format!("{name} = excluded.{name}"),
+            })
+            .collect();
+        let conflict = match (dialect, updates.is_empty()) {
+            // MySQL has no DO NOTHING, assigning the key to itself leaves the row as it is
+            (SqlDialect::MySql, true) => format!("ON DUPLICATE KEY UPDATE {key} = {key}"),
+            (SqlDialect::MySql, false) => format!("ON DUPLICATE KEY UPDATE {}", updates.join(", ")),
+            (_, true) => format!("ON CONFLICT ({key}) DO NOTHING"),
+            (_, false) => format!("ON CONFLICT ({key}) DO UPDATE SET {}", updates.join(", ")),
+        };
+
+        self.data[1..]
+            .chunks(INSERT_BATCH_SIZE)
+            .map(|batch| {
+                let mut out = format!("INSERT INTO {table} ({}) VALUES\n", names.join(", "));
+                write_rows(&mut out, batch, dialect);
+                write!(out, "\n{conflict};").unwrap();
+                out
+            })
+            .collect()
+    }
+}
+
+/// Writes rows as the comma separated tuples of a `VALUES` clause.
+fn write_rows(out: &mut String, rows: &[Row], dialect: SqlDialect) {
+    for (i, row) in rows.iter().enumerate() {
+        out.push('(');
+        for (j, cell) in row.iter().enumerate() {
+            if j > 0 {
+                out.push_str(", ");
+            }
+            dialect.write_value(out, cell);
+        }
+        out.push(')');
+        if i + 1 < rows.len() {
+            out.push_str(",\n");
+        }
+    }
+}
+
+/// Finds the broadest kind of value held by a column, ignoring nulls.
+fn column_kind<'a>(cells: impl Iterator<Item = &'a Cell>) -> ColumnKind {
+    let mut kind: Option<ColumnKind> = None;
+    // whether the column holds a negative int, which doesn't fit an unsigned type
+    let mut negative = false;
+
+    for cell in cells {
+        let cell_kind = match cell {
+            Cell::Null => continue,
+            Cell::Int(i) => {
+                negative |= *i < 0;
+                ColumnKind::Int
+            }
+            Cell::UInt(_) => ColumnKind::UInt,
+            Cell::Float(_) => ColumnKind::Float,
+            Cell::Bool(_) => ColumnKind::Bool,
+            Cell::String(_) => return ColumnKind::Text,
+        };
+
+        kind = match (kind, cell_kind) {
+            (None, k) => Some(k),
+            (Some(a), b) if a == b => Some(a),
+            (
+                Some(ColumnKind::Int | ColumnKind::UInt | ColumnKind::Decimal),
+                ColumnKind::Int | ColumnKind::UInt,
+            ) if negative => Some(ColumnKind::Decimal),
+            (Some(ColumnKind::Int | ColumnKind::UInt), ColumnKind::Int | ColumnKind::UInt) => {
+                Some(ColumnKind::UInt)
+            }
+            (
+                Some(ColumnKind::Int | ColumnKind::UInt | ColumnKind::Decimal | ColumnKind::Float),
+                ColumnKind::Int | ColumnKind::UInt | ColumnKind::Float,
+            ) => Some(ColumnKind::Float),
+            _ => return ColumnKind::Text,
+        };
+    }
+
+    kind.unwrap_or(ColumnKind::Text)
+}
@@ -0,0 +1,228 @@
+use std::error::Error;
+
+use rusqlite::{params_from_iter, types::Value, types::ValueRef, Connection};
+
+use crate::{Cell, ColumnRef, Row, Sheet, SqlDialect};
+
+impl Sheet {
+    /// Loads the result of a SQL query against a SQLite database into a Sheet.
+    ///
+    /// The column names of the query make up the header row. SQLite integers become
+    /// `Cell::Int`, reals `Cell::Float`, text `Cell::String` and nulls `Cell::Null`. Integers
+    /// read from a column declared as `BOOLEAN` become `Cell::Bool`, so tables written by
+    /// [`Sheet::export_sqlite`] round-trip.
+    ///
+    /// This method is only available with the `sqlite` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the SQLite database.
+    /// * `query` - The SQL query selecting the data to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database can't be opened, the query fails, or it returns a blob.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_sqlite("movies.db", "SELECT * FROM movies WHERE review > 4").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    #[allow(deprecated)]
+    pub fn load_sqlite(file_path: &str, query: &str) -> Result<Self, Box<dyn Error>> {
+        let conn = Connection::open(file_path)?;
+        let mut stmt = conn.prepare(query)?;
+        let booleans: Vec<bool> = stmt
+            .columns()
+            .iter()
+            .map(|c| {
+                c.decl_type()
+                    .is_some_and(|t| t.eq_ignore_ascii_case("BOOLEAN"))
+            })
+            .collect();
+
+        let mut sheet = Self::new_sheet();
+        sheet.data.push(
+            stmt.column_names()
+                .into_iter()
+                .map(|name| Cell::String(name.to_string()))
+                .collect(),
+        );
+
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let mut cells = Row(Vec::with_capacity(booleans.len()));
+            for (i, boolean) in booleans.iter().enumerate() {
+                let cell = match row.get_ref(i)? {
+                    ValueRef::Null => Cell::Null,
+                    ValueRef::Integer(v) if *boolean => Cell::Bool(v != 0),
+                    ValueRef::Integer(v) => Cell::Int(v),
+                    ValueRef::Real(v) => Cell::Float(v),
+                    ValueRef::Text(v) => Cell::String(String::from_utf8(v.to_vec())?),
+                    ValueRef::Blob(_) => {
+                        return Err(Box::from(format!(
+                            "unsupported blob value in column '{}'",
+                            sheet.data[0][i]
+                        )))
+                    }
+                };
+                cells.push(cell);
+            }
+            sheet.data.push(cells);
+        }
+
+        Ok(sheet)
+    }
+
+    /// Exports the content of a Sheet to a new table of a SQLite database.
+    ///
+    /// The table is created with one column per header cell, typed from its cells: `INTEGER` for
+    /// int columns, `REAL` for columns mixing ints and floats, `BOOLEAN` for bool columns, and
+    /// `TEXT` for everything else. The rows are then inserted in a single transaction, so either
+    /// all of them are written or none are. The database file is created if it doesn't exist.
+    ///
+    /// This method is only available with the `sqlite` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the SQLite database.
+    /// * `table_name` - The name of the table to create.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sheet has no header row, if the database can't be opened, or if
+    /// the table already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.export_sqlite("movies.db", "movies").unwrap();
+    /// ```
+    #[allow(deprecated)]
+    pub fn export_sqlite(&self, file_path: &str, table_name: &str) -> Result<(), Box<dyn Error>> {
+        let header = self
+            .data
+            .first()
+            .ok_or("cannot create a table from a sheet without a header row")?;
+        let columns = (0..header.len())
+            .map(|j| {
+                let sql_type =
+                    SqlDialect::Sqlite.column_type(self.data.iter().skip(1).map(|row| &row[j]));
+                format!(
+                    "{} {sql_type}",
+                    SqlDialect::Sqlite.quote_identifier(&header[j].to_string())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let placeholders = vec!["?"; header.len()].join(", ");
+
+        let mut conn = Connection::open(file_path)?;
+        let tx = conn.transaction()?;
+        tx.execute(
+            &format!(
+                "CREATE TABLE {} ({columns})",
+                SqlDialect::Sqlite.quote_identifier(table_name)
+            ),
+            [],
+        )?;
+        {
+            let mut stmt = tx.prepare(&format!(
+                "INSERT INTO {} VALUES ({placeholders})",
+                SqlDialect::Sqlite.quote_identifier(table_name)
+            ))?;
+            for row in self.data.iter().skip(1) {
+                stmt.execute(params_from_iter(row.iter().map(|cell| match cell {
+                    Cell::Null => Value::Null,
+                    Cell::String(s) => Value::Text(s.clone()),
+                    Cell::Bool(b) => Value::Integer(*b as i64),
+                    Cell::Int(i) => Value::Integer(*i),
+                    Cell::UInt(u) => Value::Text(u.to_string()),
+                    Cell::Float(f) => Value::Real(*f),
+                })))?;
+            }
+        }
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Upserts the rows of a Sheet into a table of a SQLite database, keyed on a column.
+    ///
+    /// Rows whose key is already in the table update the existing row, and the other rows are
+    /// inserted, so cleaned sheets can maintain a live table. Rows are written in batched
+    /// `INSERT ... ON CONFLICT` statements of up to 500 rows, within a single transaction. If the
+    /// table doesn't exist, it's created as by [`Sheet::export_sqlite`], with the key column as
+    /// its primary key; an existing table needs a primary key or unique constraint on it.
+    ///
+    /// This method is only available with the `sqlite` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the SQLite database.
+    /// * `table_name` - The name of the table to upsert into.
+    /// * `key` - The column identifying rows.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows inserted or updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key column doesn't exist or holds null or duplicated values, if
+    /// the database can't be opened, or if a statement fails, in which case nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.upsert_sqlite("movies.db", "movies", "id").unwrap();
+    /// ```
+    #[allow(deprecated)]
+    pub fn upsert_sqlite<'a>(
+        &self,
+        file_path: &str,
+        table_name: &str,
+        key: impl Into<ColumnRef<'a>>,
+    ) -> Result<usize, Box<dyn Error>> {
+        let key_index = self.upsert_key(key)?;
+        let header = &self.data[0];
+        let columns = (0..header.len())
+            .map(|j| {
+                let sql_type =
+                    SqlDialect::Sqlite.column_type(self.data.iter().skip(1).map(|row| &row[j]));
+                format!(
+                    "{} {sql_type}",
+                    SqlDialect::Sqlite.quote_identifier(&header[j].to_string())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut conn = Connection::open(file_path)?;
+        let tx = conn.transaction()?;
+        tx.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} ({columns}, PRIMARY KEY ({}))",
+                SqlDialect::Sqlite.quote_identifier(table_name),
+                SqlDialect::Sqlite.quote_identifier(&header[key_index].to_string())
+            ),
+            [],
+        )?;
+        let mut written = 0;
+        for statement in self.sql_upserts(table_name, key_index, SqlDialect::Sqlite) {
+            written += tx.execute(&statement, [])?;
+        }
+        tx.commit()?;
+
+        Ok(written)
+    }
+}
@@ -0,0 +1,154 @@
+use std::{collections::HashMap, error::Error};
+
+use crate::{Cell, ColumnRef, Sheet};
+
+/// The canonical values [`Sheet::standardize_values`] maps raw values to.
+///
+/// Both a `&HashMap<String, String>` and a `&Sheet` convert into a `ValueMapping`.
+#[derive(Debug, Clone, Copy)]
+pub enum ValueMapping<'a> {
+    /// raw values mapped to their canonical value
+    Map(&'a HashMap<String, String>),
+    /// a reference sheet whose first column holds the canonical values, and whose other
+    /// columns hold variants of the value of their row
+    Reference(&'a Sheet),
+}
+
+impl<'a> From<&'a HashMap<String, String>> for ValueMapping<'a> {
+    fn from(map: &'a HashMap<String, String>) -> Self {
+        ValueMapping::Map(map)
+    }
+}
+
+impl<'a> From<&'a Sheet> for ValueMapping<'a> {
+    fn from(sheet: &'a Sheet) -> Self {
+        ValueMapping::Reference(sheet)
+    }
+}
+
+/// What [`Sheet::standardize_values`] changed in a column.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StandardizeReport {
+    /// the number of cells replaced by a different canonical value
+    pub mapped: usize,
+    /// the distinct values absent from the mapping, with their number of occurrences, in
+    /// order of first appearance
+    pub unmapped: Vec<(String, usize)>,
+}
+
+impl StandardizeReport {
+    /// is_complete tells whether every value of the column was found in the mapping
+    pub fn is_complete(&self) -> bool {
+        self.unmapped.is_empty()
+    }
+}
+
+/// normalize returns the form of a value used for lookups
+fn normalize(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+impl ValueMapping<'_> {
+    /// lookup builds the table of canonical values, keyed by normalized raw value
+    fn lookup(self) -> Result<HashMap<String, String>, Box<dyn Error>> {
+        let mut lookup = HashMap::new();
+        match self {
+            ValueMapping::Map(map) => {
+                for (raw, canonical) in map {
+                    lookup.insert(normalize(canonical), canonical.clone());
+                    lookup.insert(normalize(raw), canonical.clone());
+                }
+            }
+            ValueMapping::Reference(sheet) => {
+                if sheet.col_count() == 0 {
+                    return Err(Box::from("the reference sheet has no column"));
+                }
+                for row in sheet.rows() {
+                    if row[0] == Cell::Null {
+                        continue;
+                    }
+                    let canonical = row[0].to_string();
+                    for variant in row.iter().filter(|c| **c != Cell::Null) {
+                        lookup.insert(normalize(&variant.to_string()), canonical.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(lookup)
+    }
+}
+
+impl Sheet {
+    /// standardize_values replaces the raw values of a column with their canonical form, e.g.
+    /// `USA`, `U.S.` and `United States` with `US`
+    ///
+    /// Values are looked up ignoring surrounding whitespace and letter case, and canonical
+    /// values map to themselves. Null cells are skipped, and values absent from the mapping are
+    /// left untouched and listed in the report, so the mapping can be completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the column to standardize
+    /// * `mapping` - a `&HashMap` from raw to canonical values, or a reference `&Sheet`, see
+    ///   [`ValueMapping`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, or if the reference sheet has no column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet =
+    ///     Sheet::load_data_from_str("id,country\n1,USA\n2,u.s.\n3,France\n4,Mars\n5,United States");
+    /// let countries = Sheet::load_data_from_str(
+    ///     "code,name,alias\nUS,United States,U.S.\nUS,United States,USA\nFR,France,",
+    /// );
+    /// let report = sheet.standardize_values("country", &countries).unwrap();
+    ///
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::String("US".to_string()));
+    /// assert_eq!(sheet.row(2).unwrap()[1], Cell::String("FR".to_string()));
+    /// assert_eq!(report.mapped, 4);
+    /// assert_eq!(report.unmapped, vec![("Mars".to_string(), 1)]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn standardize_values<'a, 'm>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        mapping: impl Into<ValueMapping<'m>>,
+    ) -> Result<StandardizeReport, Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        let lookup = mapping.into().lookup()?;
+
+        let mut report = StandardizeReport::default();
+        // the position of every unmapped value in the report
+        let mut unmapped: HashMap<String, usize> = HashMap::new();
+        for row in self.data.iter_mut().skip(1) {
+            let cell = &mut row[col_index];
+            if *cell == Cell::Null {
+                continue;
+            }
+
+            let value = cell.to_string();
+            match lookup.get(&normalize(&value)) {
+                Some(canonical) if *canonical != value => {
+                    *cell = Cell::String(canonical.clone());
+                    report.mapped += 1;
+                }
+                Some(_) => {}
+                None => match unmapped.get(&value) {
+                    Some(&i) => report.unmapped[i].1 += 1,
+                    None => {
+                        unmapped.insert(value.clone(), report.unmapped.len());
+                        report.unmapped.push((value, 1));
+                    }
+                },
+            }
+        }
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use crate::{generated_names, split_records, LoadOptions, Sheet, TextEncoding};
+
+/// Reads a delimited file chunk by chunk, yielding Sheets of at most a given number of rows,
+/// created by [`Sheet::stream_csv`].
+///
+/// Every yielded Sheet holds the header row of the file followed by the rows of its chunk, so
+/// only one chunk is held in memory at a time, whatever the size of the file. Types are inferred
+/// separately for every chunk, while the lines reported by errors and by [`ParseMode::Lenient`]
+/// warnings count from the start of the file. Files without a header, see
+/// [`LoadOptions::header`], get column names generated out of the width of their first row. The
+/// iterator ends once the whole file has been read, or after yielding an error.
+///
+/// [`ParseMode::Lenient`]: crate::ParseMode::Lenient
+#[derive(Debug)]
+pub struct CsvChunks {
+    reader: BufReader<File>,
+    options: LoadOptions,
+    chunk_rows: usize,
+    /// the header line, including its line break
+    header: String,
+    /// the first row of a headerless file, read along with the header
+    first_row: String,
+    /// the number of lines read so far
+    lines_read: usize,
+    done: bool,
+}
+
+impl Sheet {
+    /// Streams a CSV file in chunks of `chunk_rows` rows, to filter or aggregate files that
+    /// don't fit in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the CSV file.
+    /// * `chunk_rows` - The maximum number of rows of every chunk, header excluded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".csv" extension, can't be opened, or if
+    /// `chunk_rows` is 0. Errors reading or parsing a chunk are yielded by the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut errors = 0;
+    /// for chunk in Sheet::stream_csv("access_log.csv", 100_000).unwrap() {
+    ///     let chunk = chunk.unwrap();
+    ///     errors += chunk.filter("status", |c| *c == Cell::Int(500)).len();
+    /// }
+    /// println!("{errors} failed requests");
+    /// ```
+    pub fn stream_csv(file_path: &str, chunk_rows: usize) -> Result<CsvChunks, Box<dyn Error>> {
+        Self::stream_csv_with(file_path, chunk_rows, &LoadOptions::default())
+    }
+
+    /// Streams a delimited file in chunks of `chunk_rows` rows, parsed according to the given
+    /// options.
+    ///
+    /// See [`Sheet::stream_csv`] and [`Sheet::load_data_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".csv", ".tsv" or ".txt" extension, can't be
+    /// opened, if `chunk_rows` is 0, if the options select an encoding other than UTF-8, or
+    /// if they skip footer lines, since the end of the file isn't known while streaming.
+    pub fn stream_csv_with(
+        file_path: &str,
+        chunk_rows: usize,
+        options: &LoadOptions,
+    ) -> Result<CsvChunks, Box<dyn Error>> {
+        // check for ext
+        if !file_path
+            .rsplit('.')
+            .next()
+            .is_some_and(|ext| ["csv", "tsv", "txt"].contains(&ext))
+        {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+        if chunk_rows == 0 {
+            return Err(Box::from("chunk size should be greater than 0"));
+        }
+        if options.encoding != TextEncoding::Utf8 {
+            return Err(Box::from("only UTF-8 files can be streamed"));
+        }
+        if options.skip_footer > 0 {
+            return Err(Box::from("footer lines can't be skipped while streaming"));
+        }
+
+        Ok(CsvChunks {
+            reader: BufReader::new(File::open(file_path)?),
+            options: options.clone(),
+            chunk_rows,
+            header: String::new(),
+            first_row: String::new(),
+            lines_read: 0,
+            done: false,
+        })
+    }
+}
+
+impl CsvChunks {
+    /// next_chunk reads the next chunk of rows, returning None once the file was fully read
+    fn next_chunk(&mut self) -> Result<Option<Sheet>, Box<dyn Error>> {
+        // the lines before the header are skipped once, chunks are parsed without them
+        let mut skipped = String::new();
+        for _ in 0..std::mem::take(&mut self.options.skip_rows) {
+            skipped.clear();
+            if self.reader.read_line(&mut skipped)? == 0 {
+                return Ok(None);
+            }
+            self.lines_read += 1;
+        }
+        while self.header.is_empty() || self.is_comment(&self.header) {
+            self.header.clear();
+            if self.reader.read_line(&mut self.header)? == 0 {
+                return Ok(None);
+            }
+            self.lines_read += 1;
+            if let Some(header) = self.header.strip_prefix('\u{feff}') {
+                self.header = header.to_string();
+            }
+        }
+        if !self.header.ends_with('\n') {
+            self.header.push('\n');
+        }
+        if !self.options.header {
+            // the line read is a row, the columns are named after their position
+            self.first_row = std::mem::take(&mut self.header);
+            let width = split_records(&self.first_row, &self.options)
+                .0
+                .first()
+                .map_or(0, |(_, fields)| fields.len());
+            let names: Vec<String> = generated_names(width).collect();
+            self.header = names.join(&self.options.delimiter.to_string()) + "\n";
+            self.options.header = true;
+        }
+
+        // the chunk's text starts with the header, followed by the line after `lines_read`
+        let first_row = std::mem::take(&mut self.first_row);
+        let shift = self
+            .lines_read
+            .saturating_sub(1 + usize::from(!first_row.is_empty()));
+        let mut text = self.header.clone() + &first_row;
+        let mut rows = usize::from(!first_row.is_empty());
+        // a line holding an odd number of quotes opens or closes a cell spanning several lines
+        let mut in_quotes = self.unescaped_quotes(&first_row) % 2 == 1;
+        while rows < self.chunk_rows {
+            let start = text.len();
+            if self.reader.read_line(&mut text)? == 0 {
+                break;
+            }
+            self.lines_read += 1;
+            if self.unescaped_quotes(&text[start..]) % 2 == 1 {
+                in_quotes = !in_quotes;
+            }
+            if !in_quotes && !self.is_comment(&text[start..]) {
+                rows += 1;
+            }
+        }
+        if text.len() == self.header.len() {
+            return Ok(None);
+        }
+
+        Ok(Some(Sheet::load_delimited_at(&text, &self.options, shift)?))
+    }
+
+    /// is_comment tells whether a line starts with the comment character
+    fn is_comment(&self, line: &str) -> bool {
+        self.options
+            .comment_char
+            .is_some_and(|c| line.starts_with(c))
+    }
+
+    /// unescaped_quotes counts the quote characters of a line which aren't escaped
+    fn unescaped_quotes(&self, line: &str) -> usize {
+        let mut count = 0;
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if Some(c) == self.options.escape_char {
+                chars.next();
+            } else if c == self.options.quote_char {
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+impl Iterator for CsvChunks {
+    type Item = Result<Sheet, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let chunk = self.next_chunk().transpose();
+        if !matches!(chunk, Some(Ok(_))) {
+            self.done = true;
+        }
+        chunk
+    }
+}
@@ -0,0 +1,99 @@
+use std::{collections::HashMap, error::Error};
+
+use crate::{Cell, ColumnRef, Sheet};
+
+/// Summary of the values of a text column, as returned by [`Sheet::string_stats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StringStats {
+    /// number of null cells
+    pub nulls: usize,
+    /// number of empty or whitespace-only strings
+    pub empty: usize,
+    /// number of distinct non-null values
+    pub distinct: usize,
+    /// length in characters of the shortest non-null value, None if the column only holds nulls
+    pub min_len: Option<usize>,
+    /// length in characters of the longest non-null value, None if the column only holds nulls
+    pub max_len: Option<usize>,
+    /// the most frequent values with their number of occurrences, most frequent first
+    pub top: Vec<(String, usize)>,
+}
+
+impl Sheet {
+    /// string_stats summarizes a text column, to assess the quality of free-text fields
+    ///
+    /// Every non-null cell is considered through its text representation, so the statistics
+    /// are also available for columns mixing numbers and strings. Ties between equally frequent
+    /// values are broken by order of first appearance.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the column to summarize
+    /// * `top_n` - the number of most frequent values to report
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,city\n1,paris\n2,oran\n3,\n4,paris");
+    /// let stats = sheet.string_stats("city", 1).unwrap();
+    ///
+    /// assert_eq!(stats.nulls, 1);
+    /// assert_eq!(stats.distinct, 2);
+    /// assert_eq!((stats.min_len, stats.max_len), (Some(4), Some(5)));
+    /// assert_eq!(stats.top, vec![("paris".to_string(), 2)]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn string_stats<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+        top_n: usize,
+    ) -> Result<StringStats, Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+
+        let mut stats = StringStats {
+            nulls: 0,
+            empty: 0,
+            distinct: 0,
+            min_len: None,
+            max_len: None,
+            top: Vec::new(),
+        };
+        // occurrences and first position of every value
+        let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+
+        for (i, row) in self.data.iter().skip(1).enumerate() {
+            let value = match &row[col_index] {
+                Cell::Null => {
+                    stats.nulls += 1;
+                    continue;
+                }
+                cell => cell.to_string(),
+            };
+
+            if value.trim().is_empty() {
+                stats.empty += 1;
+            }
+            let len = value.chars().count();
+            stats.min_len = Some(stats.min_len.map_or(len, |m| m.min(len)));
+            stats.max_len = Some(stats.max_len.map_or(len, |m| m.max(len)));
+            counts.entry(value).or_insert((0, i)).0 += 1;
+        }
+
+        stats.distinct = counts.len();
+        let mut top: Vec<(String, (usize, usize))> = counts.into_iter().collect();
+        top.sort_by(|(_, (a, a_first)), (_, (b, b_first))| b.cmp(a).then(a_first.cmp(b_first)));
+        stats.top = top
+            .into_iter()
+            .take(top_n)
+            .map(|(value, (count, _))| (value, count))
+            .collect();
+
+        Ok(stats)
+    }
+}
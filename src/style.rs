@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use crate::{Cell, Sheet};
+
+/// Presentation hints for the cells of a Sheet, honored by [`Sheet::to_html`] and, with the
+/// `xlsx` feature, by the xlsx exporters. Delimited and other plain formats ignore them.
+///
+/// # Examples
+///
+/// ```rust
+/// use datatroll::CellStyle;
+///
+/// let outlier = CellStyle {
+///     highlight: Some("#FFC7CE".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CellStyle {
+    /// the background color of the cell, as a `#RRGGBB` hex code
+    pub highlight: Option<String>,
+    /// an Excel number format code applied to numbers, such as `0.00`, `#,##0` or `0.0%`
+    pub number_format: Option<String>,
+}
+
+impl CellStyle {
+    /// merge overrides the hints of this style with the ones set in `other`
+    fn merge(&mut self, other: &CellStyle) {
+        if other.highlight.is_some() {
+            self.highlight.clone_from(&other.highlight);
+        }
+        if other.number_format.is_some() {
+            self.number_format.clone_from(&other.number_format);
+        }
+    }
+}
+
+/// a shared predicate over the values of a column
+type CellPredicate = Arc<dyn Fn(&Cell) -> bool + Send + Sync>;
+
+/// A style attached to the cells of a column, optionally only those matching a predicate.
+///
+/// Style rules are registered with [`Sheet::style_col`], [`Sheet::style_where`] and
+/// [`Sheet::style_invalid`].
+#[derive(Clone)]
+pub struct StyleRule {
+    column: String,
+    predicate: Option<CellPredicate>,
+    style: CellStyle,
+}
+
+impl StyleRule {
+    /// column returns the name of the column styled by the rule
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// style returns the style applied by the rule
+    pub fn style(&self) -> &CellStyle {
+        &self.style
+    }
+}
+
+impl std::fmt::Debug for StyleRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StyleRule")
+            .field("column", &self.column)
+            .field("style", &self.style)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Sheet {
+    /// style_col attaches a style to every value of a column
+    ///
+    /// Rules apply in the order they were added, a later rule overriding the hints it sets.
+    /// The header row is never styled, and rules naming a column absent from the sheet are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{CellStyle, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("title,share\nold,0.125");
+    /// sheet.style_col(
+    ///     "share",
+    ///     CellStyle {
+    ///         number_format: Some("0.0%".to_string()),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    ///
+    /// assert!(sheet.to_html().contains("<td>12.5%</td>"));
+    /// ```
+    pub fn style_col(&mut self, column: &str, style: CellStyle) {
+        self.styles.push(StyleRule {
+            column: column.to_string(),
+            predicate: None,
+            style,
+        });
+    }
+
+    /// style_where attaches a style to the values of a column for which `predicate` returns
+    /// true, e.g. to flag outliers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, CellStyle, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("title,review\nold,3.5\nher,1.0");
+    /// let low = CellStyle {
+    ///     highlight: Some("#FFC7CE".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// sheet.style_where("review", |c| matches!(c, Cell::Float(r) if *r < 2.0), low);
+    ///
+    /// assert!(sheet
+    ///     .to_html()
+    ///     .contains("<td style=\"background-color: #FFC7CE\">1</td>"));
+    /// ```
+    pub fn style_where<F>(&mut self, column: &str, predicate: F, style: CellStyle)
+    where
+        F: Fn(&Cell) -> bool + Send + Sync + 'static,
+    {
+        self.styles.push(StyleRule {
+            column: column.to_string(),
+            predicate: Some(Arc::new(predicate)),
+            style,
+        });
+    }
+
+    /// style_invalid attaches a style to the values rejected by the sheet's validators, so
+    /// rows loaded without validation can be reviewed in delivered reports
+    ///
+    /// Only the validators registered so far are considered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, CellStyle, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,review\n1,3.5\n2,9.5");
+    /// sheet.add_validator("review", |c| matches!(c, Cell::Float(r) if (0.0..=5.0).contains(r)));
+    /// sheet.style_invalid(CellStyle {
+    ///     highlight: Some("#FFEB9C".to_string()),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(sheet.cell_style(2, 1).unwrap().highlight.as_deref(), Some("#FFEB9C"));
+    /// assert!(sheet.cell_style(1, 1).is_none());
+    /// ```
+    pub fn style_invalid(&mut self, style: CellStyle) {
+        let rules: Vec<StyleRule> = self
+            .validators
+            .iter()
+            .map(|validator| {
+                let rule = Arc::clone(&validator.rule);
+                StyleRule {
+                    column: validator.column().to_string(),
+                    predicate: Some(Arc::new(move |c: &Cell| !rule(c))),
+                    style: style.clone(),
+                }
+            })
+            .collect();
+        self.styles.extend(rules);
+    }
+
+    /// cell_style returns the style of the cell at the given row and column indices, merged
+    /// from every matching rule, or None when no rule applies to it
+    #[allow(deprecated)]
+    pub fn cell_style(&self, row: usize, col: usize) -> Option<CellStyle> {
+        if row == 0 {
+            return None;
+        }
+        let cell = self.data.get(row)?.get(col)?;
+
+        let mut style: Option<CellStyle> = None;
+        for rule in &self.styles {
+            if self.get_col_index(&rule.column) != Some(col) {
+                continue;
+            }
+            if rule.predicate.as_ref().is_some_and(|p| !p(cell)) {
+                continue;
+            }
+            style
+                .get_or_insert_with(CellStyle::default)
+                .merge(&rule.style);
+        }
+
+        style
+    }
+}
+
+/// Formats a number according to the common subset of Excel number format codes: the number of
+/// `0`/`#` digits after the decimal point, `,` thousands grouping and a trailing `%`.
+pub(crate) fn format_number(x: f64, format: &str) -> String {
+    let percent = format.ends_with('%');
+    let x = if percent { x * 100.0 } else { x };
+    let decimals = format.split_once('.').map_or(0, |(_, frac)| {
+        frac.chars().filter(|c| matches!(c, '0' | '#')).count()
+    });
+
+    let mut out = format!("{x:.decimals$}");
+    if format.contains(',') {
+        let (int, frac) = out.split_at(out.find('.').unwrap_or(out.len()));
+        let (sign, digits) = int.split_at(usize::from(int.starts_with('-')));
+        let mut grouped = String::new();
+        for (i, d) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(d);
+        }
+        out = format!("{sign}{grouped}{frac}");
+    }
+    if percent {
+        out.push('%');
+    }
+
+    out
+}
@@ -0,0 +1,195 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    thread,
+    time::Duration,
+};
+
+use crate::{generated_names, split_records, LoadOptions, Sheet};
+
+/// how long [`CsvTail`] waits between two checks of the file by default
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Follows a CSV file that keeps growing, such as a log being appended to, and yields the rows
+/// added since the last read as Sheets, created by [`Sheet::follow`].
+///
+/// Every yielded Sheet holds the header row of the file followed by the new rows. Lines are
+/// only parsed once their line break was written, so a row being appended is never yielded
+/// half-written. When the file shrinks, e.g. because it was rotated or truncated, it's read
+/// again from its start, header included. Files without a header, see [`LoadOptions::header`],
+/// get column names generated out of the width of their first row.
+///
+/// Iterating blocks until new rows show up, checking the file every poll interval, and never
+/// ends on its own; [`CsvTail::poll`] checks the file once without blocking.
+#[derive(Debug)]
+pub struct CsvTail {
+    path: String,
+    options: LoadOptions,
+    poll_interval: Duration,
+    /// position in the file of the first byte not read yet
+    offset: u64,
+    /// the header line, once it has been read
+    header: Option<String>,
+    /// the number of leading lines skipped so far, see [`LoadOptions::skip_rows`]
+    skipped: usize,
+    /// bytes read after the last complete line
+    pending: Vec<u8>,
+}
+
+impl Sheet {
+    /// Follows a growing CSV file, yielding the rows appended to it as they are written.
+    ///
+    /// The rows already in the file are yielded first, see [`CsvTail::seek_end`] to skip them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".csv" extension or can't be opened.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// for batch in Sheet::follow("access_log.csv").unwrap() {
+    ///     let batch = batch.unwrap();
+    ///     println!("{} new rows", batch.row_count());
+    /// }
+    /// ```
+    pub fn follow(file_path: &str) -> Result<CsvTail, Box<dyn Error>> {
+        Self::follow_with(file_path, &LoadOptions::default())
+    }
+
+    /// Follows a growing delimited file, parsing the appended rows according to the given
+    /// options.
+    ///
+    /// See [`Sheet::follow`] and [`Sheet::load_data_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".csv", ".tsv" or ".txt" extension, can't be
+    /// opened, or if the options skip footer lines, since a followed file has no end.
+    pub fn follow_with(file_path: &str, options: &LoadOptions) -> Result<CsvTail, Box<dyn Error>> {
+        // check for ext
+        if !file_path
+            .rsplit('.')
+            .next()
+            .is_some_and(|ext| ["csv", "tsv", "txt"].contains(&ext))
+        {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+        File::open(file_path)?;
+        if options.skip_footer > 0 {
+            return Err(Box::from(
+                "footer lines can't be skipped while following a file",
+            ));
+        }
+
+        Ok(CsvTail {
+            path: file_path.to_string(),
+            options: options.clone(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            offset: 0,
+            header: None,
+            skipped: 0,
+            pending: Vec::new(),
+        })
+    }
+}
+
+impl CsvTail {
+    /// Sets how long iterating waits between two checks of the file, 500ms by default.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Skips the rows already in the file, so that only the rows appended from now on are
+    /// yielded. The header row is still read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read.
+    pub fn seek_end(&mut self) -> Result<(), Box<dyn Error>> {
+        self.poll()?;
+        Ok(())
+    }
+
+    /// Checks the file once, returning the rows appended since the last check, or None if no
+    /// complete row was added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, isn't valid UTF-8, or holds a non-finite
+    /// value under [`crate::NonFinitePolicy::Reject`].
+    pub fn poll(&mut self) -> Result<Option<Sheet>, Box<dyn Error>> {
+        let mut file = File::open(&self.path)?;
+        if file.metadata()?.len() < self.offset {
+            // the file was truncated or replaced, start over
+            self.offset = 0;
+            self.header = None;
+            self.skipped = 0;
+            self.pending.clear();
+        }
+
+        file.seek(SeekFrom::Start(self.offset))?;
+        let read = file.read_to_end(&mut self.pending)?;
+        self.offset += read as u64;
+
+        let Some(end) = self.pending.iter().rposition(|&b| b == b'\n') else {
+            return Ok(None);
+        };
+        let complete: Vec<u8> = self.pending.drain(..=end).collect();
+        let mut lines = std::str::from_utf8(&complete)?;
+
+        while self.skipped < self.options.skip_rows && !lines.is_empty() {
+            lines = lines.split_once('\n').map_or("", |(_, rest)| rest);
+            self.skipped += 1;
+        }
+        if self.skipped < self.options.skip_rows {
+            return Ok(None);
+        }
+
+        if self.header.is_none() && self.options.header {
+            let (header, rest) = lines.split_once('\n').unwrap_or((lines, ""));
+            self.header = Some(header.trim_end_matches('\r').to_string());
+            lines = rest;
+        } else if self.header.is_none() {
+            let first = lines.lines().next().unwrap_or_default();
+            let width = split_records(first, &self.options)
+                .0
+                .first()
+                .map_or(0, |(_, fields)| fields.len());
+            let names: Vec<String> = generated_names(width).collect();
+            self.header = Some(names.join(&self.options.delimiter.to_string()));
+        }
+        if lines.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let header = self.header.as_deref().unwrap_or_default();
+        let options = LoadOptions {
+            header: true,
+            skip_rows: 0,
+            ..self.options.clone()
+        };
+        let sheet = Sheet::load_data_from_str_with(&format!("{header}\n{lines}"), &options)?;
+        Ok(Some(sheet))
+    }
+}
+
+impl Iterator for CsvTail {
+    type Item = Result<Sheet, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.poll() {
+                Ok(Some(sheet)) => return Some(Ok(sheet)),
+                Ok(None) => thread::sleep(self.poll_interval),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
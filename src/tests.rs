@@ -1,4 +1,12 @@
-use super::{Cell, Sheet};
+#![allow(deprecated)]
+
+use super::{
+    Cell, CellStyle, CoercionTarget, ColumnMatching, ColumnRef, Dialect, DomainPolicy,
+    ExportOptions, GlobOptions, LineTerminator, LoadOptions, MaskStrategy, MergeStrategy,
+    NonFinitePolicy, NumericTransform, ParseError, ParseMode, Pipeline, RaggedRows,
+    ScientificNotation, Sheet, SheetGenerator, SqlDialect, StringStats, TableStyle, TextEncoding,
+    TrimPolicy, UnitConversion, Workbook,
+};
 
 const STR_DATA: &str = "id ,title , director, release date, review
 1, old, quintin, 2011, 3.5
@@ -11,7 +19,7 @@ const STR_DATA: &str = "id ,title , director, release date, review
 fn test_data_loading() {
     let sheet = Sheet::load_data("test_data.csv").unwrap();
 
-    let want = vec![
+    let want = [
         vec![
             Cell::String("id".to_string()),
             Cell::String("title".to_string()),
@@ -56,8 +64,8 @@ fn test_data_loading() {
         ],
     ];
 
-    for i in 0..sheet.data.len() {
-        assert_sheet_row(&sheet.data[i], &want[i])
+    for (got, want) in sheet.data.iter().zip(want.iter()) {
+        assert_sheet_row(got, want)
     }
 }
 
@@ -77,7 +85,7 @@ fn test_mean() {
 fn test_median() {
     let sheet = Sheet::load_data_from_str(STR_DATA);
 
-    assert_eq!(*sheet.median("release date"), Cell::Int(2005))
+    assert_eq!(*sheet.median("release date"), Cell::Int(2011))
 }
 
 #[test]
@@ -122,7 +130,7 @@ fn test_insert() {
     let mut sheet = Sheet::load_data_from_str(STR_DATA);
 
     sheet.insert_row("7, hello, quintin, 2007, 2.4").unwrap();
-    let want = vec![
+    let want = [
         Cell::Int(7),
         Cell::String("hello".to_string()),
         Cell::String("quintin".to_string()),
@@ -131,7 +139,7 @@ fn test_insert() {
     ];
     let got = sheet.data.last().unwrap();
 
-    assert_sheet_row(&got, &want)
+    assert_sheet_row(got, &want)
 }
 
 #[test]
@@ -143,7 +151,7 @@ fn test_drop_rows() {
         _ => false,
     });
 
-    let want = vec![
+    let want = [
         vec![
             Cell::String("id".to_string()),
             Cell::String("title".to_string()),
@@ -174,8 +182,8 @@ fn test_drop_rows() {
         ],
     ];
 
-    for i in 0..sheet.data.len() {
-        assert_sheet_row(&sheet.data[i], &want[i]);
+    for (got, want) in sheet.data.iter().zip(want.iter()) {
+        assert_sheet_row(got, want);
     }
 }
 
@@ -183,9 +191,12 @@ fn test_drop_rows() {
 fn test_drop_col() {
     let mut sheet = Sheet::load_data_from_str(STR_DATA);
 
-    sheet.drop_col("review");
+    let dropped = sheet.drop_col("review");
+    assert_eq!(dropped.len(), 6);
+    assert_eq!(dropped[0], Cell::String("review".to_string()));
+    assert_eq!(dropped[5], Cell::Float(5.0));
 
-    let want = vec![
+    let want = [
         vec![
             Cell::String("id".to_string()),
             Cell::String("title".to_string()),
@@ -224,8 +235,8 @@ fn test_drop_col() {
         ],
     ];
 
-    for i in 1..sheet.data.len() {
-        assert_sheet_row(&sheet.data[i], &want[i])
+    for (got, want) in sheet.data.iter().zip(want.iter()).skip(1) {
+        assert_sheet_row(got, want)
     }
 }
 
@@ -255,10 +266,10 @@ fn test_map() {
 
     let _ = sheet.map("title", |c| match c {
         Cell::String(s) => Cell::String(s.to_uppercase()),
-        _ => return c,
+        _ => c,
     });
 
-    let want = vec![
+    let want = [
         Cell::String("TITLE".to_string()),
         Cell::String("OLD".to_string()),
         Cell::String("HER".to_string()),
@@ -267,8 +278,8 @@ fn test_map() {
         Cell::String("WHO".to_string()),
     ];
 
-    for i in 0..sheet.data.len() {
-        assert_eq!(&sheet.data[i][1], &want[i])
+    for (row, want) in sheet.data.iter().zip(want.iter()) {
+        assert_eq!(&row[1], want)
     }
 }
 
@@ -279,7 +290,7 @@ fn test_map_fails_when_col_doesnot_exist() {
     assert!(sheet
         .map("overrated", |c| match c {
             Cell::String(s) => Cell::String(s.to_uppercase()),
-            _ => return c,
+            _ => c,
         })
         .is_err());
 }
@@ -317,7 +328,3277 @@ fn test_edit_cell() {
     assert_eq!(sheet.data[i][3], Cell::Int(2022));
 }
 
-fn assert_sheet_row(got: &Vec<Cell>, want: &Vec<Cell>) {
+#[test]
+fn test_binary_round_trip() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.insert_row("6, , nolan, true, 2.5").unwrap();
+
+    let path = std::env::temp_dir().join("datatroll_test_binary_round_trip.dtrl");
+    let path = path.to_str().unwrap();
+    sheet.save_binary(path).unwrap();
+    let got = Sheet::load_binary(path).unwrap();
+
+    assert_eq!(got.data.len(), sheet.data.len());
+    for (got, want) in got.data.iter().zip(sheet.data.iter()) {
+        assert_sheet_row(got, want)
+    }
+}
+
+#[test]
+fn test_load_binary_rejects_other_files() {
+    assert!(Sheet::load_binary("test_data.csv").is_err());
+
+    // a string claiming more bytes than the file holds
+    let mut bytes = b"DTRL\x01".to_vec();
+    bytes.extend(1_u64.to_le_bytes());
+    bytes.extend(1_u64.to_le_bytes());
+    bytes.push(1);
+    bytes.extend(u64::MAX.to_le_bytes());
+    bytes.extend(b"abc");
+    let path = std::env::temp_dir().join("datatroll_test_truncated.dtrl");
+    std::fs::write(&path, bytes).unwrap();
+    assert!(Sheet::load_binary(path.to_str().unwrap()).is_err());
+}
+
+#[test]
+fn test_split_into() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let shards = sheet.split_into(2).unwrap();
+    assert_eq!(shards.len(), 2);
+    assert_eq!(shards[0].data.len(), 4);
+    assert_eq!(shards[1].data.len(), 3);
+    assert_sheet_row(&shards[1].data[0], &sheet.data[0]);
+    assert_sheet_row(&shards[1].data[1], &sheet.data[4]);
+
+    assert!(sheet.split_into(0).is_err());
+}
+
+#[test]
+fn test_split_by_rows() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let shards = sheet.split_by_rows(2).unwrap();
+    assert_eq!(shards.len(), 3);
+    assert_eq!(shards[2].data.len(), 2);
+    assert_sheet_row(&shards[2].data[1], &sheet.data[5]);
+
+    assert!(sheet.split_by_rows(0).is_err());
+}
+
+#[test]
+fn test_candidate_keys() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let got = sheet.candidate_keys();
+    assert!(got.contains(&vec!["id".to_string()]));
+    assert!(got.contains(&vec!["title".to_string()]));
+    assert!(!got.contains(&vec!["director".to_string()]));
+    assert!(!got.contains(&vec!["director".to_string(), "release date".to_string()]));
+}
+
+#[test]
+fn test_clamp() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+
+    sheet.clamp("review", 2.0, 4.5).unwrap();
+    sheet.clamp("release date", 2000.5, 2015.0).unwrap();
+
+    assert_eq!(sheet.data[3][4], Cell::Float(2.0));
+    assert_eq!(sheet.data[5][4], Cell::Float(4.5));
+    assert_eq!(sheet.data[4][3], Cell::Int(2001));
+    assert_eq!(sheet.data[5][3], Cell::Int(2015));
+
+    assert!(sheet.clamp("title", 0.0, 1.0).is_err());
+    assert!(sheet.clamp("review", 2.0, 1.0).is_err());
+    assert!(sheet.clamp("review", f64::NAN, 1.0).is_err());
+
+    // no integer lies in the range, ints become floats
+    sheet.clamp("id", 1.2, 1.8).unwrap();
+    assert_eq!(sheet.data[1][0], Cell::Float(1.2));
+    assert_eq!(sheet.data[5][0], Cell::Float(1.8));
+
+    let mut sheet = Sheet::load_data_from_str("n\n18446744073709551615\n3");
+    sheet.clamp("n", -5.0, -1.5).unwrap();
+    assert_eq!(sheet.data[1][0], Cell::Int(-2));
+    assert_eq!(sheet.data[2][0], Cell::Int(-2));
+}
+
+#[test]
+fn test_winsorize() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+
+    sheet.winsorize("review", 0.2).unwrap();
+
+    assert_eq!(sheet.data[3][4], Cell::Float(3.5));
+    assert_eq!(sheet.data[5][4], Cell::Float(4.7));
+    assert!(sheet.winsorize("review", 0.5).is_err());
+}
+
+#[test]
+fn test_rounding() {
+    let mut sheet = Sheet::load_data_from_str("id,price\n1,2.345\n2,-2.345\n3,7");
+
+    sheet.round("price", 1).unwrap();
+    assert_eq!(sheet.data[1][1], Cell::Float(2.3));
+    assert_eq!(sheet.data[3][1], Cell::Int(7));
+
+    let mut sheet = Sheet::load_data_from_str("id,price\n1,2.345\n2,-2.345");
+    sheet.floor("price", 2).unwrap();
+    assert_eq!(sheet.data[2][1], Cell::Float(-2.35));
+
+    let mut sheet = Sheet::load_data_from_str("id,price\n1,2.345\n2,-2.345");
+    sheet.ceil("price", 0).unwrap();
+    assert_eq!(sheet.data[1][1], Cell::Float(3.0));
+
+    let mut sheet = Sheet::load_data_from_str("id,price\n1,2.345\n2,-2.345");
+    sheet.truncate("price", 2).unwrap();
+    assert_eq!(sheet.data[2][1], Cell::Float(-2.34));
+
+    assert!(sheet.round("missing", 2).is_err());
+}
+
+#[test]
+fn test_transform() {
+    let mut sheet = Sheet::load_data_from_str("id,v\n1,-4\n2,100\n3,");
+
+    sheet
+        .transform("v", NumericTransform::Abs, DomainPolicy::Error)
+        .unwrap();
+    assert_eq!(sheet.data[1][1], Cell::Int(4));
+
+    sheet
+        .transform("v", NumericTransform::Log10, DomainPolicy::Error)
+        .unwrap();
+    assert_eq!(sheet.data[2][1], Cell::Float(2.0));
+    assert_eq!(sheet.data[3][1], Cell::Null);
+
+    sheet
+        .transform("v", NumericTransform::Pow(2.0), DomainPolicy::Error)
+        .unwrap();
+    assert_eq!(sheet.data[2][1], Cell::Float(4.0));
+}
+
+#[test]
+fn test_transform_domain_policy() {
+    let mut sheet = Sheet::load_data_from_str("id,v\n1,1\n2,-4");
+
+    assert!(sheet
+        .transform("v", NumericTransform::Ln, DomainPolicy::Error)
+        .is_err());
+    assert_eq!(sheet.data[1][1], Cell::Int(1));
+
+    let mut sheet = Sheet::load_data_from_str("id,v\n1,-4\n2,0");
+    sheet
+        .transform("v", NumericTransform::Ln, DomainPolicy::Keep)
+        .unwrap();
+    assert_eq!(sheet.data[1][1], Cell::Int(-4));
+    assert_eq!(sheet.data[2][1], Cell::Int(0));
+
+    sheet
+        .transform("v", NumericTransform::Sqrt, DomainPolicy::Null)
+        .unwrap();
+    assert_eq!(sheet.data[1][1], Cell::Null);
+    assert_eq!(sheet.data[2][1], Cell::Float(0.0));
+}
+
+#[test]
+fn test_convert_units() {
+    let mut sheet = Sheet::load_data_from_str("city,temp\na,32\nb,\nc,50.0");
+
+    sheet
+        .convert("temp", UnitConversion::FahrenheitToCelsius)
+        .unwrap();
+    assert_eq!(sheet.data[1][1], Cell::Float(0.0));
+    assert_eq!(sheet.data[2][1], Cell::Null);
+    assert_eq!(sheet.data[3][1], Cell::Float(10.0));
+
+    sheet.convert_units("temp", 2.0, 1.0).unwrap();
+    assert_eq!(sheet.data[3][1], Cell::Float(21.0));
+
+    assert!(sheet.convert_units("city", 2.0, 0.0).is_err());
+}
+
+#[test]
+fn test_count_where() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let got = sheet
+        .count_where("review", |c| matches!(c, Cell::Float(r) if *r > 4.0))
+        .unwrap();
+    assert_eq!(got, 3);
+    assert!(sheet.count_where("overrated", |_| true).is_err());
+}
+
+#[test]
+fn test_sum_where() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let got = sheet
+        .sum_where("review", "director", |c| {
+            *c == Cell::String("quintin".to_string())
+        })
+        .unwrap();
+    assert_eq!(got, 7.7);
+    assert!(sheet.sum_where("title", "director", |_| true).is_err());
+}
+
+#[test]
+fn test_to_json_string() {
+    let sheet = Sheet::load_data_from_str("id,name,ok,score\n1,\"a\",true,\n2,b\\c,false,1.5");
+
+    assert_eq!(
+        sheet.to_json_string(),
+        r#"[{"id":1,"name":"a","ok":true,"score":null},{"id":2,"name":"b\\c","ok":false,"score":1.5}]"#
+    );
+}
+
+#[test]
+fn test_export_json() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let path = std::env::temp_dir().join("datatroll_test_export.json");
+    let path = path.to_str().unwrap();
+    sheet.export_json(path).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        sheet.to_json_string()
+    );
+    assert!(sheet.export_json("output.csv").is_err());
+}
+
+#[test]
+fn test_relaxed_column_matching() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    assert!(sheet.count_where(" Review", |_| true).is_err());
+
+    sheet.column_matching = ColumnMatching::Relaxed;
+    assert_eq!(sheet.mean(" Review ").unwrap(), 3.6799999999999997);
+    assert!(sheet.map("TITLE", |c| c).is_ok());
+}
+
+#[test]
+fn test_missing_column_suggestion() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let err = sheet.map("reveiw", |c| c).unwrap_err();
+    assert_eq!(
+        err,
+        "could not find column 'reveiw', did you mean 'review'?"
+    );
+
+    let err = sheet.map("overrated", |c| c).unwrap_err();
+    assert_eq!(err, "could not find column 'overrated'");
+}
+
+#[test]
+fn test_column_by_index() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+
+    assert_eq!(sheet.mean(4).unwrap(), sheet.mean("review").unwrap());
+    assert_eq!(
+        sheet
+            .filter(ColumnRef::Index(2), |c| *c
+                == Cell::String("quintin".to_string()))
+            .len(),
+        2
+    );
+    assert!(sheet.map(5, |c| c).is_err());
+
+    sheet.drop_col(0);
+    assert_eq!(sheet.data[0][0], Cell::String("title".to_string()));
+}
+
+#[test]
+fn test_load_json_from_str() {
+    let sheet = Sheet::load_json_from_str(
+        r#"[
+            {"id": 1, "title": "old \"one\"", "ok": true},
+            {"id": 2, "review": 4.5, "title": null, "note": "caf\u00e9"},
+            {"id": 18446744073709551615, "note": "\ud83d\ude00"}
+        ]"#,
+    )
+    .unwrap();
+
+    let want = [
+        vec![
+            Cell::String("id".to_string()),
+            Cell::String("title".to_string()),
+            Cell::String("ok".to_string()),
+            Cell::String("review".to_string()),
+            Cell::String("note".to_string()),
+        ],
+        vec![
+            Cell::Int(1),
+            Cell::String("old \"one\"".to_string()),
+            Cell::Bool(true),
+            Cell::Null,
+            Cell::Null,
+        ],
+        vec![
+            Cell::Int(2),
+            Cell::Null,
+            Cell::Null,
+            Cell::Float(4.5),
+            Cell::String("café".to_string()),
+        ],
+        vec![
+            Cell::UInt(u64::MAX),
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+            Cell::String("😀".to_string()),
+        ],
+    ];
+
+    assert_eq!(sheet.data.len(), want.len());
+    for (got, want) in sheet.data.iter().zip(want.iter()) {
+        assert_sheet_row(got, want)
+    }
+}
+
+#[test]
+fn test_load_json_rejects_invalid_input() {
+    assert!(Sheet::load_json_from_str(r#"{"id": 1}"#).is_err());
+    assert!(Sheet::load_json_from_str(r#"[{"id": [1, 2]}]"#).is_err());
+    assert!(Sheet::load_json_from_str(r#"[{"id": 1}"#).is_err());
+    assert!(Sheet::load_json("test_data.csv").is_err());
+
+    let deep = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+    assert!(Sheet::load_json_from_str(&deep).is_err());
+    assert!(Sheet::load_json_from_str(r#"[{"note": "\ud83d\u0041"}]"#).is_err());
+}
+
+#[test]
+fn test_json_round_trip() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let got = Sheet::load_json_from_str(&sheet.to_json_string()).unwrap();
+    for (got, want) in got.data.iter().zip(sheet.data.iter()) {
+        assert_sheet_row(got, want)
+    }
+}
+
+#[test]
+fn test_drop_cols() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+
+    assert!(sheet.drop_cols(&["title", "overrated"]).is_err());
+    assert_eq!(sheet.data[0].len(), 5);
+
+    sheet.drop_cols(&["title", "release date"]).unwrap();
+    assert_sheet_row(
+        &sheet.data[1],
+        &[
+            Cell::Int(1),
+            Cell::String("quintin".to_string()),
+            Cell::Float(3.5),
+        ],
+    );
+}
+
+#[test]
+fn test_keep_cols() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+
+    sheet.keep_cols(&["review", "title"]).unwrap();
+    assert_sheet_row(
+        &sheet.data[0],
+        &[
+            Cell::String("review".to_string()),
+            Cell::String("title".to_string()),
+        ],
+    );
+    assert_sheet_row(
+        &sheet.data[5],
+        &[Cell::Float(5.0), Cell::String("who".to_string())],
+    );
+}
+
+#[test]
+fn test_ndjson_round_trip() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let path = std::env::temp_dir().join("datatroll_test_round_trip.ndjson");
+    let path = path.to_str().unwrap();
+    sheet.export_ndjson(path).unwrap();
+
+    let content = std::fs::read_to_string(path).unwrap();
+    assert_eq!(content.lines().count(), 5);
+
+    let got = Sheet::load_ndjson(path).unwrap();
+    assert_eq!(got.data.len(), sheet.data.len());
+    for (got, want) in got.data.iter().zip(sheet.data.iter()) {
+        assert_sheet_row(got, want)
+    }
+}
+
+#[test]
+fn test_load_ndjson_unions_keys() {
+    let path = std::env::temp_dir().join("datatroll_test_union.jsonl");
+    std::fs::write(&path, "{\"id\": 1}\n\n{\"id\": 2, \"name\": \"b\"}\n").unwrap();
+
+    let got = Sheet::load_ndjson(path.to_str().unwrap()).unwrap();
+    assert_sheet_row(&got.data[1], &[Cell::Int(1), Cell::Null]);
+    assert_sheet_row(&got.data[2], &[Cell::Int(2), Cell::String("b".to_string())]);
+}
+
+#[cfg(feature = "xlsx")]
+#[test]
+fn test_export_xlsx() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let path = std::env::temp_dir().join("datatroll_test_export.xlsx");
+    let path = path.to_str().unwrap();
+    sheet.export_xlsx(path, "movies").unwrap();
+
+    let content = std::fs::read(path).unwrap();
+    assert!(content.starts_with(b"PK"));
+    assert!(sheet.export_xlsx("output.csv", "movies").is_err());
+}
+
+#[cfg(feature = "xlsx")]
+#[test]
+fn test_xlsx_round_trip() {
+    // excel stores every number as a float, so integral floats would come back as ints
+    let sheet = Sheet::load_data_from_str("id,title,ok,score\n1,old,true,3.5\n2,,false,4.25");
+
+    let path = std::env::temp_dir().join("datatroll_test_round_trip.xlsx");
+    let path = path.to_str().unwrap();
+    sheet.export_xlsx(path, "movies").unwrap();
+
+    for got in [
+        Sheet::load_xlsx(path, "movies").unwrap(),
+        Sheet::load_xlsx(path, 0).unwrap(),
+    ] {
+        assert_eq!(got.data.len(), sheet.data.len());
+        for (got, want) in got.data.iter().zip(sheet.data.iter()) {
+            assert_sheet_row(got, want)
+        }
+    }
+    assert!(Sheet::load_xlsx(path, "missing").is_err());
+    assert!(Sheet::load_xlsx(path, 3).is_err());
+
+    // ints past 2^53 have no exact double, they keep every digit as text
+    let sheet = Sheet::load_data_from_str(
+        "id\n9007199254740992\n9007199254740993\n-9223372036854775808\n18446744073709551615",
+    );
+    sheet.export_xlsx(path, "ids").unwrap();
+    let got = Sheet::load_xlsx(path, "ids").unwrap();
+    assert_eq!(got.data.len(), 5);
+    for (got, want) in got.data.iter().zip(sheet.data.iter()) {
+        assert_sheet_row(got, want)
+    }
+    assert_eq!(got.data[2][0], Cell::Int(9007199254740993));
+}
+
+#[test]
+fn test_validators() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.add_validator("review", |c| matches!(c, Cell::Float(r) if *r <= 5.0));
+    sheet.add_validator("id", |c| matches!(c, Cell::Int(_)));
+
+    let err = sheet.insert_row("6, new, nolan, 2020, 7.5").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "value '7.5' in column 'review' failed validation"
+    );
+    assert!(sheet
+        .insert_row_cells(vec![
+            Cell::String("seven".to_string()),
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+            Cell::Float(1.0),
+        ])
+        .is_err());
+
+    let rows = vec![
+        vec![
+            Cell::Int(6),
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+            Cell::Float(1.0),
+        ],
+        vec![
+            Cell::Int(7),
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+            Cell::Float(6.0),
+        ],
+    ];
+    assert!(sheet.extend(rows.clone()).is_err());
+    assert_eq!(sheet.data.len(), 6);
+
+    sheet.extend(rows.into_iter().take(1)).unwrap();
+    assert_eq!(sheet.data.len(), 7);
+}
+
+#[test]
+fn test_duplicated() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.insert_row("1, old, quintin, 2011, 3.5").unwrap();
+
+    assert_eq!(
+        sheet.duplicated(&["director"]).unwrap(),
+        vec![false, true, false, false, false, true]
+    );
+    assert_eq!(
+        sheet.duplicated::<&str>(&[]).unwrap(),
+        vec![false, false, false, false, false, true]
+    );
+    assert!(sheet.duplicated(&["overrated"]).is_err());
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_round_trip() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet
+        .insert_row_cells(vec![
+            Cell::Int(6),
+            Cell::Null,
+            Cell::Bool(true),
+            Cell::Null,
+            Cell::Int(4),
+        ])
+        .unwrap();
+
+    let path = std::env::temp_dir().join("datatroll_test_round_trip.parquet");
+    let path = path.to_str().unwrap();
+    sheet.export_parquet(path).unwrap();
+    let got = Sheet::load_parquet(path).unwrap();
+
+    assert_eq!(got.data.len(), sheet.data.len());
+    assert_sheet_row(&got.data[1], &sheet.data[1]);
+    // mixed columns are stored as strings, and mixed numeric columns as floats
+    assert_sheet_row(
+        &got.data[6],
+        &[
+            Cell::Int(6),
+            Cell::Null,
+            Cell::String("true".to_string()),
+            Cell::Null,
+            Cell::Float(4.0),
+        ],
+    );
+    assert!(sheet.export_parquet("output.csv").is_err());
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn test_record_batch_round_trip() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let batch = sheet.to_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), 5);
+    assert_eq!(batch.num_columns(), 5);
+
+    let got = Sheet::from_record_batch(&batch).unwrap();
+    assert_eq!(got.data.len(), sheet.data.len());
+    for (got, want) in got.data.iter().zip(sheet.data.iter()) {
+        assert_sheet_row(got, want)
+    }
+
+    assert!(Sheet::default().to_record_batch().is_err());
+}
+
+#[test]
+fn test_merge_updates() {
+    let updates = Sheet::load_data_from_str("review, id\n4.0, 3\n3.0, 6");
+
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet
+        .merge_updates(&updates, "id", MergeStrategy::PreferOther)
+        .unwrap();
+    assert_eq!(sheet.data.len(), 7);
+    assert_eq!(sheet.data[3][4], Cell::Float(4.0));
+    assert_eq!(sheet.data[3][1], Cell::String("easy".to_string()));
+    assert_sheet_row(
+        &sheet.data[6],
+        &[
+            Cell::Int(6),
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+            Cell::Float(3.0),
+        ],
+    );
+
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet
+        .merge_updates(&updates, "id", MergeStrategy::PreferSelf)
+        .unwrap();
+    assert_eq!(sheet.data.len(), 7);
+    assert_eq!(sheet.data[3][4], Cell::Float(1.0));
+
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    assert!(sheet
+        .merge_updates(&updates, "id", MergeStrategy::Error)
+        .is_err());
+    assert_eq!(sheet.data.len(), 6);
+
+    let unknown = Sheet::load_data_from_str("id, rating\n1, 3");
+    assert!(sheet
+        .merge_updates(&unknown, "id", MergeStrategy::PreferOther)
+        .is_err());
+}
+
+#[test]
+fn test_export_groups() {
+    let dir = std::env::temp_dir().join("datatroll_test_export_groups");
+    let _ = std::fs::remove_dir_all(&dir);
+    let dir = dir.to_str().unwrap();
+
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.export_groups(dir, "director").unwrap();
+
+    let quintin = Sheet::load_data(&format!("{dir}/quintin.csv")).unwrap();
+    assert_eq!(quintin.data.len(), 3);
+    assert_sheet_row(&quintin.data[0][..5], &sheet.data[0]);
+    assert_sheet_row(&quintin.data[2][..5], &sheet.data[2]);
+
+    let manifest = std::fs::read_to_string(format!("{dir}/manifest.json")).unwrap();
+    assert!(manifest.starts_with(r#"{"column":"director","partitions":[{"file":"quintin.csv""#));
+    assert!(manifest.contains(
+        r#""value":"quintin","rows":2,"ranges":{"id":{"min":1,"max":2},"title":{"min":"her","max":"old"}"#
+    ));
+    assert!(manifest.contains(r#""review":{"min":1.0,"max":1.0}"#));
+
+    assert!(sheet.export_groups(dir, "rating").is_err());
+
+    let sheet = Sheet::load_data_from_str("kind,size\na,18446744073709551615\na,-1\na,2.5");
+    sheet.export_groups(dir, "kind").unwrap();
+    let manifest = std::fs::read_to_string(format!("{dir}/manifest.json")).unwrap();
+    assert!(manifest.contains(r#""size":{"min":-1,"max":18446744073709551615}"#));
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn test_sqlite_round_trip() {
+    let path = std::env::temp_dir().join("datatroll_test_sqlite.db");
+    let _ = std::fs::remove_file(&path);
+    let path = path.to_str().unwrap();
+
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.export_sqlite(path, "movies").unwrap();
+
+    let loaded = Sheet::load_sqlite(path, "SELECT * FROM movies WHERE id > 3").unwrap();
+    assert_eq!(loaded.data.len(), 3);
+    assert_sheet_row(&loaded.data[0], &sheet.data[0]);
+    assert_sheet_row(&loaded.data[1], &sheet.data[4]);
+    assert_sheet_row(&loaded.data[2], &sheet.data[5]);
+
+    let flags = Sheet::load_data_from_str("id,seen\n1,true\n2,");
+    flags.export_sqlite(path, "flags").unwrap();
+    let loaded = Sheet::load_sqlite(path, "SELECT seen FROM flags").unwrap();
+    assert_eq!(loaded.data[1][0], Cell::Bool(true));
+    assert_eq!(loaded.data[2][0], Cell::Null);
+
+    assert!(sheet.export_sqlite(path, "movies").is_err());
+    assert!(Sheet::default().export_sqlite(path, "empty").is_err());
+    assert!(Sheet::default().upsert_sqlite(path, "empty", "id").is_err());
+}
+
+#[test]
+fn test_handle_non_finite() {
+    let data = "id,review\n1,3.5\n2,NaN\n3,-inf\n4,4.5";
+
+    let mut sheet = Sheet::load_data_from_str(data);
+    assert!(sheet.mean("review").is_err());
+    assert!(sheet.variance("review").is_err());
+    assert!(sheet.max_float64("review").is_err());
+    assert!(sheet.winsorize("review", 0.1).is_err());
+
+    assert!(sheet.handle_non_finite(NonFinitePolicy::Reject).is_err());
+    assert_eq!(sheet.handle_non_finite(NonFinitePolicy::Keep).unwrap(), 2);
+    assert!(matches!(sheet.data[2][1], Cell::Float(f) if f.is_nan()));
+
+    assert_eq!(sheet.handle_non_finite(NonFinitePolicy::Null).unwrap(), 2);
+    assert_eq!(sheet.data[3][1], Cell::Null);
+    assert_eq!(sheet.handle_non_finite(NonFinitePolicy::Reject).unwrap(), 0);
+
+    let sheet = Sheet::load_data_from_str("id,review\n1,3.5\n2,inf");
+    assert_eq!(sheet.max_float64("review").unwrap(), f64::INFINITY);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn test_load_postgres_connection_error() {
+    let result = Sheet::load_postgres("host=/nonexistent/datatroll user=postgres", "SELECT 1");
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn test_postgres_numeric() {
+    use super::postgres::numeric_cell;
+
+    // the binary format: digit count, weight, sign and scale, then digits in base 10000
+    let encode =
+        |words: &[u16]| -> Vec<u8> { words.iter().flat_map(|w| w.to_be_bytes()).collect() };
+    let cell = |words: &[u16]| numeric_cell(&encode(words)).unwrap();
+
+    assert_eq!(cell(&[2, 0, 0x4000, 2, 1234, 5600]), Cell::Float(-1234.56));
+    assert_eq!(cell(&[1, 1, 0, 0, 12]), Cell::Float(120000.0));
+    assert_eq!(cell(&[1, 0xFFFF, 0, 6, 12]), Cell::Float(0.0012));
+    assert_eq!(cell(&[0, 0, 0, 0]), Cell::Float(0.0));
+    assert!(matches!(cell(&[0, 0, 0xC000, 0]), Cell::Float(f) if f.is_nan()));
+    assert_eq!(
+        cell(&[5, 4, 0, 0, 1, 2345, 6789, 123, 4567]),
+        Cell::String("12345678901234567".to_string())
+    );
+    // a NUMERIC(38, 18) value, padded with zeros up to its scale
+    assert_eq!(cell(&[2, 0, 0, 18, 1, 5000]), Cell::Float(1.5));
+    assert_eq!(
+        cell(&[5, 0, 0, 18, 1, 5000, 0, 0, 1]),
+        Cell::String("1.500000000000000100".to_string())
+    );
+    assert!(numeric_cell(&encode(&[2, 0, 0, 0, 1])).is_err());
+}
+
+#[test]
+fn test_sql_inserts() {
+    let sheet = Sheet::load_data_from_str("id,title,seen,review\n1,it's,true,3.5\n2,a\\b,,4");
+
+    let dump = sheet
+        .to_sql_inserts("my movies", SqlDialect::Postgres)
+        .unwrap();
+    assert_eq!(
+        dump,
+        "CREATE TABLE \"my movies\" (\n    \"id\" BIGINT,\n    \"title\" TEXT,\n    \"seen\" BOOLEAN,\n    \"review\" DOUBLE PRECISION\n);\n\
+         INSERT INTO \"my movies\" (\"id\", \"title\", \"seen\", \"review\") VALUES\n\
+         (1, 'it''s', TRUE, 3.5),\n(2, 'a\\b', NULL, 4);\n"
+    );
+
+    let dump = sheet.to_sql_inserts("movies", SqlDialect::MySql).unwrap();
+    assert!(dump.contains("`review` DOUBLE"));
+    assert!(dump.contains("(2, 'a\\\\b', NULL, 4);"));
+
+    let dump = sheet.to_sql_inserts("movies", SqlDialect::Sqlite).unwrap();
+    assert!(dump.contains("(1, 'it''s', 1, 3.5),"));
+
+    assert!(sheet
+        .export_sql_inserts("movies.txt", "movies", SqlDialect::Sqlite)
+        .is_err());
+
+    // negative ints and unsigned ints only fit a decimal type together
+    let sheet =
+        Sheet::load_data_from_str("big,mixed\n18446744073709551615,-1\n1,18446744073709551615");
+    let dump = sheet.to_sql_inserts("t", SqlDialect::MySql).unwrap();
+    assert!(dump.contains("`big` BIGINT UNSIGNED"));
+    assert!(dump.contains("`mixed` DECIMAL(20,0)"));
+    let dump = sheet.to_sql_inserts("t", SqlDialect::Postgres).unwrap();
+    assert!(dump.contains("\"mixed\" NUMERIC(20)"));
+
+    assert!(Sheet::default()
+        .to_sql_inserts("t", SqlDialect::Postgres)
+        .is_err());
+}
+
+#[test]
+fn test_uint_cells() {
+    let mut sheet = Sheet::load_data_from_str(
+        "id,views\n18446744073709551615,12\n9223372036854775807,9223372036854775808",
+    );
+
+    assert_eq!(sheet.data[1][0], Cell::UInt(u64::MAX));
+    assert_eq!(sheet.data[2][0], Cell::Int(i64::MAX));
+    assert_eq!(sheet.data[2][1], Cell::UInt(1 << 63));
+    assert!(sheet.data[2][0] < sheet.data[1][0]);
+    assert_eq!(sheet.data[1][0].to_string(), "18446744073709551615");
+    assert_eq!(sheet.max_float64("views").unwrap(), 9223372036854775808.0);
+
+    let path = std::env::temp_dir().join("datatroll_test_uint.dtrl");
+    sheet.save_binary(path.to_str().unwrap()).unwrap();
+    let restored = Sheet::load_binary(path.to_str().unwrap()).unwrap();
+    assert_sheet_row(&restored.data[1], &sheet.data[1]);
+
+    sheet.clamp("views", 0.0, 100.0).unwrap();
+    assert_eq!(sheet.data[2][1], Cell::Int(100));
+}
+
+#[test]
+fn test_load_data_with_delimiter() {
+    let options = LoadOptions {
+        delimiter: '\t',
+        ..Default::default()
+    };
+    let sheet = Sheet::load_data_from_str_with(&STR_DATA.replace(',', "\t"), &options).unwrap();
+    let want = Sheet::load_data_from_str(STR_DATA);
+    for (got, want) in sheet.data.iter().zip(want.data.iter()) {
+        assert_sheet_row(got, want);
+    }
+
+    let path = std::env::temp_dir().join("datatroll_test_delimiter.tsv");
+    let path = path.to_str().unwrap();
+    sheet.export(path).unwrap();
+    let exported = std::fs::read_to_string(path).unwrap();
+    assert!(exported.starts_with("id\ttitle\tdirector\trelease date\treview\t\n"));
+
+    let reloaded = Sheet::load_data_with(path, &options).unwrap();
+    assert_eq!(reloaded.data[3][1], Cell::String("easy".to_string()));
+
+    let options = LoadOptions {
+        delimiter: ';',
+        non_finite: NonFinitePolicy::Reject,
+        ..Default::default()
+    };
+    assert!(Sheet::load_data_from_str_with("id;review\n1;NaN", &options).is_err());
+}
+
+#[test]
+fn test_scientific_notation() {
+    let data = "code,amount\n1e5,2.5E3\n12,inf";
+
+    let sheet = Sheet::load_data_from_str(data);
+    assert_eq!(sheet.data[1][0], Cell::Float(100000.0));
+
+    let options = LoadOptions {
+        scientific_notation_columns: vec![("code".to_string(), ScientificNotation::KeepAsString)],
+        ..Default::default()
+    };
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.data[1][0], Cell::String("1e5".to_string()));
+    assert_eq!(sheet.data[1][1], Cell::Float(2500.0));
+    assert_eq!(sheet.data[2][0], Cell::Int(12));
+
+    let options = LoadOptions {
+        scientific_notation: ScientificNotation::KeepAsString,
+        scientific_notation_columns: vec![("code".to_string(), ScientificNotation::Parse)],
+        ..Default::default()
+    };
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.data[1][0], Cell::Float(100000.0));
+    assert_eq!(sheet.data[1][1], Cell::String("2.5E3".to_string()));
+    assert_eq!(sheet.data[2][1], Cell::Float(f64::INFINITY));
+}
+
+#[test]
+fn test_load_fixed_width() {
+    let data = "   1old    quintin 3.5\n\n   2her    quintin\n   3é";
+    let columns = [
+        ("id", 0, 4),
+        ("title", 4, 7),
+        ("director", 11, 8),
+        ("review", 19, 3),
+    ];
+
+    let sheet = Sheet::load_fixed_width_from_str(data, &columns).unwrap();
+    assert_eq!(sheet.data.len(), 4);
+    assert_sheet_row(
+        &sheet.data[1],
+        &[
+            Cell::Int(1),
+            Cell::String("old".to_string()),
+            Cell::String("quintin".to_string()),
+            Cell::Float(3.5),
+        ],
+    );
+    assert_eq!(sheet.data[2][3], Cell::Null);
+
+    assert!(Sheet::load_fixed_width_from_str(data, &[("id", 0, 5)]).is_err());
+}
+
+#[test]
+fn test_trim_policy() {
+    let data = "id, title\n 1 , \"  old \"\n2,\"12\"";
+
+    let sheet = Sheet::load_data_from_str(data);
+    assert_eq!(sheet.data[0][1], Cell::String("title".to_string()));
+    assert_eq!(sheet.data[1][1], Cell::String("old".to_string()));
+
+    let options = LoadOptions {
+        trim: TrimPolicy::UnquotedOnly,
+        ..Default::default()
+    };
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.data[1][0], Cell::Int(1));
+    assert_eq!(sheet.data[1][1], Cell::String("  old ".to_string()));
+    assert_eq!(sheet.data[2][1], Cell::String("12".to_string()));
+
+    let options = LoadOptions {
+        trim: TrimPolicy::None,
+        ..Default::default()
+    };
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.data[0][1], Cell::String(" title".to_string()));
+    assert_eq!(sheet.data[1][0], Cell::String(" 1 ".to_string()));
+    assert_eq!(sheet.data[2][0], Cell::Int(2));
+}
+
+#[test]
+fn test_apply_cols() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+
+    sheet
+        .apply_cols("label", &["title", "release date"], |cells| {
+            Cell::String(format!("{} ({})", cells[0], cells[1]))
+        })
+        .unwrap();
+    assert_eq!(sheet.data[0][5], Cell::String("label".to_string()));
+    assert_eq!(sheet.data[1][5], Cell::String("old (2011)".to_string()));
+
+    assert!(sheet.apply_cols("label", &["id"], |_| Cell::Null).is_err());
+    assert!(sheet
+        .apply_cols("other", &["rating"], |_| Cell::Null)
+        .is_err());
+    assert_eq!(sheet.data[0].len(), 6);
+}
+
+#[test]
+fn test_to_markdown() {
+    let sheet = Sheet::load_data_from_str("id,note\n1,a|b\n,\n10,c");
+
+    assert_eq!(
+        sheet.to_markdown(),
+        "| id  | note |\n| --: | :--- |\n|   1 | a\\|b |\n|     |      |\n|  10 | c    |\n"
+    );
+}
+
+#[test]
+fn test_to_latex() {
+    let sheet = Sheet::load_data_from_str("group_name,share\nR&D,12.5\n50% off,");
+
+    assert_eq!(
+        sheet.to_latex(TableStyle::Booktabs),
+        "\\begin{tabular}{lr}\n\\toprule\ngroup\\_name & share \\\\\n\\midrule\n\
+         R\\&D & 12.5 \\\\\n50\\% off &  \\\\\n\\bottomrule\n\\end{tabular}\n"
+    );
+    assert!(sheet
+        .to_latex(TableStyle::Plain)
+        .starts_with("\\begin{tabular}{lr}\n\\hline\n"));
+    assert!(sheet.export_latex("table.txt", TableStyle::Plain).is_err());
+}
+
+#[test]
+fn test_melt_dates() {
+    let sheet = Sheet::load_data_from_str(
+        "id,region,2023-01,02/2023,Sept 2023,Dec-24,2025\n1,north,1,2,3,4,5",
+    );
+    let long = sheet
+        .melt_dates(&["id", "region"], "period", "sales")
+        .unwrap();
+
+    assert_eq!(long.data.len(), 6);
+    let dates: Vec<String> = long
+        .data
+        .iter()
+        .skip(1)
+        .map(|row| row[2].to_string())
+        .collect();
+    assert_eq!(
+        dates,
+        [
+            "2023-01-01",
+            "2023-02-01",
+            "2023-09-01",
+            "2024-12-01",
+            "2025-01-01"
+        ]
+    );
+    assert_sheet_row(
+        &long.data[5],
+        &[
+            Cell::Int(1),
+            Cell::String("north".to_string()),
+            Cell::String("2025-01-01".to_string()),
+            Cell::Int(5),
+        ],
+    );
+
+    let messy = Sheet::load_data_from_str("id,2023-01,notes\n1,2,junk");
+    assert!(messy.melt_dates(&["id"], "period", "sales").is_err());
+}
+
+#[test]
+fn test_approx_equals() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    let mut other = Sheet::load_data_from_str(STR_DATA);
+    assert!(sheet.approx_equals(&other, 0.0));
+
+    other.data[1][4] = Cell::Float(3.5 + 1e-12);
+    other.data[2][0] = Cell::Float(2.0);
+    assert!(!sheet.approx_equals(&other, 0.0));
+    assert!(sheet.approx_equals(&other, 1e-9));
+
+    other.data[3][1] = Cell::String("Easy".to_string());
+    assert!(!sheet.approx_equals(&other, 1e-9));
+
+    let nan = Sheet::load_data_from_str("v\nNaN");
+    assert!(nan.approx_equals(&Sheet::load_data_from_str("v\nNaN"), 0.0));
+    assert!(!nan.approx_equals(&Sheet::load_data_from_str("v\n1"), 10.0));
+    assert!(!sheet.approx_equals(&nan, 1.0));
+}
+
+#[test]
+fn test_xml_round_trip() {
+    let xml = r#"<?xml version="1.0"?>
+<!-- exported catalogue -->
+<catalogue>
+    <movie id="1" director="Jon Watts">
+        <title>Spider-Man: No Way Home</title>
+        <review>3.5</review>
+    </movie>
+    <movie id="2"><title><![CDATA[Tom & Jerry]]></title><review/></movie>
+    <movie id="3"><title>R&amp;D &#x263A;</title><rating>4</rating></movie>
+</catalogue>"#;
+    let sheet = Sheet::load_xml_from_str(xml, "movie").unwrap();
+
+    assert_sheet_row(
+        &sheet.data[0],
+        &["id", "director", "title", "review", "rating"].map(|s| Cell::String(s.to_string())),
+    );
+    assert_sheet_row(
+        &sheet.data[2],
+        &[
+            Cell::Int(2),
+            Cell::Null,
+            Cell::String("Tom & Jerry".to_string()),
+            Cell::Null,
+            Cell::Null,
+        ],
+    );
+    assert_eq!(sheet.data[3][2], Cell::String("R&D \u{263A}".to_string()));
+
+    let rendered = Sheet::load_data_from_str(STR_DATA).to_xml_string("movies", "movie");
+    assert!(rendered.contains("<release_date>2017</release_date>"));
+    let reloaded = Sheet::load_xml_from_str(&rendered, "movie").unwrap();
+    assert_eq!(reloaded.data.len(), 6);
+    assert_eq!(reloaded.data[1][4], Cell::Float(3.5));
+
+    assert!(Sheet::load_xml_from_str("<movies><movie id=1/></movies>", "movie").is_err());
+    // stray, mismatched and missing closing tags
+    assert!(Sheet::load_xml_from_str("</a>", "movie").is_err());
+    assert!(Sheet::load_xml_from_str("<movies></movies></movies>", "movie").is_err());
+    assert!(Sheet::load_xml_from_str("<movie><a>1</b></movie>", "movie").is_err());
+    assert!(Sheet::load_xml_from_str("<movies><movie><a>1</a>", "movie").is_err());
+    assert!(sheet.export_xml("movies.csv", "movies", "movie").is_err());
+}
+
+#[test]
+fn test_mask() {
+    use crate::mask::siphash24;
+
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.data[3][2] = Cell::Null;
+    let key = *b"0123456789abcdef";
+    sheet.mask("director", MaskStrategy::Hash(key)).unwrap();
+
+    assert_eq!(sheet.data[1][2], sheet.data[2][2]);
+    assert_eq!(sheet.data[3][2], Cell::Null);
+    assert_ne!(sheet.data[1][2], sheet.data[4][2]);
+    assert_eq!(sheet.data[1][2].to_string().len(), 16);
+
+    // the hash depends on the key
+    let mut other = Sheet::load_data_from_str(STR_DATA);
+    other.mask("director", MaskStrategy::Hash([0; 16])).unwrap();
+    assert_ne!(sheet.data[1][2], other.data[1][2]);
+
+    // the reference vectors of SipHash-2-4, for the key 00 01 .. 0f and the inputs 00 01 ..
+    let key: [u8; 16] = std::array::from_fn(|i| i as u8);
+    let input: Vec<u8> = (0..15).collect();
+    assert_eq!(siphash24(&key, &[]), 0x726fdb47dd0e0e31);
+    assert_eq!(siphash24(&key, &input[..8]), 0x93f5f5799a932462);
+    assert_eq!(siphash24(&key, &input), 0xa129ca6149be45e5);
+
+    let mut a = Sheet::load_data_from_str(STR_DATA);
+    let mut b = Sheet::load_data_from_str(STR_DATA);
+    a.mask("title", MaskStrategy::FakeName(7)).unwrap();
+    b.mask(1, MaskStrategy::FakeName(7)).unwrap();
+    assert!(a.approx_equals(&b, 0.0));
+    assert_eq!(a.data[1][1].to_string().split(' ').count(), 2);
+
+    a.mask("id", MaskStrategy::Redact).unwrap();
+    assert_eq!(a.data[5][0], Cell::String("***".to_string()));
+    assert!(a.mask("ssn", MaskStrategy::Redact).is_err());
+}
+
+#[test]
+fn test_to_yaml_string() {
+    let mut sheet =
+        Sheet::load_data_from_str("name,note,score\nada,key: value,NaN\n- dash,,-inf\nno,,2");
+    sheet.data[3][1] = Cell::String("1.5".to_string());
+
+    assert_eq!(
+        sheet.to_yaml_string(),
+        "- name: ada\n  note: \"key: value\"\n  score: .nan\n\
+         - name: \"- dash\"\n  note: null\n  score: -.inf\n\
+         - name: \"no\"\n  note: \"1.5\"\n  score: 2\n"
+    );
+    assert_eq!(Sheet::load_data_from_str("id").to_yaml_string(), "[]\n");
+    assert!(sheet.export_yaml("fixtures.json").is_err());
+}
+
+#[test]
+fn test_msgpack_round_trip() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.data[1][1] = Cell::String("a".repeat(40));
+    sheet.data[2][1] = Cell::String("b".repeat(300));
+    sheet.data[3][0] = Cell::Int(-100_000);
+    sheet.data[4][0] = Cell::UInt(u64::MAX);
+    sheet.data[5][1] = Cell::Bool(false);
+    sheet.data[5][2] = Cell::Null;
+
+    let restored = Sheet::from_msgpack(&sheet.to_msgpack()).unwrap();
+    assert_eq!(restored.data.len(), sheet.data.len());
+    for (got, want) in restored.data.iter().zip(&sheet.data) {
+        assert_sheet_row(got, want);
+    }
+
+    let bytes = sheet.to_msgpack();
+    assert!(Sheet::from_msgpack(&bytes[..bytes.len() - 1]).is_err());
+    assert!(Sheet::from_msgpack(&[0x91, 0x91, 0x80]).is_err());
+    assert!(Sheet::from_msgpack(&[0x90, 0x90]).is_err());
+    // a huge declared length on a truncated input
+    assert!(Sheet::from_msgpack(&[0x91, 0xdd, 0xff, 0xff, 0xff, 0xff]).is_err());
+    assert!(Sheet::from_msgpack(&[0xdd, 0xff, 0xff, 0xff, 0xff, 0x90]).is_err());
+    // a row narrower than the header
+    assert!(Sheet::from_msgpack(&[0x92, 0x92, 0x01, 0x02, 0x91, 0x01]).is_err());
+}
+
+#[test]
+fn test_hash_rows() {
+    let mut sheet = Sheet::load_data_from_str("a,b,c\nab,c,1\na,bc,1\nab,c,2\n1,x,\n");
+    sheet.data[4][0] = Cell::String("1".to_string());
+    sheet.hash_rows(&["a", "b"], "row_key").unwrap();
+    sheet.hash_rows(&[0], "first_key").unwrap();
+
+    assert_eq!(sheet.data[1][3], sheet.data[3][3]);
+    assert_ne!(sheet.data[1][3], sheet.data[2][3]);
+    assert_eq!(sheet.data[1][3].to_string().len(), 16);
+
+    let mut typed = Sheet::load_data_from_str("a\n1");
+    typed.hash_rows(&["a"], "row_key").unwrap();
+    assert_ne!(typed.data[1][1], sheet.data[4][4]);
+
+    let mut again = Sheet::load_data_from_str("a,b,c\nab,c,1");
+    again.hash_rows(&["a", "b"], "row_key").unwrap();
+    assert_eq!(again.data[1][3], sheet.data[1][3]);
+
+    assert!(sheet.hash_rows(&["a"], "row_key").is_err());
+    assert!(sheet.hash_rows(&["missing"], "other_key").is_err());
+}
+
+#[test]
+fn test_load_cache_or_csv() {
+    let dir = std::env::temp_dir().join("datatroll_test_cache");
+    std::fs::create_dir_all(&dir).unwrap();
+    let csv = dir.join("movies.csv");
+    let cache = dir.join("movies.csv.cache");
+    let (csv, cache) = (csv.to_str().unwrap(), cache.to_str().unwrap());
+    std::fs::write(csv, STR_DATA).unwrap();
+    let _ = std::fs::remove_file(cache);
+
+    let sheet = Sheet::load_cache_or_csv(csv, cache).unwrap();
+    assert_eq!(sheet.data.len(), 6);
+    assert!(Sheet::load_cache(cache).unwrap().approx_equals(&sheet, 0.0));
+
+    // a cache is used while the CSV file keeps its size and modification time, without looking
+    // at its content
+    let modified = std::fs::metadata(csv).unwrap().modified().unwrap();
+    let rewrite = |content: &str| {
+        std::fs::write(csv, content).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(csv)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    };
+    rewrite(&STR_DATA.replacen("nolan", "NOLAN", 1));
+    let cached = Sheet::load_cache_or_csv(csv, cache).unwrap();
+    assert!(cached.approx_equals(&sheet, 0.0));
+
+    // a stale cache is refreshed, even when the CSV file kept its modification time
+    rewrite("id\n1");
+    assert_eq!(Sheet::load_cache_or_csv(csv, cache).unwrap().data.len(), 2);
+    let later = std::time::SystemTime::now() + std::time::Duration::from_secs(60);
+    std::fs::write(csv, STR_DATA).unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(csv)
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+    assert_eq!(Sheet::load_cache_or_csv(csv, cache).unwrap().data.len(), 6);
+
+    // so is a cache not tied to a source file
+    Sheet::load_data_from_str("id\n1")
+        .save_cache(cache)
+        .unwrap();
+    assert_eq!(Sheet::load_cache(cache).unwrap().data.len(), 2);
+    assert_eq!(Sheet::load_cache_or_csv(csv, cache).unwrap().data.len(), 6);
+
+    // so is an unreadable one
+    std::fs::write(cache, "not a cache").unwrap();
+    assert_eq!(Sheet::load_cache_or_csv(csv, cache).unwrap().data.len(), 6);
+    assert_eq!(Sheet::load_cache(cache).unwrap().data.len(), 6);
+}
+
+#[test]
+fn test_mark_first_occurrence() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.data[4][2] = Cell::Null;
+    sheet.data[5][2] = Cell::Null;
+    sheet.mark_first_occurrence("director").unwrap();
+
+    let flags: Vec<Cell> = sheet
+        .data
+        .iter()
+        .skip(1)
+        .map(|row| row[5].clone())
+        .collect();
+    assert_eq!(
+        flags,
+        [true, false, true, true, false].map(Cell::Bool).to_vec()
+    );
+    assert!(sheet.mark_first_occurrence("director").is_err());
+    assert!(sheet.mark_first_occurrence("producer").is_err());
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn test_gzip_round_trip() {
+    let path = std::env::temp_dir().join("datatroll_test_gzip.csv.gz");
+    let path = path.to_str().unwrap();
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.export(path).unwrap();
+
+    let raw = std::fs::read(path).unwrap();
+    assert_eq!(raw[..2], [0x1f, 0x8b]);
+
+    let got = Sheet::load_data(path).unwrap();
+    assert_eq!(got.data.len(), sheet.data.len());
+    for (got, want) in got.data.iter().zip(sheet.data.iter()) {
+        assert_sheet_row(&got[..4], &want[..4])
+    }
+    assert_eq!(got.data[2][4], Cell::Float(4.2));
+    assert!(sheet.export("movies.json.gz").is_err());
+}
+
+#[test]
+#[cfg(not(feature = "gzip"))]
+fn test_gzip_requires_feature() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    let err = sheet.export("movies.csv.gz").unwrap_err();
+    assert!(err.to_string().contains("`gzip` feature"));
+    assert!(Sheet::load_data("movies.csv.gz").is_err());
+}
+
+#[test]
+fn test_row_to_json() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.data[3][2] = Cell::String("say \"hi\"".to_string());
+
+    assert_eq!(
+        sheet.row_to_json(2).unwrap(),
+        r#"{"id":3,"title":"easy","director":"say \"hi\"","release date":2005,"review":1.0}"#
+    );
+    assert!(sheet.row_to_json(0).unwrap().starts_with(r#"{"id":1,"#));
+    assert!(sheet.row_to_json(5).is_err());
+    assert!(Sheet::default().row_to_json(0).is_err());
+}
+
+#[test]
+#[cfg(feature = "zip")]
+fn test_load_zip() {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    let path = std::env::temp_dir().join("datatroll_test_bundle.zip");
+    let mut writer = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+    for (name, content) in [
+        ("2024/january.csv", STR_DATA),
+        ("2024/february.csv", "id,title\n7,new"),
+        ("readme.txt", "monthly bundle"),
+        ("__MACOSX/2024/._january.csv", "junk"),
+    ] {
+        writer
+            .start_file(name, SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(content.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let sheets = Sheet::load_zip(path.to_str().unwrap()).unwrap();
+    assert_eq!(sheets.len(), 2);
+    assert_eq!(sheets["2024/january.csv"].data.len(), 6);
+    assert_eq!(sheets["2024/february.csv"].data[1][0], Cell::Int(7));
+    assert!(Sheet::load_zip("bundle.tar").is_err());
+}
+
+#[test]
+fn test_string_stats() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.data[3][2] = Cell::Null;
+    sheet.data[4][2] = Cell::String("   ".to_string());
+
+    assert_eq!(
+        sheet.string_stats("director", 2).unwrap(),
+        StringStats {
+            nulls: 1,
+            empty: 1,
+            distinct: 3,
+            min_len: Some(3),
+            max_len: Some(7),
+            top: vec![("quintin".to_string(), 2), ("   ".to_string(), 1)],
+        }
+    );
+    assert_eq!(sheet.string_stats("id", 0).unwrap().top, vec![]);
+    assert!(sheet.string_stats("studio", 3).is_err());
+}
+
+#[test]
+fn test_load_from_reader() {
+    let sheet = Sheet::load_from_reader(std::io::Cursor::new(STR_DATA)).unwrap();
+    assert!(sheet.approx_equals(&Sheet::load_data_from_str(STR_DATA), 0.0));
+
+    let options = LoadOptions {
+        delimiter: ';',
+        ..Default::default()
+    };
+    let sheet = Sheet::load_from_reader_with(&b"id;title\n1;old"[..], &options).unwrap();
+    assert_eq!(sheet.data[1][1], Cell::String("old".to_string()));
+
+    assert!(Sheet::load_from_reader(&[0xff, 0xfe, 0x00][..]).is_err());
+}
+
+#[test]
+fn test_copy_variants() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let by_year = sheet
+        .filtered("release date", |c| matches!(c, Cell::Int(y) if *y > 2010))
+        .unwrap()
+        .mapped("title", |c| Cell::String(c.to_string().to_uppercase()))
+        .unwrap()
+        .sorted("release date", true)
+        .unwrap();
+    let titles: Vec<String> = by_year.data.iter().map(|row| row[1].to_string()).collect();
+    assert_eq!(titles, ["title", "OLD", "HER", "WHO"]);
+    assert!(Sheet::load_data_from_str(STR_DATA).approx_equals(&sheet, 0.0));
+
+    let mut with_null = Sheet::load_data_from_str(STR_DATA);
+    with_null.data[2][4] = Cell::Null;
+    let asc = with_null.sorted(4, true).unwrap();
+    let reviews: Vec<String> = asc.data[1..].iter().map(|row| row[4].to_string()).collect();
+    assert_eq!(reviews, ["1", "3.5", "4.7", "5", ""]);
+
+    // ints and floats are sorted by value, not by type
+    let mixed = Sheet::load_data_from_str("n\n3\n2.5\n\n4");
+    let values: Vec<String> = mixed.sorted("n", true).unwrap().data[1..]
+        .iter()
+        .map(|row| row[0].to_string())
+        .collect();
+    assert_eq!(values, ["2.5", "3", "4", ""]);
+    let values: Vec<String> = mixed.sorted("n", false).unwrap().data[1..]
+        .iter()
+        .map(|row| row[0].to_string())
+        .collect();
+    assert_eq!(values, ["4", "3", "2.5", ""]);
+
+    // the merge join of sorted mixed keys matches the hash join
+    let mut book = Workbook::new();
+    book.insert("l", Sheet::load_data_from_str("k,a\n1,x\n2.5,y\n3,z"));
+    book.insert("r", Sheet::load_data_from_str("k,b\n1,p\n2.5,q\n3,s"));
+    let merged = book.join("l", "r", "k").unwrap();
+    book.insert("r", Sheet::load_data_from_str("k,b\n3,s\n1,p\n2.5,q"));
+    let hashed = book.join("l", "r", "k").unwrap();
+    assert_eq!(merged.row_count(), 3);
+    assert_eq!(merged.to_csv_string(), hashed.to_csv_string());
+
+    assert!(sheet.sorted("budget", true).is_err());
+    assert!(sheet.filtered("budget", |_| true).is_err());
+    assert!(sheet.mapped("budget", |c| c).is_err());
+}
+
+#[test]
+fn test_export_to_writer() {
+    let mut sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,,");
+    sheet.delimiter = ';';
+
+    let mut out = std::io::Cursor::new(Vec::new());
+    sheet.export_to_writer(&mut out).unwrap();
+    let csv = String::from_utf8(out.into_inner()).unwrap();
+    assert_eq!(csv, "id;title;review;\n1;old;3.5;\n2;;;\n");
+    assert_eq!(sheet.to_csv_string(), csv);
+}
+
+#[test]
+fn test_export_profiles() {
+    let mut sheet = Sheet::load_data_from_str("id,note,share\n1,a\\b,12.5\n2,,");
+    sheet.data[2][1] = Cell::String("tab\there".to_string());
+
+    let mut out = Vec::new();
+    let excel_eu = ExportOptions::profile("excel-eu").unwrap();
+    sheet.export_to_writer_with(&mut out, &excel_eu).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "\u{feff}id;note;share\r\n1;a\\b;12,5\r\n2;tab\there;\r\n"
+    );
+
+    let mut out = Vec::new();
+    let copy = ExportOptions::profile("postgres-copy").unwrap();
+    sheet.export_to_writer_with(&mut out, &copy).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "1\ta\\\\b\t12.5\n2\ttab\\there\t\\N\n"
+    );
+
+    let path = std::env::temp_dir().join("datatroll_test_profile.tsv");
+    let path = path.to_str().unwrap();
+    sheet
+        .export_with(path, &ExportOptions::profile("tsv").unwrap())
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        "id\tnote\tshare\n1\ta\\b\t12.5\n2\t\"tab\there\"\t\n"
+    );
+
+    assert!(ExportOptions::profile("excel-us").is_err());
+    assert!(sheet
+        .export_with("out.json", &ExportOptions::default())
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "http")]
+fn test_load_url() {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/movies.csv", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        for status in ["200 OK", "404 Not Found"] {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut authorized = false;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                authorized |= line.eq_ignore_ascii_case("authorization: Bearer secret\r\n");
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let body = if authorized { STR_DATA } else { "" };
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    let sheet = Sheet::load_url_with_headers(&url, &[("Authorization", "Bearer secret")]).unwrap();
+    assert_eq!(sheet.data.len(), 6);
+    assert!(Sheet::load_url(&url).is_err());
+    server.join().unwrap();
+}
+
+#[test]
+#[cfg(feature = "object-store")]
+fn test_object_store_round_trip() {
+    let path = std::env::temp_dir().join("datatroll_test_object_store.csv");
+    let uri = format!("file://{}", path.to_str().unwrap());
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.export_object_store(&uri).unwrap();
+
+    let got = Sheet::load_object_store(&uri).unwrap();
+    assert_eq!(got.data.len(), sheet.data.len());
+    assert_eq!(got.data[3][2], Cell::String("scorces".to_string()));
+    assert!(Sheet::load_object_store("not a uri").is_err());
+    assert!(Sheet::load_object_store("ftp://host/movies.csv").is_err());
+}
+
+#[test]
+fn test_export_stdout_ignores_broken_pipe() {
+    struct ClosedPipe;
+    impl std::io::Write for ClosedPipe {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    assert!(sheet.export_to_writer(ClosedPipe).is_err());
+    assert!(super::ignore_broken_pipe(sheet.export_to_writer(ClosedPipe)).is_ok());
+    assert!(super::ignore_broken_pipe(Err(Box::from("disk full"))).is_err());
+    assert!(sheet.export_stdout().is_ok());
+}
+
+#[test]
+fn test_load_glob() {
+    let dir = std::env::temp_dir().join("datatroll_test_glob");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("2024-01.csv"), "id,title\n1,old\n2,her").unwrap();
+    std::fs::write(dir.join("2024-02.csv"), "id,title,review\n3,who,4.5").unwrap();
+    std::fs::write(dir.join("2023-12.csv"), "id,title\n0,easy").unwrap();
+    std::fs::write(dir.join("2024-notes.txt"), "not a csv").unwrap();
+
+    let pattern = dir.join("2024-??.csv");
+    let pattern = pattern.to_str().unwrap();
+    assert!(Sheet::load_glob(pattern).is_err());
+
+    let options = GlobOptions {
+        union_headers: true,
+        source_column: true,
+    };
+    let sheet = Sheet::load_glob_with(pattern, &options).unwrap();
+    assert_sheet_row(
+        &sheet.data[0],
+        &["id", "title", "review", "__source_file"].map(|s| Cell::String(s.to_string())),
+    );
+    assert_eq!(sheet.data.len(), 4);
+    assert_eq!(sheet.data[1][2], Cell::Null);
+    assert_eq!(sheet.data[3][2], Cell::Float(4.5));
+    assert!(sheet.data[1][3].to_string().ends_with("2024-01.csv"));
+    assert!(sheet.data[3][3].to_string().ends_with("2024-02.csv"));
+
+    let all = Sheet::load_glob(dir.join("*-*.csv").to_str().unwrap());
+    assert!(all.is_err());
+    let before = Sheet::load_glob(dir.join("2023*").to_str().unwrap()).unwrap();
+    assert_eq!(before.data.len(), 2);
+    assert!(Sheet::load_glob(dir.join("1999-*.csv").to_str().unwrap()).is_err());
+
+    // an empty file has no header to line up with the others
+    std::fs::write(dir.join("2024-03.csv"), "").unwrap();
+    let err = Sheet::load_glob_with(pattern, &options).unwrap_err();
+    assert!(err.to_string().contains("2024-03.csv"));
+}
+
+#[test]
+fn test_export_partitioned() {
+    let dir = std::env::temp_dir().join("datatroll_test_partitioned");
+    let _ = std::fs::remove_dir_all(&dir);
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.data[5][2] = Cell::Null;
+    sheet
+        .export_partitioned(dir.to_str().unwrap(), "director")
+        .unwrap();
+
+    let mut files: Vec<String> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(
+        files,
+        ["nolan.csv", "null.csv", "quintin.csv", "scorces.csv"]
+    );
+
+    let quintin = Sheet::load_data(dir.join("quintin.csv").to_str().unwrap()).unwrap();
+    assert_eq!(quintin.data.len(), 3);
+    assert_eq!(quintin.data[0][1], Cell::String("title".to_string()));
+    assert!(sheet
+        .export_partitioned(dir.to_str().unwrap(), "studio")
+        .is_err());
+
+    // values sharing a file name, or taking the name of a collision, get a file each
+    let dir = std::env::temp_dir().join("datatroll_test_partitioned_names");
+    let _ = std::fs::remove_dir_all(&dir);
+    let sheet = Sheet::load_data_from_str("tag,n\na.b,1\na_b,2\na_b_2,3\na.b,4");
+    sheet
+        .export_partitioned(dir.to_str().unwrap(), "tag")
+        .unwrap();
+    let mut files: Vec<String> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(files, ["a_b.csv", "a_b_2.csv", "a_b_2_2.csv"]);
+
+    // names differing only in case would clash on case-insensitive file systems
+    let dir = std::env::temp_dir().join("datatroll_test_partitioned_case");
+    let _ = std::fs::remove_dir_all(&dir);
+    let sheet = Sheet::load_data_from_str("director,n\nNolan,1\nnolan,2");
+    sheet
+        .export_partitioned(dir.to_str().unwrap(), "director")
+        .unwrap();
+    let mut files: Vec<String> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(files, ["Nolan.csv", "nolan_2.csv"]);
+
+    let dir = dir.to_str().unwrap();
+    assert!(Sheet::default()
+        .export_partitioned(dir, "director")
+        .is_err());
+    assert!(Sheet::default().export_groups(dir, 0).is_err());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_summary_sampled() {
+    let data: String = (0..5_000).fold("id,group,value\n".to_string(), |acc, i| {
+        let value = if i % 10 == 0 {
+            String::new()
+        } else {
+            (i % 50).to_string()
+        };
+        acc + &format!("{i},g{},{value}\n", i % 3)
+    });
+    let sheet = Sheet::load_data_from_str(&data);
+
+    let sampled = sheet.summary_sampled(500, 7).unwrap();
+    assert_eq!(sampled, sheet.summary_sampled(500, 7).unwrap());
+    let columns: Vec<&str> = sampled.iter().map(|s| s.column.as_str()).collect();
+    assert_eq!(columns, ["id", "value"]);
+
+    let value = &sampled[1];
+    assert!(value.sample_size < 500);
+    assert!((value.null_ratio - 0.1).abs() < 0.05);
+    assert!(value.mean_margin > 0.0);
+    assert!((value.mean - 25.0).abs() < 3.0 * value.mean_margin);
+    assert!(value.min >= 1.0 && value.max <= 49.0);
+
+    let exact = sheet.summary_sampled(10_000, 7).unwrap();
+    assert_eq!(exact[0].sample_size, 5_000);
+    assert_eq!(exact[0].mean, 2_499.5);
+    assert_eq!(exact[0].mean_margin, 0.0);
+    assert!(sheet.summary_sampled(0, 7).is_err());
+    assert!(Sheet::default().summary_sampled(10, 7).unwrap().is_empty());
+    let header_only = Sheet::load_data_from_str("id,value");
+    assert!(header_only.summary_sampled(10, 7).unwrap().is_empty());
+}
+
+#[test]
+fn test_apply_renames_from() {
+    let path = std::env::temp_dir().join("datatroll_test_renames.csv");
+    let path = path.to_str().unwrap();
+    std::fs::write(
+        path,
+        "old,new\ntitle,name\nrelease date,year\nbudget,cost\n",
+    )
+    .unwrap();
+
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    assert_eq!(sheet.apply_renames_from(path).unwrap(), 2);
+    let header: Vec<String> = sheet.data[0].iter().map(|c| c.to_string()).collect();
+    assert_eq!(header, ["id", "name", "director", "year", "review"]);
+
+    std::fs::write(path, "old,new\ntitle,director\n").unwrap();
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    assert!(sheet.apply_renames_from(path).is_err());
+    assert_eq!(sheet.data[0][1], Cell::String("title".to_string()));
+
+    std::fs::write(path, "old,new\ntitle,director\ndirector,title\n").unwrap();
+    assert_eq!(sheet.apply_renames_from(path).unwrap(), 2);
+    assert_eq!(sheet.data[0][1], Cell::String("director".to_string()));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "clipboard")]
+fn test_clipboard_text() {
+    let copied = "title\treview\r\nold\t3.5\r\nher\t\r\n";
+    let sheet = Sheet::from_clipboard_text(copied).unwrap();
+
+    assert_eq!(sheet.data.len(), 3);
+    assert_eq!(sheet.data[1][1], Cell::Float(3.5));
+    assert_eq!(sheet.data[2][1], Cell::Null);
+    assert_eq!(
+        sheet.to_clipboard_text().unwrap(),
+        "title\treview\nold\t3.5\nher\t\n"
+    );
+}
+
+#[test]
+fn test_filter_in_and_not_in() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    let directors = [
+        Cell::String("quintin".to_string()),
+        Cell::String("nolan".to_string()),
+    ];
+
+    let kept = sheet.filter_in("director", &directors).unwrap();
+    let ids: Vec<Cell> = kept.data[1..].iter().map(|row| row[0].clone()).collect();
+    assert_eq!(ids, [Cell::Int(1), Cell::Int(2), Cell::Int(4)]);
+    assert_eq!(*kept.data[0], *sheet.data[0]);
+
+    let dropped = sheet.filter_not_in("director", &directors).unwrap();
+    let ids: Vec<Cell> = dropped.data[1..].iter().map(|row| row[0].clone()).collect();
+    assert_eq!(ids, [Cell::Int(3), Cell::Int(5)]);
+
+    assert_eq!(
+        sheet
+            .filter_in("id", &[Cell::Float(1.0)])
+            .unwrap()
+            .data
+            .len(),
+        1
+    );
+    assert_eq!(sheet.filter_not_in("id", &[]).unwrap().data.len(), 6);
+    assert!(sheet.filter_in("budget", &directors).is_err());
+}
+
+#[test]
+#[cfg(feature = "google-sheets")]
+fn test_google_sheet_round_trip() {
+    use super::GoogleCredentials;
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}/v4/spreadsheets", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for _ in 0..2 {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length: ") {
+                    length = value.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let response = if head.starts_with("GET") {
+                r#"{"range":"Movies!A1:C3","majorDimension":"ROWS","values":[["id","title","review"],[1,"old",3.5],[2,"her"]]}"#
+            } else {
+                "{}"
+            };
+            write!(
+                reader.into_inner(),
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .unwrap();
+            requests.push((head, String::from_utf8(body).unwrap()));
+        }
+        requests
+    });
+
+    let credentials = GoogleCredentials::AccessToken("secret".to_string());
+    let sheet = Sheet::load_google_sheet_from(&base, "abc", "Movies!A1:C", &credentials).unwrap();
+    assert_eq!(sheet.data.len(), 3);
+    assert_eq!(sheet.data[1][2], Cell::Float(3.5));
+    assert_eq!(sheet.data[2][2], Cell::Null);
+
+    sheet
+        .export_google_sheet_to(&base, "abc", "Movies!A1", &credentials)
+        .unwrap();
+    let requests = server.join().unwrap();
+    assert!(requests[0].0.starts_with(
+        "GET /v4/spreadsheets/abc/values/Movies!A1:C?valueRenderOption=UNFORMATTED_VALUE"
+    ));
+    assert!(requests[0]
+        .0
+        .to_ascii_lowercase()
+        .contains("authorization: bearer secret"));
+    assert!(requests[1].0.starts_with("PUT "));
+    assert_eq!(
+        requests[1].1,
+        r#"{"range":"Movies!A1","majorDimension":"ROWS","values":[["id","title","review"],[1,"old",3.5],[2,"her",""]]}"#
+    );
+}
+
+#[test]
+fn test_filter_between() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    let ids = |s: &Sheet| -> Vec<Cell> { s.data[1..].iter().map(|row| row[0].clone()).collect() };
+
+    let inclusive = sheet
+        .filter_between("review", &Cell::Float(3.5), &Cell::Int(5), true)
+        .unwrap();
+    assert_eq!(
+        ids(&inclusive),
+        [Cell::Int(1), Cell::Int(2), Cell::Int(4), Cell::Int(5)]
+    );
+    let exclusive = sheet
+        .filter_between("review", &Cell::Float(3.5), &Cell::Int(5), false)
+        .unwrap();
+    assert_eq!(ids(&exclusive), [Cell::Int(2), Cell::Int(4)]);
+
+    let dates = Sheet::load_data_from_str("id,day\n1,2024-01-31\n2,2024-02-15\n3,\n4,2024-03-01");
+    let february = dates
+        .filter_between(
+            "day",
+            &Cell::String("2024-02-01".to_string()),
+            &Cell::String("2024-02-29".to_string()),
+            true,
+        )
+        .unwrap();
+    assert_eq!(ids(&february), [Cell::Int(2)]);
+
+    assert!(sheet
+        .filter_between("review", &Cell::Int(5), &Cell::Int(1), true)
+        .is_err());
+    assert!(sheet
+        .filter_between(
+            "review",
+            &Cell::Int(1),
+            &Cell::String("5".to_string()),
+            true
+        )
+        .is_err());
+    assert!(sheet
+        .filter_between("review", &Cell::Null, &Cell::Null, true)
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "avro")]
+fn test_avro_round_trip() {
+    let path = std::env::temp_dir().join("datatroll_test_round_trip.avro");
+    let path = path.to_str().unwrap();
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.data[2][4] = Cell::Null;
+    sheet.data[3][3] = Cell::String("unknown".to_string());
+    sheet.export_avro(path).unwrap();
+
+    let got = Sheet::load_avro(path).unwrap();
+    assert_eq!(got.data[0][3], Cell::String("release date".to_string()));
+    for (got, want) in got.data.iter().zip(&sheet.data) {
+        assert_eq!(**got, **want);
+    }
+    assert_eq!(got.data.len(), sheet.data.len());
+    assert!(sheet.export_avro("movies.csv").is_err());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_workbook() {
+    let dir = std::env::temp_dir().join("datatroll_test_workbook");
+    let dir = dir.to_str().unwrap();
+    let _ = std::fs::remove_dir_all(dir);
+
+    let mut book = Workbook::new();
+    book.insert("movies", Sheet::load_data_from_str(STR_DATA));
+    book.insert(
+        "directors",
+        Sheet::load_data_from_str("director,title,born\nquintin,mr,1963\nnolan,sir,1970"),
+    );
+    assert!(book
+        .insert("movies", Sheet::load_data_from_str(STR_DATA))
+        .is_some());
+    assert_eq!(book.names(), ["movies", "directors"]);
+
+    let joined = book.join("movies", "directors", "director").unwrap();
+    let header: Vec<String> = joined.data[0].iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        header,
+        [
+            "id",
+            "title",
+            "director",
+            "release date",
+            "review",
+            "directors.title",
+            "born"
+        ]
+    );
+    let ids: Vec<Cell> = joined.data[1..].iter().map(|row| row[0].clone()).collect();
+    assert_eq!(ids, [Cell::Int(1), Cell::Int(2), Cell::Int(4)]);
+    assert_eq!(joined.data[3][6], Cell::Int(1970));
+    assert!(book.join("movies", "actors", "director").is_err());
+
+    book.export_dir(dir).unwrap();
+    let loaded = Workbook::load_dir(dir).unwrap();
+    assert_eq!(loaded.names(), ["directors", "movies"]);
+    assert_eq!(loaded.get("movies").unwrap().data.len(), 6);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_follow() {
+    use std::io::Write;
+
+    let path = std::env::temp_dir().join("datatroll_test_follow.csv");
+    let path = path.to_str().unwrap();
+    std::fs::write(path, "id,level\n1,info\n2,warn").unwrap();
+
+    let mut tail = Sheet::follow(path)
+        .unwrap()
+        .with_poll_interval(std::time::Duration::from_millis(10));
+    let batch = tail.next().unwrap().unwrap();
+    assert_eq!(batch.data.len(), 2);
+    assert_eq!(batch.data[1][1], Cell::String("info".to_string()));
+    assert!(tail.poll().unwrap().is_none());
+
+    let mut file = std::fs::OpenOptions::new().append(true).open(path).unwrap();
+    write!(file, "\n3,error\n4,in").unwrap();
+    let batch = tail.poll().unwrap().unwrap();
+    let ids: Vec<Cell> = batch.data[1..].iter().map(|row| row[0].clone()).collect();
+    assert_eq!(ids, [Cell::Int(2), Cell::Int(3)]);
+    assert_eq!(batch.data[0][1], Cell::String("level".to_string()));
+
+    writeln!(file, "fo").unwrap();
+    let batch = tail.poll().unwrap().unwrap();
+    assert_eq!(batch.data[1][1], Cell::String("info".to_string()));
+
+    std::fs::write(path, "id,level\n9,debug\n").unwrap();
+    let batch = tail.poll().unwrap().unwrap();
+    assert_eq!(batch.data[1][0], Cell::Int(9));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "ods")]
+fn test_load_ods() {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    let path = std::env::temp_dir().join("datatroll_test_load.ods");
+    let path = path.to_str().unwrap();
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    let files = [
+        ("mimetype", "application/vnd.oasis.opendocument.spreadsheet"),
+        (
+            "META-INF/manifest.xml",
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2">
+<manifest:file-entry manifest:full-path="/" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>
+<manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>"#,
+        ),
+        (
+            "content.xml",
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" office:version="1.2">
+<office:body><office:spreadsheet>
+<table:table table:name="movies">
+<table:table-row><table:table-cell office:value-type="string"><text:p>title</text:p></table:table-cell><table:table-cell office:value-type="string"><text:p>review</text:p></table:table-cell></table:table-row>
+<table:table-row><table:table-cell office:value-type="string"><text:p>old</text:p></table:table-cell><table:table-cell office:value-type="float" office:value="3.5"><text:p>3.5</text:p></table:table-cell></table:table-row>
+<table:table-row><table:table-cell office:value-type="string"><text:p>her</text:p></table:table-cell></table:table-row>
+</table:table>
+</office:spreadsheet></office:body>
+</office:document-content>"#,
+        ),
+    ];
+    for (name, content) in files {
+        zip.start_file(name, SimpleFileOptions::default()).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap();
+
+    let by_name = Sheet::load_ods(path, "movies").unwrap();
+    let by_index = Sheet::load_ods(path, 0).unwrap();
+    assert_eq!(by_name.data.len(), 3);
+    assert_eq!(by_name.data[1][1], Cell::Float(3.5));
+    assert_eq!(by_name.data[2][1], Cell::Null);
+    assert_eq!(*by_index.data[1], *by_name.data[1]);
+    assert!(Sheet::load_ods(path, "budget").is_err());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_sql_upserts() {
+    let sheet = Sheet::load_data_from_str("id,title,review\n1,it's,3.5\n2,her,4.2");
+
+    let statements = sheet.sql_upserts("movies", 0, SqlDialect::Postgres);
+    assert_eq!(
+        statements,
+        vec!["INSERT INTO \"movies\" (\"id\", \"title\", \"review\") VALUES\n\
+              (1, 'it''s', 3.5),\n(2, 'her', 4.2)\n\
+              ON CONFLICT (\"id\") DO UPDATE SET \"title\" = excluded.\"title\", \"review\" = excluded.\"review\";"]
+    );
+    let statements = sheet.sql_upserts("movies", 0, SqlDialect::MySql);
+    assert!(statements[0].ends_with(
+        "ON DUPLICATE KEY UPDATE `title` = VALUES(`title`), `review` = VALUES(`review`);"
+    ));
+
+    let duplicated = Sheet::load_data_from_str("id,title\n1,old\n1,her");
+    assert!(duplicated.upsert_key("id").is_err());
+    let nulls = Sheet::load_data_from_str("id,title\n1,old\n,her");
+    assert!(nulls.upsert_key("id").is_err());
+    assert!(Sheet::default().upsert_key("id").is_err());
+    assert!(Sheet::default().upsert_key(0).is_err());
+    assert!(sheet
+        .export_sql_upserts("movies.txt", "movies", "id", SqlDialect::Sqlite)
+        .is_err());
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn test_upsert_sqlite() {
+    let path = std::env::temp_dir().join("datatroll_test_upsert.db");
+    let _ = std::fs::remove_file(&path);
+    let path = path.to_str().unwrap();
+
+    let sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,her,4.2");
+    assert_eq!(sheet.upsert_sqlite(path, "movies", "id").unwrap(), 2);
+    let update = Sheet::load_data_from_str("id,title,review\n2,her,4.8\n3,easy,1.0");
+    assert_eq!(update.upsert_sqlite(path, "movies", "id").unwrap(), 2);
+
+    let loaded = Sheet::load_sqlite(path, "SELECT * FROM movies ORDER BY id").unwrap();
+    assert_eq!(loaded.data.len(), 4);
+    assert_eq!(loaded.data[2][2], Cell::Float(4.8));
+    assert_eq!(loaded.data[3][1], Cell::String("easy".to_string()));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[cfg(feature = "ipc")]
+#[test]
+fn test_ipc_round_trip() {
+    let path = std::env::temp_dir().join("datatroll_test_round_trip.feather");
+    let path = path.to_str().unwrap();
+    let sheet = Sheet::load_data_from_str(
+        "id,title,seen,review,views\n1,old,true,3.5,18446744073709551615\n2,,false,4.5,9223372036854775808",
+    );
+
+    sheet.export_ipc(path).unwrap();
+    let loaded = Sheet::load_ipc(path).unwrap();
+    assert_eq!(loaded.data.len(), 3);
+    for (got, want) in loaded.data.iter().zip(&sheet.data) {
+        assert_sheet_row(got, want);
+    }
+    std::fs::remove_file(path).unwrap();
+
+    assert!(sheet.export_ipc("movies.csv").is_err());
+    assert!(Sheet::load_ipc("movies.parquet").is_err());
+}
+
+#[test]
+fn test_load_dbf() {
+    let fields: [(&str, u8, u8, u8); 5] = [
+        ("TITLE", b'C', 6, 0),
+        ("ID", b'N', 4, 0),
+        ("REVIEW", b'N', 5, 1),
+        ("SEEN", b'L', 1, 0),
+        ("RELEASED", b'D', 8, 0),
+    ];
+    // 3 records, a header of 32 + 5 * 32 + 1 bytes, and records of 1 + 24 bytes
+    let mut bytes = vec![0x03, 124, 1, 1, 3, 0, 0, 0, 193, 0, 25, 0];
+    bytes.resize(32, 0);
+    for (name, kind, length, decimals) in fields {
+        let mut descriptor = name.as_bytes().to_vec();
+        descriptor.resize(32, 0);
+        descriptor[11] = kind;
+        descriptor[16] = length;
+        descriptor[17] = decimals;
+        bytes.extend(descriptor);
+    }
+    bytes.push(0x0d);
+    bytes.extend(b" old      1  3.5T20110514");
+    bytes.extend(b"*her      2  4.2F20130101");
+    bytes.extend(b" caf\xe9     3     ?        ");
+    bytes.push(0x1a);
+
+    let sheet = Sheet::read_dbf(&bytes).unwrap();
+    assert_eq!(sheet.data.len(), 3);
+    assert_sheet_row(
+        &sheet.data[0],
+        &["TITLE", "ID", "REVIEW", "SEEN", "RELEASED"].map(|s| Cell::String(s.to_string())),
+    );
+    assert_sheet_row(
+        &sheet.data[1],
+        &[
+            Cell::String("old".to_string()),
+            Cell::Int(1),
+            Cell::Float(3.5),
+            Cell::Bool(true),
+            Cell::String("2011-05-14".to_string()),
+        ],
+    );
+    assert_sheet_row(
+        &sheet.data[2],
+        &[
+            Cell::String("café".to_string()),
+            Cell::Int(3),
+            Cell::Null,
+            Cell::Null,
+            Cell::Null,
+        ],
+    );
+
+    // records too short for their deletion flag and fields
+    let mut short = bytes.clone();
+    short[10] = 0;
+    assert!(Sheet::read_dbf(&short).is_err());
+    short[10] = 24;
+    assert!(Sheet::read_dbf(&short).is_err());
+
+    bytes[32 + 11] = b'M';
+    assert!(Sheet::read_dbf(&bytes).is_err());
+    assert!(Sheet::read_dbf(&bytes[..100]).is_err());
+    assert!(Sheet::load_dbf("parcels.csv").is_err());
+}
+
+#[test]
+fn test_change_tracking() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    assert!(sheet.changes().is_err());
+    sheet.begin_tracking();
+    assert!(sheet.changes().unwrap().is_empty());
+    assert_eq!(sheet.changes().unwrap().to_string(), "no changes");
+
+    sheet.drop_rows("director", |c| *c == Cell::String("nolan".to_string()));
+    sheet.data[1][4] = Cell::Float(3.8);
+    sheet.drop_col("release date");
+    sheet.data[0].push(Cell::String("seen".to_string()));
+    for row in sheet.data.iter_mut().skip(1) {
+        row.push(Cell::Bool(false));
+    }
+    let mut row = sheet.data[1].clone();
+    row[0] = Cell::Int(6);
+    sheet.data.push(row);
+
+    let changes = sheet.changes().unwrap();
+    assert_eq!(changes.rows_added, 1);
+    assert_eq!(changes.rows_removed, 1);
+    assert_eq!(changes.rows_modified, 1);
+    assert_eq!(changes.columns_added, vec!["seen".to_string()]);
+    assert_eq!(changes.columns_removed, vec!["release date".to_string()]);
+    assert_eq!(changes.cells_modified, vec![("review".to_string(), 1)]);
+    assert_eq!(
+        changes.to_string(),
+        "1 row added, 1 row removed, 1 row modified\ncolumns added: seen\n\
+         columns removed: release date\ncells modified: review (1)"
+    );
+}
+
+#[test]
+fn test_stream_csv() {
+    let path = std::env::temp_dir().join("datatroll_test_stream.csv");
+    let path = path.to_str().unwrap();
+    std::fs::write(path, STR_DATA).unwrap();
+
+    let chunks: Vec<Sheet> = Sheet::stream_csv(path, 2)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let whole = Sheet::load_data_from_str(STR_DATA);
+    assert_eq!(
+        chunks.iter().map(|c| c.data.len()).collect::<Vec<_>>(),
+        vec![3, 3, 2]
+    );
+    let rows = chunks.iter().flat_map(|chunk| {
+        assert_sheet_row(&chunk.data[0], &whole.data[0]);
+        chunk.data[1..].iter()
+    });
+    for (got, want) in rows.zip(&whole.data[1..]) {
+        assert_sheet_row(got, want);
+    }
+    assert_eq!(Sheet::stream_csv(path, 10).unwrap().count(), 1);
+
+    std::fs::write(path, "id,title\n").unwrap();
+    assert_eq!(Sheet::stream_csv(path, 10).unwrap().count(), 0);
+    assert!(Sheet::stream_csv(path, 0).is_err());
+    assert!(Sheet::stream_csv("movies.json", 10).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_row_numbers_and_uuids() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    sheet.with_row_numbers("row_id", 100).unwrap();
+    assert_eq!(sheet.data[0][5], Cell::String("row_id".to_string()));
+    assert_eq!(sheet.data[1][5], Cell::Int(100));
+    assert_eq!(sheet.data[5][5], Cell::Int(104));
+    assert!(sheet.with_row_numbers("row_id", 1).is_err());
+
+    sheet.generate_uuid_col("uid").unwrap();
+    let uuids: std::collections::HashSet<String> = sheet.data[1..]
+        .iter()
+        .map(|row| row[6].to_string())
+        .collect();
+    assert_eq!(uuids.len(), 5);
+    for uuid in &uuids {
+        let groups: Vec<&str> = uuid.split('-').collect();
+        assert_eq!(
+            groups.iter().map(|g| g.len()).collect::<Vec<_>>(),
+            vec![8, 4, 4, 4, 12]
+        );
+        assert!(groups[2].starts_with('4'));
+        assert!(matches!(groups[3].as_bytes()[0], b'8' | b'9' | b'a' | b'b'));
+    }
+    assert!(sheet.generate_uuid_col("uid").is_err());
+}
+
+#[test]
+fn test_sample_weighted() {
+    let sheet = Sheet::load_data_from_str("id,weight\n1,1\n2,9\n3,0\n4,\n5,0.5");
+
+    let mut heavy = 0;
+    for seed in 0..1000 {
+        let sample = sheet.sample_weighted(1, "weight", seed).unwrap();
+        assert_eq!(sample.data.len(), 2);
+        if sample.data[1][0] == Cell::Int(2) {
+            heavy += 1;
+        }
+    }
+    // the row of weight 9 is drawn with a 9 / 10.5 probability
+    assert!((800..915).contains(&heavy));
+
+    let sample = sheet.sample_weighted(3, "weight", 1).unwrap();
+    let ids: Vec<Cell> = sample.data[1..].iter().map(|row| row[0].clone()).collect();
+    assert_eq!(ids, vec![Cell::Int(1), Cell::Int(2), Cell::Int(5)]);
+    assert_eq!(
+        sheet.sample_weighted(2, "weight", 3).unwrap().data[1..]
+            .iter()
+            .map(|row| row[0].clone())
+            .collect::<Vec<_>>(),
+        sheet.sample_weighted(2, "weight", 3).unwrap().data[1..]
+            .iter()
+            .map(|row| row[0].clone())
+            .collect::<Vec<_>>()
+    );
+
+    assert!(sheet.sample_weighted(4, "weight", 1).is_err());
+    assert!(sheet.sample_weighted(1, "id ", 1).is_err());
+    let negative = Sheet::load_data_from_str("id,weight\n1,-1");
+    assert!(negative.sample_weighted(1, "weight", 1).is_err());
+}
+
+#[test]
+fn test_sniff() {
+    let dialect = Sheet::sniff_str(STR_DATA);
+    assert_eq!(dialect, Dialect::default());
+
+    let dialect = Sheet::sniff_str("1\t'old'\t3.5\r2\t'her'\t4.2\r3\t'easy'\t1.0");
+    assert_eq!(
+        dialect,
+        Dialect {
+            delimiter: '\t',
+            quote: Some('\''),
+            has_header: false,
+            line_terminator: LineTerminator::Cr,
+        }
+    );
+
+    // commas within quotes don't make it a comma separated file
+    let dialect = Sheet::sniff_str("code|label\nFR|\"France, metropolitan\"\nDE|Germany\n");
+    assert_eq!(dialect.delimiter, '|');
+    assert!(dialect.has_header);
+    assert_eq!(dialect.load_options().trim, TrimPolicy::UnquotedOnly);
+
+    let path = std::env::temp_dir().join("datatroll_test_sniff.txt");
+    std::fs::write(&path, "title;review\nold;3,5\nher;4,2\n").unwrap();
+    let dialect = Sheet::sniff(path.to_str().unwrap()).unwrap();
+    assert_eq!(dialect.delimiter, ';');
+    std::fs::remove_file(&path).unwrap();
+    assert!(Sheet::sniff("missing.csv").is_err());
+}
+
+#[test]
+fn test_sample_per_group() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+
+    let sample = sheet.sample_per_group("director", 1, 9).unwrap();
+    let directors: Vec<String> = sample.data[1..]
+        .iter()
+        .map(|row| row[2].to_string())
+        .collect();
+    assert_eq!(directors, vec!["quintin", "scorces", "nolan", "martin"]);
+
+    let sample = sheet.sample_per_group("director", 2, 9).unwrap();
+    assert_eq!(sample.data.len(), 6);
+    for (got, want) in sample.data.iter().zip(&sheet.data) {
+        assert_sheet_row(got, want);
+    }
+
+    let first = sheet.sample_per_group("director", 1, 3).unwrap();
+    let again = sheet.sample_per_group("director", 1, 3).unwrap();
+    assert!(first.data.iter().zip(&again.data).all(|(a, b)| **a == **b));
+    assert_eq!(
+        sheet.sample_per_group("director", 0, 3).unwrap().data.len(),
+        1
+    );
+    assert!(sheet.sample_per_group("studio", 1, 3).is_err());
+}
+
+#[test]
+fn test_coerce_types() {
+    let options = LoadOptions {
+        trim: TrimPolicy::None,
+        ..Default::default()
+    };
+    let mut sheet = Sheet::load_data_from_str_with(
+        "id,review,seen,label\n1,3.5,true,a\n2, 4 ,yes,2\n3,n/a,0,c\n4,5,false,d",
+        &options,
+    )
+    .unwrap();
+
+    let report = sheet.coerce_types();
+    assert_eq!(sheet.data[2][1], Cell::Int(4));
+    assert_eq!(sheet.data[3][1], Cell::Null);
+    assert_eq!(sheet.data[2][2], Cell::Bool(true));
+    assert_eq!(sheet.data[3][2], Cell::Bool(false));
+    assert_eq!(sheet.data[2][3], Cell::String("2".to_string()));
+
+    assert_eq!(report.columns.len(), 3);
+    assert_eq!(report.columns[0].target, CoercionTarget::Number);
+    assert_eq!(
+        report.columns[0].coerced,
+        vec![(2, Cell::String(" 4 ".to_string()))]
+    );
+    assert_eq!(
+        report.to_string(),
+        "review: 1 coerced to number, 1 failed ('n/a' at row 3)\n\
+         seen: 2 coerced to bool, 0 failed\n\
+         label: 1 coerced to string, 0 failed"
+    );
+    assert!(sheet.coerce_types().is_empty());
+}
+
+#[test]
+fn test_apply_named() {
+    let mut sheet = Sheet::load_data_from_str(
+        "title,released,review\n Old ,14 May 11,3.456\nher,,4.2\nEasy,29 Feb 12,",
+    );
+    sheet.apply_named("title", "lower").unwrap();
+    sheet.apply_named(0, "trim").unwrap();
+    sheet.apply_named("review", "round:1").unwrap();
+    sheet.apply_named("review", "fill_null:0").unwrap();
+    sheet.apply_named("released", "to_date:%d %b %y").unwrap();
+
+    assert_eq!(sheet.data[1][0], Cell::String("old".to_string()));
+    assert_eq!(sheet.data[1][1], Cell::String("2011-05-14".to_string()));
+    assert_eq!(sheet.data[2][1], Cell::Null);
+    assert_eq!(sheet.data[3][1], Cell::String("2012-02-29".to_string()));
+    assert_eq!(sheet.data[1][2], Cell::Float(3.5));
+    assert_eq!(sheet.data[3][2], Cell::Int(0));
+
+    // a failing cell leaves the column untouched
+    let mut dates = Sheet::load_data_from_str("day\n2023-02-28\n2023-02-29");
+    assert!(dates.apply_named("day", "to_date:%Y-%m-%d").is_err());
+    assert_eq!(dates.data[1][0], Cell::String("2023-02-28".to_string()));
+    assert!(dates.apply_named("day", "to_date").is_err());
+    assert!(dates.apply_named("day", "reverse").is_err());
+
+    dates.register_transformer("reverse", |cell, _| {
+        Ok(Cell::String(cell.to_string().chars().rev().collect()))
+    });
+    dates.apply_named("day", "reverse").unwrap();
+    assert_eq!(dates.data[1][0], Cell::String("82-20-3202".to_string()));
+    assert!(dates.transformers.names().contains(&"reverse"));
+}
+
+#[test]
+fn test_pipeline() {
+    let dir = std::env::temp_dir().join("datatroll_test_pipeline");
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("movies.csv");
+    std::fs::write(&input, STR_DATA).unwrap();
+    let output = dir.join("summary.json");
+
+    let yaml = format!(
+        "# monthly report
+load:
+  path: '{}'
+  trim: all
+steps:
+  - filter: director != 'scorces'
+  - filter: release date >= 2000 # keeps 3 rows
+  - rename:
+      review: score
+  - sort: {{column: score, ascending: false}}
+  - aggregate:
+      score: [count, mean, max]
+      id: min
+export:
+  - {}
+",
+        input.to_str().unwrap(),
+        output.to_str().unwrap()
+    );
+    let pipeline = Pipeline::from_yaml(&yaml).unwrap();
+    let summary = pipeline.run().unwrap();
+    assert_sheet_row(
+        &summary.data[0],
+        &["score_count", "score_mean", "score_max", "id_min"].map(|s| Cell::String(s.to_string())),
+    );
+    assert_eq!(summary.data[1][0], Cell::Int(3));
+    assert!((summary.data[1][1].to_string().parse::<f64>().unwrap() - 12.7 / 3.0).abs() < 1e-9);
+    assert_eq!(summary.data[1][2], Cell::Float(5.0));
+    assert_eq!(summary.data[1][3], Cell::Float(1.0));
+    assert!(std::fs::read_to_string(&output)
+        .unwrap()
+        .contains("\"score_count\":3"));
+
+    let json = r#"{"steps": [
+        {"filter": "review < 4.5"},
+        {"apply": {"column": "title", "transformer": "upper"}},
+        {"drop": ["id", "release date"]},
+        {"sort": {"column": "title"}}
+    ]}"#;
+    let sheet = Pipeline::from_json(json)
+        .unwrap()
+        .apply(Sheet::load_data_from_str(STR_DATA))
+        .unwrap();
+    assert_eq!(sheet.data.len(), 4);
+    assert_sheet_row(
+        &sheet.data[1],
+        &[
+            Cell::String("EASY".to_string()),
+            Cell::String("scorces".to_string()),
+            Cell::Float(1.0),
+        ],
+    );
+
+    // nulls and values of other kinds match no comparison, like the predicates
+    let directors = "title,director\nold,nolan\nher,\nwho,jonze";
+    let filtered = |expression: &str| {
+        Pipeline::from_yaml(&format!("steps:\n  - filter: {expression}"))
+            .unwrap()
+            .apply(Sheet::load_data_from_str(directors))
+            .unwrap()
+            .row_count()
+    };
+    let sheet = Sheet::load_data_from_str(directors);
+    assert_eq!(filtered("director != 'nolan'"), 1);
+    assert_eq!(
+        sheet
+            .count_where(
+                "director",
+                crate::predicate::ne(Cell::String("nolan".to_string()))
+            )
+            .unwrap(),
+        1
+    );
+    assert_eq!(filtered("director != 5"), 0);
+    assert_eq!(filtered("director == null"), 1);
+    assert_eq!(filtered("director != null"), 2);
+
+    assert!(Pipeline::from_json(r#"{"steps": [{"explode": "title"}]}"#).is_err());
+    assert!(Pipeline::from_json(r#"{"steps": [{"filter": "title ~ old"}]}"#).is_err());
+    assert!(Pipeline::from_yaml("steps:\n  - aggregate: {review: [median]}").is_err());
+    let missing = Pipeline::from_yaml("steps:\n  - keep: [studio]").unwrap();
+    assert!(missing.apply(Sheet::load_data_from_str(STR_DATA)).is_err());
+    assert!(missing.run().is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_parse_yaml() {
+    use crate::json::JsonValue;
+    use crate::yaml::parse_yaml;
+
+    let value = parse_yaml(
+        "a: 1\nb:\n  - x\n  - 'it''s' # comment\n  - name: \"q#\"\n    n: [1, two, {k: ~}]\nc:\n- true\n",
+    )
+    .unwrap();
+    assert_eq!(
+        value,
+        JsonValue::Object(vec![
+            ("a".to_string(), JsonValue::Number(Cell::Int(1))),
+            (
+                "b".to_string(),
+                JsonValue::Array(vec![
+                    JsonValue::String("x".to_string()),
+                    JsonValue::String("it's".to_string()),
+                    JsonValue::Object(vec![
+                        ("name".to_string(), JsonValue::String("q#".to_string())),
+                        (
+                            "n".to_string(),
+                            JsonValue::Array(vec![
+                                JsonValue::Number(Cell::Int(1)),
+                                JsonValue::String("two".to_string()),
+                                JsonValue::Object(vec![("k".to_string(), JsonValue::Null)]),
+                            ])
+                        ),
+                    ]),
+                ])
+            ),
+            (
+                "c".to_string(),
+                JsonValue::Array(vec![JsonValue::Bool(true)])
+            ),
+        ])
+    );
+    assert!(parse_yaml("a: 1\n   b: 2").is_err());
+    assert!(parse_yaml("a: [1, 2").is_err());
+}
+
+#[test]
+fn test_multiline_quoted_cells() {
+    let data = "id,comment\n1,\"line one\nline two, \"\"quoted\"\"\"\r\n2,plain\n";
+    let options = LoadOptions::default();
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.data.len(), 3);
+    assert_eq!(
+        sheet.data[1][1],
+        Cell::String("line one\nline two, \"quoted\"".to_string())
+    );
+    assert_eq!(sheet.data[2][1], Cell::String("plain".to_string()));
+
+    let csv = sheet.to_csv_string();
+    assert_eq!(
+        csv,
+        "id,comment,\n1,\"line one\nline two, \"\"quoted\"\"\",\n2,plain,\n"
+    );
+    let reloaded = Sheet::load_data_from_str_with(&csv, &options).unwrap();
+    assert_eq!(reloaded.data[1][1], sheet.data[1][1]);
+
+    let path = std::env::temp_dir().join("datatroll_test_multiline.csv");
+    std::fs::write(&path, format!("{csv}{csv}")).unwrap();
+    let chunks: Vec<Sheet> = Sheet::stream_csv_with(path.to_str().unwrap(), 1, &options)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(chunks[0].data[1][1], sheet.data[1][1]);
+
+    // quoted values round-trip with the default options
+    let sheet = Sheet::load_data_from_str("id,name\n1,\"a, \"\"b\"\"\"");
+    assert_eq!(sheet.data[1][1], Cell::String("a, \"b\"".to_string()));
+    let csv = sheet.to_csv_string();
+    let reloaded = Sheet::load_data_from_str(&csv);
+    assert_eq!(reloaded.data[1][1], sheet.data[1][1]);
+    assert!(csv.contains("1,\"a, \"\"b\"\"\","));
+}
+
+#[test]
+fn test_find_redundant_cols() {
+    let sheet = Sheet::load_data_from_str(
+        "id,celsius,fahrenheit,noisy,city,town\n1,10,50,3,oran,oran\n2,20,68,1,algiers,algiers\n3,30,86,,paris,paris\n4,15,59,9,rome,rome",
+    );
+
+    let redundant = sheet.find_redundant_cols(0.95).unwrap();
+    let pairs: Vec<(&str, &str)> = redundant
+        .iter()
+        .map(|r| (r.column.as_str(), r.duplicate_of.as_str()))
+        .collect();
+    assert_eq!(pairs, vec![("fahrenheit", "celsius"), ("town", "city")]);
+    assert!((redundant[0].correlation - 1.0).abs() < 1e-9);
+
+    assert!(sheet.find_redundant_cols(1.5).is_err());
+}
+
+#[test]
+fn test_quote_and_escape_chars() {
+    let options = LoadOptions {
+        trim: TrimPolicy::UnquotedOnly,
+        quote_char: '\'',
+        escape_char: Some('\\'),
+        ..LoadOptions::default()
+    };
+    let sheet =
+        Sheet::load_data_from_str_with("id,name\n1,'o\\'brien, pat'\n2,\"plain\"", &options)
+            .unwrap();
+    assert_eq!(sheet.data[1][1], Cell::String("o'brien, pat".to_string()));
+    assert_eq!(sheet.data[2][1], Cell::String("\"plain\"".to_string()));
+
+    let export = ExportOptions {
+        quote_char: '\'',
+        escape_char: Some('\\'),
+        ..ExportOptions::default()
+    };
+    let mut out = Vec::new();
+    sheet.export_to_writer_with(&mut out, &export).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(text, "id,name\n1,'o\\'brien, pat'\n2,\"plain\"\n");
+
+    let reloaded = Sheet::load_data_from_str_with(&text, &options).unwrap();
+    assert_eq!(reloaded.data[1][1], sheet.data[1][1]);
+}
+
+#[test]
+fn test_comment_lines() {
+    let data = "# instrument: spectrometer\n# units: nm\nid,wavelength\n1,532.0\n# recalibrated\n2,633.8\n";
+    let options = LoadOptions {
+        comment_char: Some('#'),
+        ..LoadOptions::default()
+    };
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.data.len(), 3);
+    assert_eq!(sheet.data[0][1], Cell::String("wavelength".to_string()));
+    assert_eq!(sheet.data[2][1], Cell::Float(633.8));
+    assert_eq!(
+        sheet.comments,
+        vec!["instrument: spectrometer", "units: nm", "recalibrated"]
+    );
+
+    let path = std::env::temp_dir().join("datatroll_test_comments.csv");
+    std::fs::write(&path, data).unwrap();
+    let chunks: Vec<Sheet> = Sheet::stream_csv_with(path.to_str().unwrap(), 1, &options)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[1].data[1][0], Cell::Int(2));
+
+    let plain = Sheet::load_data_from_str(data);
+    assert!(plain.comments.is_empty());
+    assert_eq!(plain.data.len(), 6);
+}
+
+#[test]
+fn test_group_summary() {
+    let sheet = Sheet::load_data_from_str(STR_DATA);
+    let summary = sheet.group_summary("director").unwrap();
+
+    // 4 directors, 3 numeric columns (id, release date, review) and 6 statistics
+    assert_eq!(summary.data.len(), 1 + 4 * 3 * 6);
+    let find = |group: &str, column: &str, statistic: &str| {
+        summary
+            .data
+            .iter()
+            .find(|row| {
+                row[0] == Cell::String(group.to_string())
+                    && row[1] == Cell::String(column.to_string())
+                    && row[2] == Cell::String(statistic.to_string())
+            })
+            .map(|row| row[3].clone())
+            .unwrap()
+    };
+    assert_eq!(find("quintin", "review", "count"), Cell::Int(2));
+    assert_eq!(find("quintin", "review", "min"), Cell::Float(3.5));
+    assert_eq!(find("quintin", "release date", "sum"), Cell::Float(4024.0));
+    assert_eq!(find("nolan", "review", "variance"), Cell::Float(0.0));
+    assert!(sheet.group_summary("studio").is_err());
+}
+
+#[test]
+fn test_cell_styles() {
+    let mut sheet =
+        Sheet::load_data_from_str("title,review,votes\nold,3.5,1200\nher,1.0,\nwho,9.5,35000");
+    sheet.add_validator(
+        "review",
+        |c| matches!(c, Cell::Float(r) if (0.0..=5.0).contains(r)),
+    );
+    let low = CellStyle {
+        highlight: Some("#FFC7CE".to_string()),
+        ..Default::default()
+    };
+    sheet.style_where("review", |c| matches!(c, Cell::Float(r) if *r < 2.0), low);
+    sheet.style_invalid(CellStyle {
+        highlight: Some("#FFEB9C".to_string()),
+        number_format: Some("0.00".to_string()),
+    });
+    sheet.style_col(
+        "votes",
+        CellStyle {
+            number_format: Some("#,##0".to_string()),
+            ..Default::default()
+        },
+    );
+
+    assert!(sheet.cell_style(0, 1).is_none());
+    assert!(sheet.cell_style(1, 1).is_none());
+    assert_eq!(
+        sheet.cell_style(3, 1),
+        Some(CellStyle {
+            highlight: Some("#FFEB9C".to_string()),
+            number_format: Some("0.00".to_string()),
+        })
+    );
+
+    let html = sheet.to_html();
+    assert!(html.contains("<td style=\"background-color: #FFC7CE\">1</td>"));
+    assert!(html.contains("<td style=\"background-color: #FFEB9C\">9.50</td>"));
+    assert!(html.contains("<td>1,200</td>"));
+    assert!(html.contains("<td>35,000</td>"));
+    assert!(html.contains("<td></td>"));
+
+    let path = std::env::temp_dir().join("datatroll_test_styles.html");
+    let path = path.to_str().unwrap();
+    sheet.export_html(path).unwrap();
+    assert_eq!(std::fs::read_to_string(path).unwrap(), html);
+    assert!(sheet.export_html("report.htm").is_err());
+
+    #[cfg(feature = "xlsx")]
+    {
+        let path = std::env::temp_dir().join("datatroll_test_styles.xlsx");
+        let path = path.to_str().unwrap();
+        sheet.export_xlsx(path, "reviews").unwrap();
+        let loaded = Sheet::load_xlsx(path, 0).unwrap();
+        assert_eq!(loaded.data[3][1], Cell::Float(9.5));
+    }
+}
+
+#[test]
+fn test_bom_is_stripped() {
+    let data = "\u{feff}id,title\n1,old\n";
+    let sheet = Sheet::load_data_from_str(data);
+    assert_eq!(sheet.data[0][0], Cell::String("id".to_string()));
+    assert_eq!(sheet.filter("id", |c| *c == Cell::Int(1)).len(), 1);
+
+    let path = std::env::temp_dir().join("datatroll_test_bom.csv");
+    let path = path.to_str().unwrap();
+    let excel = ExportOptions::profile("excel").unwrap();
+    sheet.export_with(path, &excel).unwrap();
+    assert!(std::fs::read(path)
+        .unwrap()
+        .starts_with(b"\xef\xbb\xbfid,title\r\n"));
+    let reloaded = Sheet::load_data(path).unwrap();
+    assert_eq!(reloaded.data[0][0], Cell::String("id".to_string()));
+
+    let json = Sheet::load_json_from_str("\u{feff}[{\"id\": 1}]").unwrap();
+    assert_eq!(json.data[1][0], Cell::Int(1));
+    assert_eq!(Sheet::sniff_str("\u{feff}id;title\n1;old\n").delimiter, ';');
+}
+
+#[test]
+fn test_text_encodings() {
+    let latin1: &[u8] = b"id,city\n1,Montr\xe9al\n";
+    let options = LoadOptions {
+        encoding: TextEncoding::Latin1,
+        ..LoadOptions::default()
+    };
+    assert!(Sheet::load_from_reader(latin1).is_err());
+
+    #[cfg(not(feature = "encoding"))]
+    assert!(Sheet::load_from_reader_with(latin1, &options).is_err());
+
+    #[cfg(feature = "encoding")]
+    {
+        let sheet = Sheet::load_from_reader_with(latin1, &options).unwrap();
+        assert_eq!(sheet.data[1][1], Cell::String("Montréal".to_string()));
+
+        let mut utf16 = vec![0xff, 0xfe];
+        utf16.extend("id,price\n1,5€\n".encode_utf16().flat_map(u16::to_le_bytes));
+        let options = LoadOptions {
+            encoding: TextEncoding::Utf16Le,
+            ..LoadOptions::default()
+        };
+        let sheet = Sheet::load_from_reader_with(utf16.as_slice(), &options).unwrap();
+        assert_eq!(sheet.data[0][0], Cell::String("id".to_string()));
+        assert_eq!(sheet.data[1][1], Cell::String("5€".to_string()));
+
+        let export = ExportOptions {
+            encoding: TextEncoding::Windows1252,
+            ..ExportOptions::default()
+        };
+        let mut out = Vec::new();
+        sheet.export_to_writer_with(&mut out, &export).unwrap();
+        assert_eq!(out, b"id,price\n1,5\x80\n");
+
+        let latin1_export = ExportOptions {
+            encoding: TextEncoding::Latin1,
+            ..ExportOptions::default()
+        };
+        assert!(sheet
+            .export_to_writer_with(Vec::new(), &latin1_export)
+            .is_err());
+    }
+}
+
+#[test]
+fn test_views_and_mutators() {
+    let mut sheet = Sheet::load_data_from_str(STR_DATA);
+    assert_eq!((sheet.row_count(), sheet.col_count()), (5, 5));
+    assert_eq!(
+        sheet.header().unwrap()[1],
+        Cell::String("title".to_string())
+    );
+    assert_eq!(sheet.row(4).unwrap()[1], Cell::String("who".to_string()));
+    assert!(sheet.row(5).is_none());
+    assert_eq!(sheet.as_slices().len(), 6);
+
+    let names: Vec<String> = sheet.columns().map(|c| c.name()).collect();
+    assert_eq!(names[3], "release date");
+    let review = sheet.column("review").unwrap();
+    assert_eq!(review.index(), 4);
+    assert_eq!(review.len(), 5);
+    assert_eq!(review.iter().filter(|c| **c == Cell::Float(5.0)).count(), 1);
+    assert!(sheet.column("studio").is_err());
+
+    // rows shorter than the header read as null
+    let mut short = Sheet::load_data_from_str("id,review\n1,3.5\n2,4.0");
+    short.data[2].pop();
+    let review = short.column("review").unwrap();
+    assert_eq!(review.get(1), Some(&Cell::Null));
+    assert_eq!(review.iter().nth(1), Some(&Cell::Null));
+
+    sheet.add_validator(
+        "review",
+        |c| matches!(c, Cell::Float(r) if (0.0..=5.0).contains(r)),
+    );
+    sheet.set_cell(0, "review", Cell::Float(2.5)).unwrap();
+    assert!(sheet.set_cell(0, "review", Cell::Float(7.0)).is_err());
+    assert!(sheet.set_cell(5, "review", Cell::Float(1.0)).is_err());
+    assert_eq!(sheet.rows().next().unwrap()[4], Cell::Float(2.5));
+
+    let removed = sheet.remove_row(0).unwrap();
+    assert_eq!(removed[0], Cell::Int(1));
+    sheet.retain_rows(|row| row[2] != Cell::String("quintin".to_string()));
+    assert_eq!(sheet.row_count(), 3);
+    assert!(sheet.remove_row(3).is_err());
+
+    sheet.rename_col("review", "score").unwrap();
+    assert!(sheet.rename_col("score", "title").is_err());
+    assert_eq!(sheet.column("score").unwrap().name(), "score");
+
+    let built = Sheet::from_rows(&["a", "b"], vec![vec![Cell::Int(1), Cell::Null]]).unwrap();
+    assert_eq!(built.row(0).unwrap(), &[Cell::Int(1), Cell::Null]);
+    assert!(Sheet::from_rows(&["a", "a"], Vec::new()).is_err());
+}
+
+#[test]
+fn test_sheet_generator() {
+    let generator = SheetGenerator::new()
+        .seed(42)
+        .col_int("id", 1..)
+        .col_int("year", 1990..=2020)
+        .col_choice("director", &["nolan", "martin"])
+        .col_float_normal("review", 3.5, 1.0)
+        .col_float_uniform("share", 0.0, 1.0)
+        .col_bool("watched", 0.25);
+    let sheet = generator.rows(2_000);
+
+    assert_eq!((sheet.row_count(), sheet.col_count()), (2_000, 6));
+    assert_eq!(
+        sheet.header().unwrap()[2],
+        Cell::String("director".to_string())
+    );
+    assert_eq!(sheet.row(0).unwrap()[0], Cell::Int(1));
+    assert!(sheet
+        .column("year")
+        .unwrap()
+        .iter()
+        .all(|c| matches!(c, Cell::Int(y) if (1990..=2020).contains(y))));
+    assert!(sheet
+        .column("share")
+        .unwrap()
+        .iter()
+        .all(|c| matches!(c, Cell::Float(f) if (0.0..1.0).contains(f))));
+    let mean = sheet.mean("review").unwrap();
+    assert!((mean - 3.5).abs() < 0.1, "mean {mean}");
+    let watched = sheet
+        .count_where("watched", |c| *c == Cell::Bool(true))
+        .unwrap();
+    assert!((400..600).contains(&watched), "watched {watched}");
+
+    let again = generator.rows(2_000);
+    assert!(sheet.rows().zip(again.rows()).all(|(a, b)| a == b));
+    let other = generator.clone().seed(7).rows(10);
+    assert!(other.rows().zip(sheet.rows()).any(|(a, b)| a != b));
+}
+
+#[test]
+fn test_bulk_load_and_column_scans() {
+    let sheet = Sheet::load_from_bytes(STR_DATA.as_bytes(), &LoadOptions::default()).unwrap();
+    assert_eq!(sheet.row_count(), 5);
+    assert!(Sheet::load_from_bytes(b"id\n\xff", &LoadOptions::default()).is_err());
+
+    let review = sheet.column("review").unwrap();
+    assert_eq!(review.floats().flatten().sum::<f64>(), 18.4);
+    let titles: Vec<Option<&str>> = sheet.column("title").unwrap().strs().collect();
+    assert_eq!(titles[0], Some("old"));
+    assert!(sheet.column("id").unwrap().strs().all(|s| s.is_none()));
+}
+
+#[test]
+fn test_parse_modes() {
+    let data = "id,title,review,watched\n1,old,3.5,true\n# skipped\n2,\"two\nlines\",n/a,false\n3,her,4.2,yes\n4,hey,4.7,true\n";
+    let sheet = Sheet::load_data_from_str(data);
+    assert_eq!(sheet.data[3][2], Cell::String("n/a".to_string()));
+
+    let strict = LoadOptions {
+        comment_char: Some('#'),
+        parse_mode: ParseMode::Strict,
+        ..Default::default()
+    };
+    let err = Sheet::load_data_from_str_with(data, &strict).unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap();
+    assert_eq!(err.issues.len(), 2);
+    assert_eq!(
+        err.to_string(),
+        "2 malformed values\n\
+         line 4, column 'review': expected a number, found 'n/a'\n\
+         line 6, column 'watched': expected a bool, found 'yes'"
+    );
+
+    let lenient = LoadOptions {
+        parse_mode: ParseMode::Lenient,
+        ..strict.clone()
+    };
+    let sheet = Sheet::load_data_from_str_with(data, &lenient).unwrap();
+    assert_eq!(sheet.data[2][2], Cell::Null);
+    assert_eq!(sheet.data[3][3], Cell::Null);
+    assert_eq!(sheet.data[4][2], Cell::Float(4.7));
+    assert_eq!(sheet.parse_warnings.len(), 2);
+    assert_eq!(sheet.parse_warnings[0].line, 4);
+    assert_eq!(sheet.parse_warnings[0].token, "n/a");
+
+    let clean = "id,review\n1,3.5\n2,\n";
+    assert!(Sheet::load_data_from_str_with(clean, &strict).is_ok());
+}
+
+#[test]
+fn test_standardize_values() {
+    let mut sheet = Sheet::load_data_from_str(
+        "id,country\n1,USA\n2, u.s. \n3,United States\n4,US\n5,\n6,Mars\n7,mars\n8,Mars",
+    );
+    let mapping = std::collections::HashMap::from([
+        ("USA".to_string(), "US".to_string()),
+        ("U.S.".to_string(), "US".to_string()),
+        ("United States".to_string(), "US".to_string()),
+    ]);
+    let report = sheet.standardize_values("country", &mapping).unwrap();
+    assert_eq!(report.mapped, 3);
+    assert_eq!(
+        report.unmapped,
+        vec![("Mars".to_string(), 2), ("mars".to_string(), 1)]
+    );
+    assert!(!report.is_complete());
+    for i in 1..=4 {
+        assert_eq!(sheet.data[i][1], Cell::String("US".to_string()));
+    }
+    assert_eq!(sheet.data[5][1], Cell::Null);
+
+    let planets = Sheet::load_data_from_str("name,alias\nMars,the red planet");
+    let report = sheet.standardize_values(1, &planets).unwrap();
+    assert_eq!(report.mapped, 1);
+    assert_eq!(report.unmapped, vec![("US".to_string(), 4)]);
+    assert_eq!(sheet.data[7][1], Cell::String("Mars".to_string()));
+
+    assert!(sheet.standardize_values("region", &mapping).is_err());
+    assert!(sheet
+        .standardize_values("country", &Sheet::new_sheet())
+        .is_err());
+}
+
+#[test]
+fn test_headerless_load() {
+    let data = "1,old,3.5\n2,her,4.5\n3,easy,1.0,extra\n";
+    let options = LoadOptions::default().has_header(false);
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.row_count(), 3);
+    let header: Vec<String> = sheet.data[0].iter().map(|c| c.to_string()).collect();
+    assert_eq!(header, vec!["col_0", "col_1", "col_2", "col_3"]);
+    assert_eq!(sheet.data[1][0], Cell::Int(1));
+    assert_eq!(sheet.data[1][3], Cell::Null);
+    assert_eq!(sheet.mean("col_2").unwrap(), 3.0);
+
+    let dialect = Sheet::sniff_str(data);
+    assert!(!dialect.has_header);
+    assert!(!dialect.load_options().header);
+
+    let path = std::env::temp_dir().join("datatroll_test_headerless_stream.csv");
+    let path = path.to_str().unwrap();
+    std::fs::write(path, data).unwrap();
+    let chunks: Vec<Sheet> = Sheet::stream_csv_with(path, 2, &options)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].data[0][2], Cell::String("col_2".to_string()));
+    assert_eq!(chunks[0].data[1][1], Cell::String("old".to_string()));
+    assert_eq!(chunks[1].data[1][0], Cell::Int(3));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_predicates() {
+    use crate::predicate::{eq, ge, gt, is_not_null, is_null, le, lt, ne, PredicateExt};
+
+    let sheet = Sheet::load_data_from_str(
+        "title,review,watched\nold,3.5,true\nher,,false\neasy,1,\nhey,4.7,true\nwho,n/a,false",
+    );
+    assert_eq!(sheet.filter("review", gt(Cell::Int(3))).len(), 2);
+    assert_eq!(sheet.filter("review", le(Cell::Float(3.5))).len(), 2);
+    assert_eq!(sheet.filter("review", eq(Cell::Float(1.0))).len(), 1);
+    // nulls and values of another kind never compare
+    assert_eq!(sheet.filter("review", ne(Cell::Int(1))).len(), 2);
+    assert_eq!(sheet.filter("review", eq(Cell::Null)).len(), 0);
+    assert_eq!(sheet.filter("review", ne(Cell::Null)).len(), 0);
+    assert_eq!(sheet.filter("review", ne(Cell::Float(f64::NAN))).len(), 0);
+    assert_eq!(sheet.filter("review", is_null()).len(), 1);
+    assert_eq!(sheet.filter("review", is_not_null()).len(), 4);
+
+    assert_eq!(sheet.filter("watched", eq(Cell::Bool(true))).len(), 2);
+    assert_eq!(sheet.filter("watched", ne(Cell::Bool(true))).len(), 2);
+    assert_eq!(sheet.filter("watched", gt(Cell::Bool(false))).len(), 0);
+
+    let mid = ge(Cell::Int(1)).and(lt(Cell::Int(4)));
+    assert_eq!(sheet.filter("review", &mid).len(), 2);
+    assert_eq!(sheet.filter("review", mid.not()).len(), 3);
+    let outliers = lt(Cell::Int(2)).or(gt(Cell::Float(4.5))).or(is_null());
+    assert_eq!(sheet.filter("review", outliers).len(), 3);
+    assert_eq!(
+        sheet
+            .filter("title", ge(Cell::String("hey".to_string())))
+            .len(),
+        3
+    );
+
+    let mut sheet = sheet;
+    sheet.add_validator(
+        "review",
+        is_null().or(ge(Cell::Int(0)).and(le(Cell::Int(5)))),
+    );
+    assert!(sheet.set_cell(0, "review", Cell::Float(9.0)).is_err());
+    assert!(sheet.set_cell(0, "review", Cell::Null).is_ok());
+}
+
+#[test]
+fn test_skip_rows_and_footer() {
+    let data = format!("Movies export\n==========\n{STR_DATA}\ntotal,,,,18.4\n\n");
+    let options = LoadOptions::default().skip_rows(2).skip_footer(2);
+    let sheet = Sheet::load_data_from_str_with(&data, &options).unwrap();
+    let whole = Sheet::load_data_from_str(STR_DATA);
+    assert_eq!(sheet.data.len(), whole.data.len());
+    for (got, want) in sheet.data.iter().zip(&whole.data) {
+        assert_sheet_row(got, want);
+    }
+
+    // lines are reported as in the input, skipped lines included
+    let strict = LoadOptions {
+        parse_mode: ParseMode::Strict,
+        ..LoadOptions::default().skip_rows(1)
+    };
+    let err = Sheet::load_data_from_str_with("banner\nid,review\n1,3.5\n2,n/a\n3,4.0", &strict)
+        .unwrap_err();
+    assert_eq!(err.downcast::<ParseError>().unwrap().issues[0].line, 4);
+
+    let too_many = LoadOptions::default().skip_rows(10);
+    assert_eq!(
+        Sheet::load_data_from_str_with(&data, &too_many)
+            .unwrap()
+            .data
+            .len(),
+        0
+    );
+    let too_many = LoadOptions::default().skip_footer(10);
+    assert_eq!(
+        Sheet::load_data_from_str_with(&data, &too_many)
+            .unwrap()
+            .data
+            .len(),
+        0
+    );
+
+    let path = std::env::temp_dir().join("datatroll_test_skip_rows.csv");
+    let path = path.to_str().unwrap();
+    std::fs::write(path, &data).unwrap();
+    let chunks: Vec<Sheet> = Sheet::stream_csv_with(path, 10, &LoadOptions::default().skip_rows(2))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_sheet_row(&chunks[0].data[0], &whole.data[0]);
+    assert_eq!(chunks[0].row_count(), 6);
+    assert!(Sheet::stream_csv_with(path, 10, &options).is_err());
+    std::fs::remove_file(path).unwrap();
+
+    assert!(Pipeline::from_yaml("load: {path: movies.csv, skip_rows: 2, skip_footer: 1}").is_ok());
+    assert!(Pipeline::from_yaml("load: {path: movies.csv, skip_rows: -1}").is_err());
+}
+
+#[test]
+fn test_ragged_rows() {
+    let data = "id,title,review\n1,old,3.5\n2,her\n3,easy,1.0,extra\n";
+    let sheet = Sheet::load_data_from_str(data);
+    assert_eq!(sheet.col_count(), 4);
+    assert_eq!(sheet.data[0][3], Cell::String("col_3".to_string()));
+    assert_eq!(sheet.data[2][2], Cell::Null);
+    assert_eq!(sheet.data[3][3], Cell::String("extra".to_string()));
+    assert!(sheet.data.iter().all(|row| row.len() == 4));
+
+    // generated names don't collide with the header
+    let sheet = Sheet::load_data_from_str("id,col_3,col_2\n1,a,b,c,d");
+    let header: Vec<String> = sheet.data[0].iter().map(|c| c.to_string()).collect();
+    assert_eq!(header, ["id", "col_3", "col_2", "col_3_2", "col_4"]);
+    assert_eq!(
+        sheet.column("col_3_2").unwrap().get(0),
+        Some(&Cell::String("c".to_string()))
+    );
+
+    let truncate = LoadOptions {
+        ragged_rows: RaggedRows::Truncate,
+        ..Default::default()
+    };
+    let sheet = Sheet::load_data_from_str_with(data, &truncate).unwrap();
+    assert_eq!(sheet.col_count(), 3);
+    assert!(sheet.data.iter().all(|row| row.len() == 3));
+    assert_eq!(sheet.data[3][2], Cell::Float(1.0));
+
+    let error = LoadOptions {
+        ragged_rows: RaggedRows::Error,
+        ..Default::default()
+    };
+    let err = Sheet::load_data_from_str_with(data, &error).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 3 holds 2 values, expected 3, along with 1 other ragged row"
+    );
+    assert!(Sheet::load_data_from_str_with(STR_DATA, &error).is_ok());
+
+    let path = std::env::temp_dir().join("datatroll_test_ragged_rows.csv");
+    let path = path.to_str().unwrap();
+    std::fs::write(path, data).unwrap();
+    let err = Sheet::stream_csv_with(path, 1, &error)
+        .unwrap()
+        .find_map(Result::err)
+        .unwrap();
+    assert_eq!(err.to_string(), "line 3 holds 2 values, expected 3");
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_detect_gaps() {
+    let day = std::time::Duration::from_secs(24 * 60 * 60);
+    let sheet = Sheet::load_data_from_str(
+        "day,requests\n2023-12-30,5\n2024-01-02,7\n2023-12-31,6\n,3\n2024-01-02,8\n2024-03-01,9",
+    );
+    let gaps = sheet.detect_gaps("day", day).unwrap();
+    assert_eq!(gaps.len(), 2);
+    assert_eq!(
+        (
+            gaps[0].start.as_str(),
+            gaps[0].end.as_str(),
+            gaps[0].missing
+        ),
+        ("2024-01-01", "2024-01-01", 1)
+    );
+    // 2024 is a leap year
+    assert_eq!(
+        (
+            gaps[1].start.as_str(),
+            gaps[1].end.as_str(),
+            gaps[1].missing
+        ),
+        ("2024-01-03", "2024-02-29", 58)
+    );
+
+    let hour = std::time::Duration::from_secs(60 * 60);
+    let sheet = Sheet::load_data_from_str(
+        "time,level\n2024-01-31T22:00:00Z,1\n2024-01-31 23:00,2\n2024-02-01T02:00:00,3",
+    );
+    let gaps = sheet.detect_gaps(0, hour).unwrap();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].start, "2024-02-01T00:00:00");
+    assert_eq!(gaps[0].end, "2024-02-01T01:00:00");
+    assert_eq!(gaps[0].missing, 2);
+
+    let sheet = Sheet::load_data_from_str("epoch\n0\n86400\n345600");
+    let gaps = sheet.detect_gaps("epoch", day).unwrap();
+    assert_eq!(gaps[0].start, "1970-01-03");
+    assert_eq!(gaps[0].missing, 2);
+
+    assert!(sheet
+        .detect_gaps("epoch", std::time::Duration::ZERO)
+        .is_err());
+    assert!(sheet.detect_gaps("day", day).is_err());
+    let sheet = Sheet::load_data_from_str("day\n2024-02-30");
+    assert!(sheet.detect_gaps("day", day).is_err());
+}
+
+#[test]
+fn test_is_sorted_and_monotonic() {
+    let sheet = Sheet::load_data_from_str("id,total,stock\n1,1,9\n2,2.5,\n3,2.5,7\n4,,8");
+    assert!(sheet.is_sorted("id", true).unwrap());
+    assert!(!sheet.is_sorted("id", false).unwrap());
+    assert!(!sheet.is_sorted("stock", false).unwrap());
+    assert!(sheet.is_monotonic("total").unwrap());
+    assert!(!sheet.is_monotonic("stock").unwrap());
+    assert!(sheet.is_sorted("nope", true).is_err());
+
+    let sorted = sheet.sorted("id", false).unwrap();
+    assert!(sorted.is_sorted("id", false).unwrap());
+    assert_eq!(*sorted.median("id"), Cell::Int(2));
+    assert_eq!(*sheet.median("stock"), Cell::Int(8));
+
+    // ints and floats are ordered by value
+    let mixed = Sheet::load_data_from_str("n,m\n1,3\n2.5,2.5\n3,4");
+    assert!(mixed.is_sorted("n", true).unwrap());
+    assert!(!mixed.is_sorted("m", true).unwrap());
+    assert_eq!(*mixed.median("m"), Cell::Int(3));
+    assert_eq!(*mixed.median("n"), Cell::Float(2.5));
+
+    // sorted keys are merged, with the same rows as the hash join
+    let mut book = Workbook::new();
+    book.insert(
+        "movies",
+        Sheet::load_data_from_str("id,title\n1,old\n2,her\n2,who\n3,easy\n,hey"),
+    );
+    book.insert(
+        "reviews",
+        Sheet::load_data_from_str("id,review\n2,4.2\n2,5.0\n3,1.0\n4,3.0\n,2.0"),
+    );
+    let merged = book.join("movies", "reviews", "id").unwrap();
+    book.insert(
+        "reviews",
+        Sheet::load_data_from_str("id,review\n3,1.0\n2,4.2\n4,3.0\n2,5.0"),
+    );
+    let hashed = book.join("movies", "reviews", "id").unwrap();
+    assert_eq!(merged.row_count(), 5);
+    assert_eq!(merged.to_csv_string(), hashed.to_csv_string());
+}
+
+#[test]
+fn test_thousands_separator() {
+    let data =
+        "id,amount,rate\n1,\"1,234,567\",1.2e6\n2,\"-12,000.25\",3\n3,\"1,23\",4\n4,\"999\",5";
+    let sheet = Sheet::load_data_from_str(data);
+    assert_eq!(sheet.data[1][1], Cell::String("1,234,567".to_string()));
+    assert_eq!(sheet.data[1][2], Cell::Float(1_200_000.0));
+
+    let options = LoadOptions::default().thousands_separator(',');
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.data[1][1], Cell::Int(1_234_567));
+    assert_eq!(sheet.data[2][1], Cell::Float(-12_000.25));
+    // badly grouped and ungrouped values are left as they were
+    assert_eq!(sheet.data[3][1], Cell::String("1,23".to_string()));
+    assert_eq!(sheet.data[4][1], Cell::String("999".to_string()));
+
+    let options = LoadOptions {
+        trim: TrimPolicy::UnquotedOnly,
+        ..LoadOptions::default().thousands_separator(',')
+    };
+    let sheet = Sheet::load_data_from_str_with(data, &options).unwrap();
+    assert_eq!(sheet.data[1][1], Cell::Int(1_234_567));
+    assert_eq!(sheet.data[4][1], Cell::String("999".to_string()));
+
+    let options = LoadOptions::default().thousands_separator('_');
+    let sheet =
+        Sheet::load_data_from_str_with("n\n1_000\n18_446_744_073_709_551_615\n1_0", &options)
+            .unwrap();
+    assert_eq!(sheet.data[1][0], Cell::Int(1000));
+    assert_eq!(sheet.data[2][0], Cell::UInt(u64::MAX));
+    assert_eq!(sheet.data[3][0], Cell::String("1_0".to_string()));
+
+    let options = LoadOptions::default().thousands_separator(',');
+    let sheet = Sheet::load_data_from_str_with("n\n\"--1,000\"\n\"+-1,000\"\n\"+1,000\"", &options)
+        .unwrap();
+    assert_eq!(sheet.data[1][0], Cell::String("--1,000".to_string()));
+    assert_eq!(sheet.data[2][0], Cell::String("+-1,000".to_string()));
+    assert_eq!(sheet.data[3][0], Cell::Int(1000));
+
+    // '.' would turn the decimal mark of 1.5 into a separator
+    let options = LoadOptions::default().thousands_separator('.');
+    assert!(Sheet::load_data_from_str_with("n\n1.5\n1.000", &options).is_err());
+
+    // identifier columns keep their values as strings
+    let options = LoadOptions {
+        scientific_notation_columns: vec![("code".to_string(), ScientificNotation::KeepAsString)],
+        ..LoadOptions::default().thousands_separator(',')
+    };
+    let sheet = Sheet::load_data_from_str_with(
+        "code,amount
+\"1,234\",\"1,234\"",
+        &options,
+    )
+    .unwrap();
+    assert_eq!(sheet.data[1][0], Cell::String("1,234".to_string()));
+    assert_eq!(sheet.data[1][1], Cell::Int(1234));
+
+    assert!(Pipeline::from_yaml("load: {path: a.csv, thousands_separator: _}").is_ok());
+    assert!(Pipeline::from_yaml("load: {path: a.csv, thousands_separator: ab}").is_err());
+    assert!(
+        Pipeline::from_yaml("load: {path: a.csv, scientific_notation: keep_as_string}").is_ok()
+    );
+    assert!(Pipeline::from_yaml("load: {path: a.csv, scientific_notation: drop}").is_err());
+}
+
+fn assert_sheet_row(got: &[Cell], want: &[Cell]) {
     assert_eq!(got.len(), want.len());
 
     for i in 0..got.len() {
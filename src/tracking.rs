@@ -0,0 +1,202 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use crate::{CellKey, Row, Sheet};
+
+/// Changes made to a Sheet since [`Sheet::begin_tracking`], as returned by [`Sheet::changes`].
+///
+/// Its `Display` implementation renders a short human-readable summary, meant for pipeline logs.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChangeSummary {
+    /// names of the columns that weren't in the header when tracking began
+    pub columns_added: Vec<String>,
+    /// names of the columns that are no longer in the header
+    pub columns_removed: Vec<String>,
+    /// number of rows that were added
+    pub rows_added: usize,
+    /// number of rows that were removed
+    pub rows_removed: usize,
+    /// number of rows with at least one modified cell
+    pub rows_modified: usize,
+    /// number of modified cells of every column, in header order, for columns with changes
+    pub cells_modified: Vec<(String, usize)>,
+}
+
+impl ChangeSummary {
+    /// is_empty tells whether the Sheet is the same as when tracking began
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl fmt::Display for ChangeSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no changes");
+        }
+
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+        write!(
+            f,
+            "{} row{} added, {} row{} removed, {} row{} modified",
+            self.rows_added,
+            plural(self.rows_added),
+            self.rows_removed,
+            plural(self.rows_removed),
+            self.rows_modified,
+            plural(self.rows_modified)
+        )?;
+        if !self.columns_added.is_empty() {
+            write!(f, "\ncolumns added: {}", self.columns_added.join(", "))?;
+        }
+        if !self.columns_removed.is_empty() {
+            write!(f, "\ncolumns removed: {}", self.columns_removed.join(", "))?;
+        }
+        if !self.cells_modified.is_empty() {
+            let cells: Vec<String> = self
+                .cells_modified
+                .iter()
+                .map(|(name, count)| format!("{name} ({count})"))
+                .collect();
+            write!(f, "\ncells modified: {}", cells.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Sheet {
+    /// begin_tracking takes a snapshot of the Sheet, against which [`Sheet::changes`] reports
+    /// what a pipeline did
+    ///
+    /// The snapshot is a copy of every row, so tracking doubles the memory used by the Sheet.
+    /// Calling it again replaces the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,her,4.2\n3,easy,1.0");
+    /// sheet.begin_tracking();
+    ///
+    /// sheet.drop_rows("review", |c| *c == Cell::Float(1.0));
+    /// sheet.fill_col("title", Cell::String("new".to_string())).unwrap();
+    ///
+    /// let changes = sheet.changes().unwrap();
+    /// assert_eq!(changes.rows_removed, 1);
+    /// assert_eq!(
+    ///     changes.to_string(),
+    ///     "0 rows added, 1 row removed, 2 rows modified\ncells modified: title (2)"
+    /// );
+    /// ```
+    #[allow(deprecated)]
+    pub fn begin_tracking(&mut self) {
+        self.tracking = Some(self.data.clone());
+    }
+
+    /// changes summarizes which rows and columns were added, removed or modified since
+    /// [`Sheet::begin_tracking`]
+    ///
+    /// Columns are matched by name, so a renamed column shows as removed and added. Rows are
+    /// compared on the columns present both then and now: rows found unchanged are matched
+    /// first, then the remaining rows are paired in order as modified rows when they agree on at
+    /// least half of those columns, and the rows left over are reported as added or removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if tracking hasn't begun.
+    #[allow(deprecated)]
+    pub fn changes(&self) -> Result<ChangeSummary, Box<dyn Error>> {
+        let snapshot = self
+            .tracking
+            .as_ref()
+            .ok_or("tracking hasn't begun, call begin_tracking first")?;
+
+        let names = |rows: &[Row]| -> Vec<String> {
+            rows.first()
+                .map(|header| header.iter().map(|c| c.to_string()).collect())
+                .unwrap_or_default()
+        };
+        let before = names(snapshot);
+        let after = names(&self.data);
+
+        let mut summary = ChangeSummary {
+            columns_added: after
+                .iter()
+                .filter(|name| !before.contains(name))
+                .cloned()
+                .collect(),
+            columns_removed: before
+                .iter()
+                .filter(|name| !after.contains(name))
+                .cloned()
+                .collect(),
+            ..Default::default()
+        };
+
+        // positions of the shared columns, in the snapshot and in the current header
+        let shared: Vec<(usize, usize)> = before
+            .iter()
+            .enumerate()
+            .filter_map(|(i, name)| after.iter().position(|n| n == name).map(|j| (i, j)))
+            .collect();
+
+        let mut unmatched: HashMap<Vec<CellKey>, Vec<usize>> = HashMap::new();
+        for (i, row) in snapshot.iter().enumerate().skip(1).rev() {
+            let key = shared.iter().map(|(k, _)| CellKey(&row[*k])).collect();
+            unmatched.entry(key).or_default().push(i);
+        }
+        let mut removed = vec![true; snapshot.len()];
+        let mut added = Vec::new();
+        for (i, row) in self.data.iter().enumerate().skip(1) {
+            let key: Vec<CellKey> = shared.iter().map(|(_, k)| CellKey(&row[*k])).collect();
+            match unmatched.get_mut(&key).and_then(|rows| rows.pop()) {
+                Some(old) => removed[old] = false,
+                None => added.push(i),
+            }
+        }
+        let removed: Vec<usize> = (1..snapshot.len()).filter(|i| removed[*i]).collect();
+
+        // the remaining rows are paired in order, as long as they agree on at least half of the
+        // shared columns
+        let mut cells_modified = vec![0; shared.len()];
+        let mut paired = vec![false; removed.len()];
+        let mut first_unpaired = 0;
+        for new in &added {
+            let new_row = &self.data[*new];
+            let candidate = (first_unpaired..removed.len()).find(|k| {
+                let old_row = &snapshot[removed[*k]];
+                let same = shared
+                    .iter()
+                    .filter(|(i, j)| CellKey(&old_row[*i]) == CellKey(&new_row[*j]))
+                    .count();
+                !paired[*k] && same * 2 >= shared.len()
+            });
+            let Some(k) = candidate else {
+                continue;
+            };
+            paired[k] = true;
+            while first_unpaired < removed.len() && paired[first_unpaired] {
+                first_unpaired += 1;
+            }
+
+            summary.rows_modified += 1;
+            for (count, (i, j)) in cells_modified.iter_mut().zip(&shared) {
+                if CellKey(&snapshot[removed[k]][*i]) != CellKey(&new_row[*j]) {
+                    *count += 1;
+                }
+            }
+        }
+
+        summary.rows_added = added.len() - summary.rows_modified;
+        summary.rows_removed = removed.len() - summary.rows_modified;
+        summary.cells_modified = shared
+            .iter()
+            .zip(cells_modified)
+            .filter(|(_, count)| *count > 0)
+            .map(|((_, j), count)| (after[*j].clone(), count))
+            .collect();
+
+        Ok(summary)
+    }
+}
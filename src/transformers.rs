@@ -0,0 +1,262 @@
+use std::{collections::HashMap, error::Error, fmt, sync::Arc};
+
+use crate::{parse_token, reshape::MONTHS, Cell, ColumnRef, Sheet};
+
+/// A transformer function, called with a cell and the argument given after `:` in its spec.
+type TransformerFn = Arc<dyn Fn(&Cell, Option<&str>) -> Result<Cell, Box<dyn Error>> + Send + Sync>;
+
+/// Named cell transformers, applied by name with [`Sheet::apply_named`].
+///
+/// Referring to transformers by name lets cleaning pipelines be defined in configuration files
+/// rather than in Rust code. A transformer is applied through a spec made of its name,
+/// optionally followed by `:` and an argument, such as `"to_date:%d/%m/%Y"`. Every registry
+/// comes with the following transformers:
+///
+/// * `upper`, `lower` and `trim` change the strings of the column, other cells being kept;
+/// * `to_date:<format>` parses strings and ints with a `strftime`-like format made of `%Y`,
+///   `%y`, `%m`, `%b`, `%d` and `%%`, into `YYYY-MM-DD` strings;
+/// * `fill_null:<value>` replaces nulls with the value, whose type is inferred;
+/// * `round:<digits>` rounds floats to the given number of decimal places.
+///
+/// Transformers leave null cells as they are, except for `fill_null`.
+#[derive(Clone)]
+pub struct TransformerRegistry {
+    transformers: HashMap<String, TransformerFn>,
+}
+
+impl Default for TransformerRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            transformers: HashMap::new(),
+        };
+        registry.register("upper", |cell, _| {
+            Ok(map_string(cell, |s| s.to_uppercase()))
+        });
+        registry.register("lower", |cell, _| {
+            Ok(map_string(cell, |s| s.to_lowercase()))
+        });
+        registry.register("trim", |cell, _| {
+            Ok(map_string(cell, |s| s.trim().to_string()))
+        });
+        registry.register("to_date", |cell, format| {
+            let format = format.ok_or("to_date expects a format, such as 'to_date:%Y-%m-%d'")?;
+            match cell {
+                Cell::Null => Ok(Cell::Null),
+                Cell::String(_) | Cell::Int(_) | Cell::UInt(_) => {
+                    let text = cell.to_string();
+                    let (year, month, day) = parse_date(text.trim(), format).ok_or_else(|| {
+                        format!("value '{text}' doesn't match date format '{format}'")
+                    })?;
+                    Ok(Cell::String(format!("{year:04}-{month:02}-{day:02}")))
+                }
+                other => Err(Box::from(format!("value '{other}' is not a date"))),
+            }
+        });
+        registry.register("fill_null", |cell, value| match cell {
+            Cell::Null => Ok(parse_token(value.unwrap_or_default())),
+            other => Ok(other.clone()),
+        });
+        registry.register("round", |cell, digits| {
+            let digits: i32 = digits
+                .ok_or("round expects a number of digits, such as 'round:2'")?
+                .parse()?;
+            match cell {
+                Cell::Float(f) => {
+                    let factor = 10f64.powi(digits);
+                    Ok(Cell::Float((f * factor).round() / factor))
+                }
+                other => Ok(other.clone()),
+            }
+        });
+
+        registry
+    }
+}
+
+impl TransformerRegistry {
+    /// register adds a transformer under `name`, replacing any transformer of the same name
+    ///
+    /// The function receives every cell of the column, header excluded, along with the argument
+    /// given after `:` in the spec, and returns the new cell.
+    pub fn register<F>(&mut self, name: &str, transformer: F)
+    where
+        F: Fn(&Cell, Option<&str>) -> Result<Cell, Box<dyn Error>> + Send + Sync + 'static,
+    {
+        self.transformers
+            .insert(name.to_string(), Arc::new(transformer));
+    }
+
+    /// names returns the names of the registered transformers, sorted
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.transformers.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+impl fmt::Debug for TransformerRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransformerRegistry")
+            .field("names", &self.names())
+            .finish()
+    }
+}
+
+impl Sheet {
+    /// register_transformer adds a named transformer to the Sheet's registry, see
+    /// [`TransformerRegistry::register`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,phone\n1,+33 6 12\n2,06-12");
+    /// sheet.register_transformer("digits", |cell, _| {
+    ///     Ok(Cell::String(cell.to_string().chars().filter(char::is_ascii_digit).collect()))
+    /// });
+    /// sheet.apply_named("phone", "digits").unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::String("33612".to_string()));
+    /// ```
+    pub fn register_transformer<F>(&mut self, name: &str, transformer: F)
+    where
+        F: Fn(&Cell, Option<&str>) -> Result<Cell, Box<dyn Error>> + Send + Sync + 'static,
+    {
+        self.transformers.register(name, transformer);
+    }
+
+    /// apply_named applies a registered transformer to every cell of a column
+    ///
+    /// The spec is the name of the transformer, optionally followed by `:` and its argument, see
+    /// [`TransformerRegistry`] for the built-in transformers. The header row is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the column to transform
+    /// * `spec` - the transformer to apply, such as `"upper"` or `"to_date:%d/%m/%Y"`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, if no transformer is registered under the
+    /// name, or if the transformer fails on a cell, in which case the column is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("title,released\nold,14/05/2011\nher,03/01/2013");
+    /// for (column, spec) in [("title", "upper"), ("released", "to_date:%d/%m/%Y")] {
+    ///     sheet.apply_named(column, spec).unwrap();
+    /// }
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[0], Cell::String("OLD".to_string()));
+    /// assert_eq!(sheet.row(1).unwrap()[1], Cell::String("2013-01-03".to_string()));
+    /// ```
+    #[allow(deprecated)]
+    pub fn apply_named<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        spec: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        let (name, argument) = match spec.split_once(':') {
+            Some((name, argument)) => (name, Some(argument)),
+            None => (spec, None),
+        };
+        let transformer = self
+            .transformers
+            .transformers
+            .get(name)
+            .ok_or_else(|| format!("unknown transformer '{name}'"))?;
+
+        let values = self
+            .data
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, row)| {
+                transformer(&row[col_index], argument).map_err(|err| format!("row '{i}': {err}"))
+            })
+            .collect::<Result<Vec<Cell>, String>>()?;
+        for (row, value) in self.data.iter_mut().skip(1).zip(values) {
+            row[col_index] = value;
+        }
+
+        Ok(())
+    }
+}
+
+/// Applies a function to string cells, leaving other cells as they are.
+fn map_string(cell: &Cell, f: impl Fn(&str) -> String) -> Cell {
+    match cell {
+        Cell::String(s) => Cell::String(f(s)),
+        other => other.clone(),
+    }
+}
+
+/// Parses a date according to a `strftime`-like format, returning its year, month and day.
+fn parse_date(text: &str, format: &str) -> Option<(u32, u32, u32)> {
+    let (mut year, mut month, mut day) = (None, None, Some(1));
+    let mut rest = text;
+    let mut spec = format.chars();
+    while let Some(c) = spec.next() {
+        if c != '%' {
+            rest = rest.strip_prefix(c)?;
+            continue;
+        }
+        match spec.next()? {
+            'Y' => year = Some(take_number(&mut rest, 4, 4)?),
+            'y' => {
+                // the POSIX pivot: 69 to 99 are in the 1900s, 00 to 68 in the 2000s
+                let yy = take_number(&mut rest, 2, 2)?;
+                year = Some(if yy >= 69 { 1900 + yy } else { 2000 + yy });
+            }
+            'm' => month = Some(take_number(&mut rest, 1, 2)?),
+            'd' => day = Some(take_number(&mut rest, 1, 2)?),
+            'b' => {
+                let name = rest.get(..3)?.to_ascii_lowercase();
+                let position = MONTHS.iter().position(|m| m.starts_with(&name))?;
+                month = Some(position as u32 + 1);
+                rest = &rest[3..];
+            }
+            '%' => rest = rest.strip_prefix('%')?,
+            _ => return None,
+        }
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+
+    let (year, month, day) = (year?, month?, day?);
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if !(1..=days).contains(&day) {
+        return None;
+    }
+
+    Some((year, month, day))
+}
+
+/// Takes between `min` and `max` leading digits off `rest`, returning their value.
+fn take_number(rest: &mut &str, min: usize, max: usize) -> Option<u32> {
+    let len = rest
+        .bytes()
+        .take(max)
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    if len < min {
+        return None;
+    }
+    let value = rest[..len].parse().ok()?;
+    *rest = &rest[len..];
+
+    Some(value)
+}
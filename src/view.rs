@@ -0,0 +1,311 @@
+use std::{collections::HashMap, error::Error};
+
+use crate::{Cell, ColumnRef, Row, Sheet};
+
+/// A read-only view over the values of one column of a Sheet, returned by [`Sheet::column`]
+/// and [`Sheet::columns`].
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnView<'a> {
+    sheet: &'a Sheet,
+    index: usize,
+}
+
+impl<'a> ColumnView<'a> {
+    /// name returns the header of the column
+    #[allow(deprecated)]
+    pub fn name(&self) -> String {
+        self.sheet.data[0][self.index].to_string()
+    }
+
+    /// index returns the zero-based position of the column
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// len returns the number of values of the column, header excluded
+    pub fn len(&self) -> usize {
+        self.sheet.row_count()
+    }
+
+    /// is_empty tells whether the column holds no value
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// get returns the value of the column in the data row at `row`, header excluded, null
+    /// when the row is shorter than the header
+    #[allow(deprecated)]
+    pub fn get(&self, row: usize) -> Option<&'a Cell> {
+        self.sheet
+            .data
+            .get(row + 1)
+            .map(|r| r.get(self.index).unwrap_or(&Cell::Null))
+    }
+
+    /// iter iterates over the values of the column, header excluded, yielding null for the rows
+    /// shorter than the header
+    #[allow(deprecated)]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'a Cell> + 'a {
+        let index = self.index;
+        self.sheet
+            .data
+            .iter()
+            .skip(1)
+            .map(move |row| row.get(index).unwrap_or(&Cell::Null))
+    }
+
+    /// floats scans the column as floats, yielding None for nulls and non-numeric values
+    ///
+    /// Ints are converted, so aggregations can be written as a single pass over the column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,review\n1,3.5\n2,\n3,4");
+    /// let total: f64 = sheet.column("review").unwrap().floats().flatten().sum();
+    ///
+    /// assert_eq!(total, 7.5);
+    /// ```
+    pub fn floats(&self) -> impl ExactSizeIterator<Item = Option<f64>> + 'a {
+        self.iter().map(|cell| match cell {
+            Cell::Int(x) => Some(*x as f64),
+            Cell::UInt(u) => Some(*u as f64),
+            Cell::Float(f) => Some(*f),
+            _ => None,
+        })
+    }
+
+    /// strs scans the column as strings, yielding None for every value which isn't a string
+    pub fn strs(&self) -> impl ExactSizeIterator<Item = Option<&'a str>> + 'a {
+        self.iter().map(|cell| match cell {
+            Cell::String(s) => Some(s.as_str()),
+            _ => None,
+        })
+    }
+}
+
+impl Sheet {
+    /// from_rows creates a Sheet out of column names and rows of cells
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if two columns share a name, or if a row doesn't hold one cell per
+    /// column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::from_rows(
+    ///     &["id", "title"],
+    ///     vec![vec![Cell::Int(1), Cell::String("old".to_string())]],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(sheet.row_count(), 1);
+    /// assert!(Sheet::from_rows(&["id", "title"], vec![vec![Cell::Int(1)]]).is_err());
+    /// ```
+    #[allow(deprecated)]
+    pub fn from_rows(header: &[&str], rows: Vec<Vec<Cell>>) -> Result<Self, Box<dyn Error>> {
+        for (i, name) in header.iter().enumerate() {
+            if header[..i].contains(name) {
+                return Err(Box::from(format!("column '{name}' already exists")));
+            }
+        }
+        if let Some(i) = rows.iter().position(|row| row.len() != header.len()) {
+            return Err(Box::from(format!(
+                "row {i} holds {} values, expected {}",
+                rows[i].len(),
+                header.len()
+            )));
+        }
+
+        let mut sheet = Self::new_sheet();
+        sheet.data.push(
+            header
+                .iter()
+                .map(|name| Cell::String(name.to_string()))
+                .collect(),
+        );
+        sheet.data.extend(rows.into_iter().map(Row));
+
+        Ok(sheet)
+    }
+
+    /// header returns the cells of the header row, None for a sheet without data
+    #[allow(deprecated)]
+    pub fn header(&self) -> Option<&[Cell]> {
+        self.data.first().map(|row| row.as_slice())
+    }
+
+    /// row_count returns the number of data rows, header excluded
+    #[allow(deprecated)]
+    pub fn row_count(&self) -> usize {
+        self.data.len().saturating_sub(1)
+    }
+
+    /// col_count returns the number of columns
+    #[allow(deprecated)]
+    pub fn col_count(&self) -> usize {
+        self.data.first().map_or(0, |header| header.len())
+    }
+
+    /// row returns the cells of the data row at `index`, header excluded
+    #[allow(deprecated)]
+    pub fn row(&self, index: usize) -> Option<&[Cell]> {
+        self.data.get(index + 1).map(|row| row.as_slice())
+    }
+
+    /// rows iterates over the data rows, header excluded
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,title\n1,old\n2,her");
+    /// let ids: Vec<&Cell> = sheet.rows().map(|row| &row[0]).collect();
+    ///
+    /// assert_eq!(ids, vec![&Cell::Int(1), &Cell::Int(2)]);
+    /// ```
+    #[allow(deprecated)]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[Cell]> + '_ {
+        self.data.iter().skip(1).map(|row| row.as_slice())
+    }
+
+    /// as_slices returns every row as a slice of cells, the header row first
+    #[allow(deprecated)]
+    pub fn as_slices(&self) -> Vec<&[Cell]> {
+        self.data.iter().map(|row| row.as_slice()).collect()
+    }
+
+    /// column returns a view over the values of a column
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let sheet = Sheet::load_data_from_str("id,review\n1,3.5\n2,4.2");
+    /// let review = sheet.column("review").unwrap();
+    ///
+    /// assert_eq!(review.get(1), Some(&Cell::Float(4.2)));
+    /// assert_eq!(review.iter().count(), 2);
+    /// ```
+    pub fn column<'a>(
+        &self,
+        column: impl Into<ColumnRef<'a>>,
+    ) -> Result<ColumnView<'_>, Box<dyn Error>> {
+        let index = self.col_index(column)?;
+
+        Ok(ColumnView { sheet: self, index })
+    }
+
+    /// columns iterates over views of every column, in header order
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = ColumnView<'_>> + '_ {
+        (0..self.col_count()).map(|index| ColumnView { sheet: self, index })
+    }
+
+    /// set_cell replaces the value at a data row, header excluded, and a column
+    ///
+    /// Unlike writing to the `data` field, the header row can't be overwritten and the sheet's
+    /// validators are checked against the new value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column or the row doesn't exist, or if a validator of the column
+    /// rejects the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,review\n1,3.5");
+    /// sheet.set_cell(0, "review", Cell::Float(4.0)).unwrap();
+    ///
+    /// assert_eq!(sheet.row(0).unwrap()[1], Cell::Float(4.0));
+    /// assert!(sheet.set_cell(1, "review", Cell::Null).is_err());
+    /// ```
+    #[allow(deprecated)]
+    pub fn set_cell<'a>(
+        &mut self,
+        row: usize,
+        column: impl Into<ColumnRef<'a>>,
+        value: Cell,
+    ) -> Result<(), Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        if row >= self.row_count() {
+            return Err(Box::from(format!(
+                "row {row} is out of bounds for a sheet of {} rows",
+                self.row_count()
+            )));
+        }
+
+        for validator in &self.validators {
+            if self.col_index(validator.column()) == Ok(col_index) && !(validator.rule)(&value) {
+                return Err(Box::from(format!(
+                    "value '{value}' in column '{}' failed validation",
+                    validator.column()
+                )));
+            }
+        }
+        self.data[row + 1][col_index] = value;
+
+        Ok(())
+    }
+
+    /// remove_row removes the data row at `index`, header excluded, and returns its cells
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row doesn't exist.
+    #[allow(deprecated)]
+    pub fn remove_row(&mut self, index: usize) -> Result<Vec<Cell>, Box<dyn Error>> {
+        if index >= self.row_count() {
+            return Err(Box::from(format!(
+                "row {index} is out of bounds for a sheet of {} rows",
+                self.row_count()
+            )));
+        }
+
+        Ok(self.data.remove(index + 1).0)
+    }
+
+    /// retain_rows keeps the data rows for which `predicate` returns true, header excluded
+    #[allow(deprecated)]
+    pub fn retain_rows<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&[Cell]) -> bool,
+    {
+        let mut is_header = true;
+        self.data
+            .retain(|row| std::mem::take(&mut is_header) || predicate(row));
+    }
+
+    /// rename_col renames a column
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column doesn't exist, or if another column is already named
+    /// `new_name`.
+    #[allow(deprecated)]
+    pub fn rename_col<'a>(
+        &mut self,
+        column: impl Into<ColumnRef<'a>>,
+        new_name: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let col_index = self.col_index(column)?;
+        let renames = HashMap::from([(self.data[0][col_index].to_string(), new_name.to_string())]);
+        self.rename_cols(&renames)?;
+
+        Ok(())
+    }
+}
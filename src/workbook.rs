@@ -0,0 +1,352 @@
+use std::{collections::HashMap, error::Error, fs, path::Path};
+
+use crate::{sort_order, Cell, CellKey, Row, Sheet};
+
+/// A collection of named Sheets, such as the related tables of a project or the worksheets of
+/// an Excel workbook.
+///
+/// Sheets keep the order they were inserted in, and their names are unique.
+#[derive(Debug, Default)]
+pub struct Workbook {
+    sheets: Vec<(String, Sheet)>,
+}
+
+impl Workbook {
+    /// Creates an empty Workbook.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sheet under the given name, returning the sheet it replaces if the name was
+    /// already taken.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Sheet, Workbook};
+    ///
+    /// let mut book = Workbook::new();
+    /// book.insert("movies", Sheet::load_data_from_str("id,title\n1,old"));
+    ///
+    /// assert_eq!(book.names(), vec!["movies"]);
+    /// ```
+    pub fn insert(&mut self, name: &str, sheet: Sheet) -> Option<Sheet> {
+        match self.sheets.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, sheet)),
+            None => {
+                self.sheets.push((name.to_string(), sheet));
+                None
+            }
+        }
+    }
+
+    /// Returns the sheet with the given name.
+    pub fn get(&self, name: &str) -> Option<&Sheet> {
+        self.sheets.iter().find(|(n, _)| n == name).map(|(_, s)| s)
+    }
+
+    /// Returns a mutable reference to the sheet with the given name.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Sheet> {
+        self.sheets
+            .iter_mut()
+            .find(|(n, _)| n == name)
+            .map(|(_, s)| s)
+    }
+
+    /// Removes the sheet with the given name and returns it.
+    pub fn remove(&mut self, name: &str) -> Option<Sheet> {
+        let i = self.sheets.iter().position(|(n, _)| n == name)?;
+        Some(self.sheets.remove(i).1)
+    }
+
+    /// Returns the names of the sheets, in insertion order.
+    pub fn names(&self) -> Vec<&str> {
+        self.sheets.iter().map(|(n, _)| n.as_str()).collect()
+    }
+
+    /// Returns the number of sheets.
+    pub fn len(&self) -> usize {
+        self.sheets.len()
+    }
+
+    /// Returns true if the workbook holds no sheet.
+    pub fn is_empty(&self) -> bool {
+        self.sheets.is_empty()
+    }
+
+    /// Iterates over the `(name, sheet)` pairs, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Sheet)> {
+        self.sheets.iter().map(|(n, s)| (n.as_str(), s))
+    }
+
+    /// Loads every CSV file of a directory, each one becoming a sheet named after its file
+    /// stem, such as `movies` for `movies.csv`.
+    ///
+    /// Files are loaded in alphabetical order, and files of other formats are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or one of its CSV files can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Workbook;
+    ///
+    /// let book = Workbook::load_dir("tables").unwrap();
+    /// for (name, sheet) in book.iter() {
+    ///     println!("{name}: {} rows", sheet.row_count());
+    /// }
+    /// ```
+    pub fn load_dir(dir: &str) -> Result<Self, Box<dyn Error>> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "csv") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut book = Self::new();
+        for path in paths {
+            let name = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .ok_or_else(|| format!("invalid file name '{}'", path.display()))?;
+            let file_path = path
+                .to_str()
+                .ok_or_else(|| format!("invalid file name '{}'", path.display()))?;
+            book.insert(name, Sheet::load_data(file_path)?);
+        }
+
+        Ok(book)
+    }
+
+    /// Exports every sheet to a CSV file named after it in the given directory, which is
+    /// created if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a sheet name can't be used as a file name, or if the directory or a
+    /// file can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::{Sheet, Workbook};
+    ///
+    /// let mut book = Workbook::new();
+    /// book.insert("movies", Sheet::load_data("movies.csv").unwrap());
+    /// // writes tables/movies.csv
+    /// book.export_dir("tables").unwrap();
+    /// ```
+    pub fn export_dir(&self, dir: &str) -> Result<(), Box<dyn Error>> {
+        fs::create_dir_all(dir)?;
+        for (name, sheet) in &self.sheets {
+            if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+                return Err(Box::from(format!(
+                    "sheet name '{name}' can't be used as a file name"
+                )));
+            }
+            let path = Path::new(dir).join(format!("{name}.csv"));
+            sheet.export(
+                path.to_str()
+                    .ok_or("the provided directory is not valid UTF-8")?,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Joins two sheets of the workbook on a key column they both hold, returning a new sheet.
+    ///
+    /// This is an inner join: every row of `left` is combined with every row of `right` holding
+    /// the same key, and rows without a match on the other side, or with a null key, are left
+    /// out. The result holds the columns of `left` followed by the columns of `right` but the
+    /// key; a column of `right` whose name is already taken is prefixed with the name of the
+    /// sheet, as in `directors.name`.
+    ///
+    /// When both sheets are already sorted on the key, see [`Sheet::is_sorted`], they are merged
+    /// in a single pass rather than through a hash table of `right`.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - the name of the first sheet
+    /// * `right` - the name of the second sheet
+    /// * `on` - the name of the key column, in both sheets
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a sheet doesn't exist, or doesn't hold the key column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet, Workbook};
+    ///
+    /// let mut book = Workbook::new();
+    /// book.insert("movies", Sheet::load_data_from_str("title,director_id\nold,1\nher,2"));
+    /// book.insert("directors", Sheet::load_data_from_str("director_id,name\n1,quintin"));
+    ///
+    /// let joined = book.join("movies", "directors", "director_id").unwrap();
+    /// assert_eq!(joined.row_count(), 1);
+    /// assert_eq!(joined.row(0).unwrap()[2], Cell::String("quintin".to_string()));
+    /// ```
+    #[allow(deprecated)]
+    pub fn join(&self, left: &str, right: &str, on: &str) -> Result<Sheet, Box<dyn Error>> {
+        let sheet = |name: &str| {
+            self.get(name)
+                .ok_or_else(|| format!("workbook has no sheet named '{name}'"))
+        };
+        let (l, r) = (sheet(left)?, sheet(right)?);
+        let (lk, rk) = (l.col_index(on)?, r.col_index(on)?);
+
+        let mut header: Row = l.data[0].clone();
+        let mut right_cols = Vec::new();
+        for (j, name) in r.data[0].iter().enumerate() {
+            if j == rk {
+                continue;
+            }
+            let name = name.to_string();
+            let taken = header.iter().any(|h| h.to_string() == name);
+            header.push(Cell::String(if taken {
+                format!("{right}.{name}")
+            } else {
+                name
+            }));
+            right_cols.push(j);
+        }
+
+        let mut joined = l.shard(&[]);
+        joined.data[0] = header;
+        let mut combine = |row: &Row, other: &Row| {
+            let mut combined = row.clone();
+            combined.extend(right_cols.iter().map(|&j| other[j].clone()));
+            joined.data.push(combined);
+        };
+
+        // NaN keys compare equal to every value, so they can't be merged in key order
+        let ordered = |sheet: &Sheet, k: usize| {
+            sheet.is_sorted(k, true).unwrap_or(false)
+                && !sheet.data[1..]
+                    .iter()
+                    .any(|row| matches!(row[k], Cell::Float(f) if f.is_nan()))
+        };
+        if ordered(l, lk) && ordered(r, rk) {
+            // both sides are already sorted on the key: merge them instead of hashing `right`
+            let others = &r.data[1..];
+            let mut start = 0;
+            for row in l.data.iter().skip(1) {
+                let key = &row[lk];
+                if *key == Cell::Null {
+                    break;
+                }
+                while start < others.len() && sort_order(&others[start][rk], key, true).is_lt() {
+                    start += 1;
+                }
+                for other in others[start..]
+                    .iter()
+                    .take_while(|other| sort_order(&other[rk], key, true).is_eq())
+                    .filter(|other| CellKey(&other[rk]) == CellKey(key))
+                {
+                    combine(row, other);
+                }
+            }
+        } else {
+            let mut matches: HashMap<CellKey, Vec<&Row>> = HashMap::new();
+            for row in r.data.iter().skip(1) {
+                if row[rk] != Cell::Null {
+                    matches.entry(CellKey(&row[rk])).or_default().push(row);
+                }
+            }
+            for row in l.data.iter().skip(1) {
+                for other in matches.get(&CellKey(&row[lk])).into_iter().flatten() {
+                    combine(row, other);
+                }
+            }
+        }
+
+        Ok(joined)
+    }
+}
+
+#[cfg(feature = "xlsx")]
+impl Workbook {
+    /// Loads every worksheet of an Excel (.xlsx) workbook, each one becoming a sheet named
+    /// after it.
+    ///
+    /// Cells are loaded as by [`Sheet::load_xlsx`].
+    ///
+    /// This method is only available with the `xlsx` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".xlsx" extension, or can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Workbook;
+    ///
+    /// let book = Workbook::load_xlsx("report.xlsx").unwrap();
+    /// println!("{:?}", book.names());
+    /// ```
+    pub fn load_xlsx(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        use calamine::{open_workbook, Reader, Xlsx};
+
+        // check for ext
+        if file_path.rsplit('.').next() != Some("xlsx") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let mut workbook: Xlsx<_> = open_workbook(file_path)?;
+        let mut book = Self::new();
+        for (name, range) in workbook.worksheets() {
+            book.insert(&name, Sheet::from_range(&range));
+        }
+
+        Ok(book)
+    }
+
+    /// Exports every sheet to a worksheet named after it, in a single Excel (.xlsx) workbook.
+    /// If the file already exists, it is overwritten.
+    ///
+    /// Cells are written as by [`Sheet::export_xlsx`].
+    ///
+    /// This method is only available with the `xlsx` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".xlsx" extension, a sheet name is invalid
+    /// for Excel, a sheet is too large for a worksheet, or the file can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Workbook;
+    ///
+    /// let book = Workbook::load_dir("tables").unwrap();
+    /// book.export_xlsx("report.xlsx").unwrap();
+    /// ```
+    pub fn export_xlsx(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("xlsx") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        for (name, sheet) in &self.sheets {
+            let worksheet = workbook.add_worksheet();
+            worksheet.set_name(name)?;
+            sheet.write_worksheet(worksheet)?;
+        }
+
+        workbook.save(file_path)?;
+        Ok(())
+    }
+}
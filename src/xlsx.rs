@@ -0,0 +1,157 @@
+use std::error::Error;
+
+use calamine::{open_workbook, Reader, Xlsx};
+use rust_xlsxwriter::{Format, Workbook, Worksheet};
+
+use crate::{Cell, CellStyle, Sheet, WorksheetRef};
+
+/// the largest magnitude up to which every int is exactly representable as an Excel number
+const MAX_EXACT_INT: u64 = 1 << 53;
+
+impl Sheet {
+    /// Loads a worksheet of an Excel (.xlsx) workbook into a Sheet.
+    ///
+    /// Every cell goes through the same type inference as CSV loading, so a worksheet and its
+    /// CSV export produce the same Sheet. Since Excel stores every number as a float, integral
+    /// numbers are loaded as `Cell::Int`. Empty cells become `Cell::Null`, and rows shorter than
+    /// the header are padded with `Cell::Null`.
+    ///
+    /// This method is only available with the `xlsx` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the xlsx file.
+    /// * `worksheet` - The name or zero-based position of the worksheet to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".xlsx" extension, can't be read, or doesn't
+    /// contain the requested worksheet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let by_name = Sheet::load_xlsx("report.xlsx", "movies").unwrap();
+    /// let first = Sheet::load_xlsx("report.xlsx", 0).unwrap();
+    /// ```
+    pub fn load_xlsx<'a>(
+        file_path: &str,
+        worksheet: impl Into<WorksheetRef<'a>>,
+    ) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("xlsx") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let mut workbook: Xlsx<_> = open_workbook(file_path)?;
+        let range = match worksheet.into() {
+            WorksheetRef::Name(name) => workbook.worksheet_range(name)?,
+            WorksheetRef::Index(i) => workbook
+                .worksheet_range_at(i)
+                .ok_or_else(|| format!("could not find worksheet at index '{i}'"))??,
+        };
+
+        Ok(Self::from_range(&range))
+    }
+
+    /// Exports the content of a Sheet to an Excel (.xlsx) workbook.
+    ///
+    /// The workbook holds a single worksheet named `sheet_name`. Cells keep their types: ints
+    /// and floats are written as numbers, bools as booleans and strings as text, while
+    /// `Cell::Null` leaves the cell empty. Excel numbers are doubles, so ints beyond ±2^53 are
+    /// written as text to keep every digit. If the file already exists, it is overwritten.
+    ///
+    /// This method is only available with the `xlsx` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the xlsx file.
+    /// * `sheet_name` - The name of the worksheet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".xlsx" extension, the worksheet name is
+    /// invalid for Excel, the sheet is too large for a worksheet, or the file can't be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("input.csv").unwrap();
+    /// sheet.export_xlsx("report.xlsx", "movies").unwrap();
+    /// ```
+    pub fn export_xlsx(&self, file_path: &str, sheet_name: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("xlsx") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(sheet_name)?;
+        self.write_worksheet(worksheet)?;
+
+        workbook.save(file_path)?;
+        Ok(())
+    }
+
+    /// write_worksheet writes every cell of the sheet to a worksheet, keeping the cell types and
+    /// applying the sheet's styles
+    #[allow(deprecated)]
+    pub(crate) fn write_worksheet(&self, worksheet: &mut Worksheet) -> Result<(), Box<dyn Error>> {
+        for (i, row) in self.data.iter().enumerate() {
+            let r = u32::try_from(i)?;
+            for (j, cell) in row.iter().enumerate() {
+                let c = u16::try_from(j)?;
+                let style = self.cell_style(i, j);
+                let format = &style.as_ref().map(xlsx_format).unwrap_or_default();
+                match cell {
+                    Cell::Null => {
+                        // an empty cell only needs writing to show its highlight
+                        if style.is_some() {
+                            worksheet.write_blank(r, c, format)?;
+                        }
+                    }
+                    Cell::String(s) => {
+                        worksheet.write_string_with_format(r, c, s, format)?;
+                    }
+                    Cell::Bool(b) => {
+                        worksheet.write_boolean_with_format(r, c, *b, format)?;
+                    }
+                    Cell::Int(x) if x.unsigned_abs() <= MAX_EXACT_INT => {
+                        worksheet.write_number_with_format(r, c, *x as f64, format)?;
+                    }
+                    // Excel numbers are doubles, so only a string keeps every digit
+                    Cell::Int(_) | Cell::UInt(_) => {
+                        worksheet.write_string_with_format(r, c, cell.to_string(), format)?;
+                    }
+                    Cell::Float(f) => {
+                        worksheet.write_number_with_format(r, c, *f, format)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// xlsx_format converts a cell style into an Excel cell format
+fn xlsx_format(style: &CellStyle) -> Format {
+    let mut format = Format::new();
+    if let Some(color) = &style.highlight {
+        format = format.set_background_color(color.as_str());
+    }
+    if let Some(number_format) = &style.number_format {
+        format = format.set_num_format(number_format);
+    }
+
+    format
+}
@@ -0,0 +1,465 @@
+use std::{
+    error::Error,
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter, Read, Write},
+};
+
+use crate::{parse_token, Cell, Row, Sheet};
+
+impl Sheet {
+    /// Loads data from an XML file into a Sheet.
+    ///
+    /// Every element named `row_element`, wherever it sits in the document, becomes a row. Its
+    /// attributes and its child elements both become columns, named after the attribute or the
+    /// element. Columns are unioned, in the order they first appear, to build the header row,
+    /// and values missing from a row are filled with `Cell::Null`. Values are typed like the
+    /// values of a CSV file, and empty elements are loaded as `Cell::Null`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the XML file to load.
+    /// * `row_element` - The name of the elements holding the rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".xml" extension, can't be read, or isn't
+    /// well-formed XML.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_xml("movies.xml", "movie").unwrap();
+    /// sheet.pretty_print();
+    /// ```
+    pub fn load_xml(file_path: &str, row_element: &str) -> Result<Self, Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("xml") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let f = File::open(file_path)?;
+        let mut reader = BufReader::new(f);
+        let mut data = String::new();
+
+        reader.read_to_string(&mut data)?;
+
+        Self::load_xml_from_str(&data, row_element)
+    }
+
+    /// Loads data from an XML string into a Sheet.
+    ///
+    /// See [`Sheet::load_xml`] for how elements are mapped to rows and columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't well-formed XML, including closing tags that don't
+    /// match the open element and elements left open at the end of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let xml = r#"<movies>
+    ///     <movie id="1"><title>old</title><review>3.5</review></movie>
+    ///     <movie id="2"><title>her</title></movie>
+    /// </movies>"#;
+    /// let sheet = Sheet::load_xml_from_str(xml, "movie").unwrap();
+    ///
+    /// assert_eq!(sheet.col_count(), 3);
+    /// assert_eq!(sheet.row(0).unwrap()[0], Cell::Int(1));
+    /// assert_eq!(sheet.row(1).unwrap()[2], Cell::Null);
+    /// ```
+    #[allow(deprecated)]
+    pub fn load_xml_from_str(data: &str, row_element: &str) -> Result<Self, Box<dyn Error>> {
+        let mut reader = XmlReader {
+            input: data,
+            pos: 0,
+        };
+        let mut header: Vec<String> = Vec::new();
+        let mut records: Vec<Vec<(usize, Cell)>> = Vec::new();
+
+        // the names of the open elements, the record being built along with the depth of its
+        // element, and the child element whose text is being collected
+        let mut open: Vec<String> = Vec::new();
+        let mut record: Option<(Vec<(usize, Cell)>, usize)> = None;
+        let mut field: Option<(String, String)> = None;
+
+        while let Some(event) = reader.next_event()? {
+            match event {
+                XmlEvent::Start {
+                    name,
+                    attributes,
+                    empty,
+                } => {
+                    let depth = open.len();
+                    match &mut record {
+                        None if name == row_element => {
+                            let mut fields = Vec::new();
+                            for (key, value) in attributes {
+                                fields.push((column(&mut header, key), parse_token(value.trim())));
+                            }
+                            if empty {
+                                records.push(fields);
+                            } else {
+                                record = Some((fields, depth));
+                            }
+                        }
+                        Some((fields, row_depth)) if depth == *row_depth + 1 => {
+                            if empty {
+                                fields.push((column(&mut header, name.clone()), Cell::Null));
+                            } else {
+                                field = Some((name.clone(), String::new()));
+                            }
+                        }
+                        _ => {}
+                    }
+                    if !empty {
+                        open.push(name);
+                    }
+                }
+                XmlEvent::End(name) => {
+                    match open.pop() {
+                        Some(expected) if expected == name => {}
+                        Some(expected) => {
+                            return Err(Box::from(format!(
+                                "closing tag '</{name}>' doesn't match the open '<{expected}>'"
+                            )))
+                        }
+                        None => {
+                            return Err(Box::from(format!(
+                                "closing tag '</{name}>' without an open element"
+                            )))
+                        }
+                    }
+                    let depth = open.len();
+                    let Some((fields, row_depth)) = &mut record else {
+                        continue;
+                    };
+                    if depth == *row_depth {
+                        records.push(std::mem::take(fields));
+                        record = None;
+                    } else if depth == *row_depth + 1 {
+                        if let Some((name, text)) = field.take() {
+                            fields.push((column(&mut header, name), parse_token(text.trim())));
+                        }
+                    }
+                }
+                XmlEvent::Text(text) => {
+                    if let Some((_, buf)) = &mut field {
+                        buf.push_str(&text);
+                    }
+                }
+            }
+        }
+
+        if let Some(name) = open.last() {
+            return Err(Box::from(format!("element '<{name}>' is never closed")));
+        }
+
+        let mut sheet = Self::new_sheet();
+        sheet
+            .data
+            .push(header.iter().cloned().map(Cell::String).collect());
+        for record in records {
+            let mut row: Row = Row(vec![Cell::Null; header.len()]);
+            for (i, cell) in record {
+                row[i] = cell;
+            }
+            sheet.data.push(row);
+        }
+
+        Ok(sheet)
+    }
+
+    /// Exports the content of a Sheet to an XML file.
+    ///
+    /// The rows are written as `row_element` elements inside a single `root` element, with one
+    /// child element per column. Column names are turned into valid element names by replacing
+    /// unsupported characters with `_`, and `Cell::Null` values are written as empty elements.
+    /// If the file already exists, it truncates the file and overwrites its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the XML file.
+    /// * `root` - The name of the document element.
+    /// * `row_element` - The name of the element written for every row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".xml" extension, or can't be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.export_xml("movies.xml", "movies", "movie").unwrap();
+    /// ```
+    pub fn export_xml(
+        &self,
+        file_path: &str,
+        root: &str,
+        row_element: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if file_path.rsplit('.').next() != Some("xml") {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+        let mut buf_writer = BufWriter::new(file);
+        buf_writer.write_all(self.to_xml_string(root, row_element).as_bytes())?;
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+
+    /// to_xml_string renders the document written by [`Sheet::export_xml`]
+    #[allow(deprecated)]
+    pub(crate) fn to_xml_string(&self, root: &str, row_element: &str) -> String {
+        let root = element_name(root);
+        let row_element = element_name(row_element);
+        let names: Vec<String> = self
+            .data
+            .first()
+            .map(|header| {
+                header
+                    .iter()
+                    .map(|c| element_name(&c.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut out = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<{root}>\n");
+        for row in self.data.iter().skip(1) {
+            out.push_str(&format!("  <{row_element}>\n"));
+            for (name, cell) in names.iter().zip(row.iter()) {
+                match cell {
+                    Cell::Null => out.push_str(&format!("    <{name}/>\n")),
+                    cell => out.push_str(&format!(
+                        "    <{name}>{}</{name}>\n",
+                        escape(&cell.to_string())
+                    )),
+                }
+            }
+            out.push_str(&format!("  </{row_element}>\n"));
+        }
+        out.push_str(&format!("</{root}>\n"));
+
+        out
+    }
+}
+
+/// Returns the index of a column in the header, registering it if it's unseen.
+fn column(header: &mut Vec<String>, name: String) -> usize {
+    match header.iter().position(|k| *k == name) {
+        Some(i) => i,
+        None => {
+            header.push(name);
+            header.len() - 1
+        }
+    }
+}
+
+/// Turns a column name into a valid XML element name.
+fn element_name(name: &str) -> String {
+    let mut out: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '_' | '-' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !out.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        out.insert(0, '_');
+    }
+
+    out
+}
+
+/// Escapes the characters that can't appear as-is in XML text or attribute values.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Replaces the predefined and numeric character references of XML text.
+fn unescape(s: &str) -> Result<String, Box<dyn Error>> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(';')
+            .ok_or("unterminated XML character reference")?;
+        let reference = &rest[start + 1..start + end];
+        let c = match reference {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            r if r.starts_with("#x") => u32::from_str_radix(&r[2..], 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("invalid XML character reference '&{r};'"))?,
+            r if r.starts_with('#') => r[1..]
+                .parse::<u32>()
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("invalid XML character reference '&{r};'"))?,
+            r => return Err(Box::from(format!("unknown XML entity '&{r};'"))),
+        };
+        out.push(c);
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+
+    Ok(out)
+}
+
+/// A piece of an XML document.
+enum XmlEvent {
+    /// an opening tag, `empty` being set for self-closing tags
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+        empty: bool,
+    },
+    /// a closing tag
+    End(String),
+    /// character data, with references replaced
+    Text(String),
+}
+
+/// A minimal pull parser over an XML document, skipping comments, processing instructions and
+/// doctype declarations.
+struct XmlReader<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl XmlReader<'_> {
+    fn next_event(&mut self) -> Result<Option<XmlEvent>, Box<dyn Error>> {
+        loop {
+            let rest = &self.input[self.pos..];
+            if rest.is_empty() {
+                return Ok(None);
+            }
+
+            if !rest.starts_with('<') {
+                let end = rest.find('<').unwrap_or(rest.len());
+                self.pos += end;
+                return Ok(Some(XmlEvent::Text(unescape(&rest[..end])?)));
+            }
+
+            if let Some(body) = rest.strip_prefix("<![CDATA[") {
+                let end = body.find("]]>").ok_or("unterminated CDATA section")?;
+                self.pos += "<![CDATA[".len() + end + "]]>".len();
+                return Ok(Some(XmlEvent::Text(body[..end].to_string())));
+            }
+
+            let skipped = [("<!--", "-->"), ("<?", "?>"), ("<!", ">")]
+                .iter()
+                .find(|(open, _)| rest.starts_with(open));
+            if let Some((open, close)) = skipped {
+                let end = rest[open.len()..]
+                    .find(close)
+                    .ok_or_else(|| format!("unterminated '{open}' markup"))?;
+                self.pos += open.len() + end + close.len();
+                continue;
+            }
+
+            let end = tag_end(rest).ok_or("unterminated XML tag")?;
+            let tag = &rest[1..end];
+            self.pos += end + 1;
+
+            if let Some(name) = tag.strip_prefix('/') {
+                return Ok(Some(XmlEvent::End(name.trim_end().to_string())));
+            }
+
+            let (tag, empty) = match tag.strip_suffix('/') {
+                Some(tag) => (tag, true),
+                None => (tag, false),
+            };
+            let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+            let name = tag[..name_end].to_string();
+            if name.is_empty() {
+                return Err(Box::from("XML tag without a name"));
+            }
+
+            return Ok(Some(XmlEvent::Start {
+                name,
+                attributes: parse_attributes(&tag[name_end..])?,
+                empty,
+            }));
+        }
+    }
+}
+
+/// Finds the `>` closing a tag, skipping the ones inside quoted attribute values.
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Parses the `key="value"` attributes of a tag.
+fn parse_attributes(mut s: &str) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+    let mut attributes = Vec::new();
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return Ok(attributes);
+        }
+
+        let eq = s.find('=').ok_or("XML attribute without a value")?;
+        let key = s[..eq].trim().to_string();
+        let value = s[eq + 1..].trim_start();
+        let quote = value
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '"' | '\''))
+            .ok_or_else(|| format!("value of XML attribute '{key}' should be quoted"))?;
+        let end = value[1..]
+            .find(quote)
+            .ok_or_else(|| format!("unterminated value for XML attribute '{key}'"))?;
+
+        attributes.push((key, unescape(&value[1..end + 1])?));
+        s = &value[end + 2..];
+    }
+}
@@ -0,0 +1,504 @@
+use std::{
+    error::Error,
+    fmt::Write as _,
+    fs::OpenOptions,
+    io::{BufWriter, Write},
+};
+
+use crate::{
+    json::{write_json_string, JsonValue},
+    parse_token, Cell, Sheet,
+};
+
+/// plain scalars YAML parsers would read as something other than a string
+const RESERVED_WORDS: [&str; 12] = [
+    "null", "~", "true", "false", "yes", "no", "on", "off", "y", "n", ".nan", ".inf",
+];
+
+impl Sheet {
+    /// Renders the Sheet as a YAML sequence of mappings, one mapping per row keyed by the header
+    /// row.
+    ///
+    /// `Cell::Null` maps to `null`, ints and floats to numbers and bools to booleans, with NaN
+    /// and infinities written as `.nan`, `.inf` and `-.inf`. Strings are written plain whenever
+    /// that's unambiguous, and double-quoted otherwise, e.g. when they look like a number or a
+    /// boolean, or hold characters meaningful to YAML. An empty sheet renders as `[]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use datatroll::{Cell, Sheet};
+    ///
+    /// let mut sheet = Sheet::load_data_from_str("id,title,review\n1,old,3.5\n2,,4.0");
+    /// sheet.set_cell(1, "title", Cell::String("true".to_string())).unwrap();
+    ///
+    /// assert_eq!(
+    ///     sheet.to_yaml_string(),
+    ///     "- id: 1\n  title: old\n  review: 3.5\n- id: 2\n  title: \"true\"\n  review: 4.0\n"
+    /// );
+    /// ```
+    #[allow(deprecated)]
+    pub fn to_yaml_string(&self) -> String {
+        if self.data.len() < 2 {
+            return "[]\n".to_string();
+        }
+
+        let keys: Vec<String> = self.data[0]
+            .iter()
+            .map(|key| {
+                let mut out = String::new();
+                write_yaml_string(&mut out, &key.to_string());
+                out
+            })
+            .collect();
+
+        let mut out = String::new();
+        for row in self.data.iter().skip(1) {
+            if row.is_empty() {
+                out.push_str("- {}\n");
+                continue;
+            }
+            for (j, (key, cell)) in keys.iter().zip(row.iter()).enumerate() {
+                out.push_str(if j == 0 { "- " } else { "  " });
+                write!(out, "{key}: ").unwrap();
+                write_yaml_value(&mut out, cell);
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+
+    /// Exports the content of a Sheet to a YAML file.
+    ///
+    /// The file holds the sequence of mappings produced by [`Sheet::to_yaml_string`]. If the file
+    /// already exists, it truncates the file and overwrites its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the YAML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't have a ".yaml" or ".yml" extension, or if it can't be
+    /// written to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use datatroll::Sheet;
+    ///
+    /// let sheet = Sheet::load_data("movies.csv").unwrap();
+    /// sheet.export_yaml("fixtures/movies.yaml").unwrap();
+    /// ```
+    pub fn export_yaml(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        // check for ext
+        if !matches!(file_path.rsplit('.').next(), Some("yaml" | "yml")) {
+            return Err(Box::from(
+                "the provided file path is invalid, or of unsupported format",
+            ));
+        }
+
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(file_path)?;
+
+        let mut buf_writer = BufWriter::new(file);
+        buf_writer.write_all(self.to_yaml_string().as_bytes())?;
+        buf_writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Writes a cell as a YAML scalar.
+fn write_yaml_value(out: &mut String, cell: &Cell) {
+    match cell {
+        Cell::Null => out.push_str("null"),
+        Cell::String(s) => write_yaml_string(out, s),
+        Cell::Bool(b) => write!(out, "{b}").unwrap(),
+        Cell::Int(i) => write!(out, "{i}").unwrap(),
+        Cell::UInt(u) => write!(out, "{u}").unwrap(),
+        Cell::Float(f) if f.is_nan() => out.push_str(".nan"),
+        Cell::Float(f) if f.is_infinite() => out.push_str(if *f > 0.0 { ".inf" } else { "-.inf" }),
+        Cell::Float(f) => write!(out, "{f:?}").unwrap(),
+    }
+}
+
+/// Writes a string as a plain YAML scalar when it would be read back as the same string, and
+/// as a double-quoted one otherwise.
+fn write_yaml_string(out: &mut String, s: &str) {
+    let lower = s.to_ascii_lowercase();
+    let plain = !s.is_empty()
+        && s.trim() == s
+        && matches!(parse_token(s), Cell::String(_))
+        && !RESERVED_WORDS.contains(&lower.as_str())
+        && !s.starts_with([
+            '-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"', '%',
+            '@', '`', '.', '+',
+        ])
+        && !s.ends_with(':')
+        && !s.contains(": ")
+        && !s.contains(" #")
+        && !s.chars().any(char::is_control);
+
+    if plain {
+        out.push_str(s);
+    } else {
+        // JSON strings are valid YAML double-quoted scalars
+        write_json_string(out, s);
+    }
+}
+
+/// Parses a YAML document into the same tree as a JSON document.
+///
+/// Only the subset of YAML used by configuration files is supported: block mappings and
+/// sequences nested by indentation, flow collections such as `[a, b]` or `{a: 1}`, plain,
+/// single-quoted and double-quoted scalars, and comments. Anchors, tags, multi-document streams
+/// and block scalars (`|` and `>`) are rejected or read as plain strings.
+pub(crate) fn parse_yaml(input: &str) -> Result<JsonValue, Box<dyn Error>> {
+    let lines: Vec<YamlLine> = input
+        .lines()
+        .enumerate()
+        .filter_map(|(n, line)| {
+            let content = strip_comment(line).trim_end();
+            let trimmed = content.trim_start_matches(' ');
+            if trimmed.is_empty() || trimmed == "---" {
+                return None;
+            }
+            Some(YamlLine {
+                number: n + 1,
+                indent: content.len() - trimmed.len(),
+                content: trimmed,
+            })
+        })
+        .collect();
+    let Some(first) = lines.first() else {
+        return Ok(JsonValue::Null);
+    };
+
+    let indent = first.indent;
+    let mut parser = YamlParser { lines, pos: 0 };
+    let value = parser.parse_block(indent)?;
+    if let Some(line) = parser.lines.get(parser.pos) {
+        return Err(yaml_error(line.number, "unexpected indentation"));
+    }
+
+    Ok(value)
+}
+
+/// A non-empty line of a YAML document, without its indentation and comment.
+#[derive(Debug, Clone, Copy)]
+struct YamlLine<'a> {
+    number: usize,
+    indent: usize,
+    content: &'a str,
+}
+
+impl YamlLine<'_> {
+    /// is_item tells whether the line starts a block sequence item
+    fn is_item(&self) -> bool {
+        self.content == "-" || self.content.starts_with("- ")
+    }
+}
+
+/// A parser of block YAML, going through the lines of a document.
+struct YamlParser<'a> {
+    lines: Vec<YamlLine<'a>>,
+    pos: usize,
+}
+
+impl YamlParser<'_> {
+    fn parse_block(&mut self, indent: usize) -> Result<JsonValue, Box<dyn Error>> {
+        match self.lines.get(self.pos) {
+            Some(line) if line.is_item() => self.parse_sequence(indent),
+            Some(line) if split_key(line.content).is_none() => {
+                // a lone scalar
+                self.pos += 1;
+                parse_yaml_scalar(line.content).map_err(|err| yaml_error(line.number, &err))
+            }
+            Some(_) => self.parse_mapping(indent),
+            None => Ok(JsonValue::Null),
+        }
+    }
+
+    fn parse_sequence(&mut self, indent: usize) -> Result<JsonValue, Box<dyn Error>> {
+        let mut items = Vec::new();
+        while let Some(line) = self.lines.get(self.pos).copied() {
+            if line.indent != indent || !line.is_item() {
+                break;
+            }
+            let rest = line.content[1..].trim_start_matches(' ');
+            self.pos += 1;
+
+            let item = if rest.is_empty() {
+                self.parse_nested(indent, false)?
+            } else if split_key(rest).is_some() {
+                // a mapping starting on the line of the dash, its keys aligned with the first one
+                let item_indent = indent + line.content.len() - rest.len();
+                self.pos -= 1;
+                self.lines[self.pos] = YamlLine {
+                    indent: item_indent,
+                    content: rest,
+                    ..line
+                };
+                self.parse_mapping(item_indent)?
+            } else {
+                parse_yaml_scalar(rest).map_err(|err| yaml_error(line.number, &err))?
+            };
+            items.push(item);
+        }
+
+        Ok(JsonValue::Array(items))
+    }
+
+    fn parse_mapping(&mut self, indent: usize) -> Result<JsonValue, Box<dyn Error>> {
+        let mut fields = Vec::new();
+        while let Some(line) = self.lines.get(self.pos).copied() {
+            if line.indent != indent || line.is_item() {
+                break;
+            }
+            let (key, value) = split_key(line.content)
+                .ok_or_else(|| yaml_error(line.number, "expected 'key: value'"))?;
+            self.pos += 1;
+
+            let value = if value.is_empty() {
+                self.parse_nested(indent, true)?
+            } else {
+                parse_yaml_scalar(value).map_err(|err| yaml_error(line.number, &err))?
+            };
+            fields.push((key, value));
+        }
+
+        Ok(JsonValue::Object(fields))
+    }
+
+    /// parse_nested parses the block following a key or a dash without a value, which is
+    /// indented further, or is a sequence at the same indentation as a mapping key
+    fn parse_nested(
+        &mut self,
+        indent: usize,
+        in_mapping: bool,
+    ) -> Result<JsonValue, Box<dyn Error>> {
+        match self.lines.get(self.pos) {
+            Some(next)
+                if next.indent > indent
+                    || (in_mapping && next.indent == indent && next.is_item()) =>
+            {
+                self.parse_block(next.indent)
+            }
+            _ => Ok(JsonValue::Null),
+        }
+    }
+}
+
+fn yaml_error(line: usize, msg: &str) -> Box<dyn Error> {
+    Box::from(format!("invalid YAML at line {line}: {msg}"))
+}
+
+/// Removes the comment ending a line, if any, leaving `#` within quotes untouched.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in line.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && previous.is_whitespace() => return &line[..i],
+            None => {}
+        }
+        previous = c;
+    }
+
+    line
+}
+
+/// Splits a mapping entry into its key and value, returning None if the line isn't one.
+fn split_key(content: &str) -> Option<(String, &str)> {
+    if content.starts_with(['[', '{']) {
+        return None;
+    }
+
+    let mut quote = None;
+    for (i, c) in content.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if (c == '"' || c == '\'') && i == 0 => quote = Some(c),
+            None if c == ':' => {
+                let rest = &content[i + 1..];
+                if rest.is_empty() || rest.starts_with(' ') {
+                    let key = match parse_yaml_scalar(content[..i].trim()) {
+                        Ok(JsonValue::String(key)) => key,
+                        _ => content[..i].trim().to_string(),
+                    };
+                    return Some((key, rest.trim()));
+                }
+            }
+            None => {}
+        }
+    }
+
+    None
+}
+
+/// Parses a scalar or a flow collection.
+fn parse_yaml_scalar(text: &str) -> Result<JsonValue, String> {
+    let mut parser = FlowParser { text, pos: 0 };
+    let value = parser.parse_value(false)?;
+    if parser.pos != text.len() {
+        return Err(format!(
+            "unexpected characters after '{}'",
+            &text[..parser.pos]
+        ));
+    }
+
+    Ok(value)
+}
+
+/// A parser of flow YAML values, such as `[a, "b", {c: 1}]`.
+struct FlowParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> FlowParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.pos = self.text.len() - self.rest().trim_start().len();
+    }
+
+    /// parse_value parses a value, which ends at `,`, `]` or `}` within a flow collection
+    fn parse_value(&mut self, in_flow: bool) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+        let value = match self.rest().chars().next() {
+            Some('[') => self.parse_sequence()?,
+            Some('{') => self.parse_mapping()?,
+            Some('"') => {
+                let end =
+                    closing_double_quote(self.rest()).ok_or("unterminated double-quoted string")?;
+                let quoted = &self.rest()[..=end];
+                self.pos += end + 1;
+                JsonValue::parse(quoted).map_err(|err| err.to_string())?
+            }
+            Some('\'') => {
+                let mut end = 1;
+                loop {
+                    match self.rest()[end..].find('\'') {
+                        Some(i) if self.rest()[end + i + 1..].starts_with('\'') => end += i + 2,
+                        Some(i) => {
+                            end += i;
+                            break;
+                        }
+                        None => return Err("unterminated single-quoted string".to_string()),
+                    }
+                }
+                let inner = self.rest()[1..end].replace("''", "'");
+                self.pos += end + 1;
+                JsonValue::String(inner)
+            }
+            _ => {
+                let end = if in_flow {
+                    self.rest()
+                        .find([',', ']', '}'])
+                        .unwrap_or(self.rest().len())
+                } else {
+                    self.rest().len()
+                };
+                let plain = self.rest()[..end].trim();
+                self.pos += end;
+                plain_scalar(plain)
+            }
+        };
+        self.skip_whitespace();
+
+        Ok(value)
+    }
+
+    fn parse_sequence(&mut self) -> Result<JsonValue, String> {
+        // skip the opening bracket
+        self.pos += 1;
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if let Some(rest) = self.rest().strip_prefix(']') {
+                self.pos = self.text.len() - rest.len();
+                return Ok(JsonValue::Array(items));
+            }
+            items.push(self.parse_value(true)?);
+            match self.rest().chars().next() {
+                Some(',') => self.pos += 1,
+                Some(']') => {}
+                _ => return Err("expected ',' or ']'".to_string()),
+            }
+        }
+    }
+
+    fn parse_mapping(&mut self) -> Result<JsonValue, String> {
+        // skip the opening brace
+        self.pos += 1;
+        let mut fields = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if let Some(rest) = self.rest().strip_prefix('}') {
+                self.pos = self.text.len() - rest.len();
+                return Ok(JsonValue::Object(fields));
+            }
+            let end = self.rest().find(':').ok_or("expected 'key: value'")?;
+            let key = match plain_or_quoted(self.rest()[..end].trim())? {
+                JsonValue::String(key) => key,
+                _ => self.rest()[..end].trim().to_string(),
+            };
+            self.pos += end + 1;
+            fields.push((key, self.parse_value(true)?));
+            match self.rest().chars().next() {
+                Some(',') => self.pos += 1,
+                Some('}') => {}
+                _ => return Err("expected ',' or '}'".to_string()),
+            }
+        }
+    }
+}
+
+/// Parses a mapping key, which may be quoted.
+fn plain_or_quoted(text: &str) -> Result<JsonValue, String> {
+    if text.starts_with(['"', '\'']) {
+        parse_yaml_scalar(text)
+    } else {
+        Ok(JsonValue::String(text.to_string()))
+    }
+}
+
+/// Finds the closing quote of a double-quoted string, skipping escaped quotes.
+fn closing_double_quote(text: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Reads a plain scalar as null, a bool, a number or a string.
+fn plain_scalar(text: &str) -> JsonValue {
+    match text {
+        "" | "~" | "null" | "Null" | "NULL" => JsonValue::Null,
+        "true" | "True" | "TRUE" => JsonValue::Bool(true),
+        "false" | "False" | "FALSE" => JsonValue::Bool(false),
+        _ => match parse_token(text) {
+            number @ (Cell::Int(_) | Cell::UInt(_) | Cell::Float(_)) => JsonValue::Number(number),
+            _ => JsonValue::String(text.to_string()),
+        },
+    }
+}